@@ -2081,6 +2081,58 @@ impl BillPayments {
         remitwise_common::require_no_active_kill_switch(&env)
             .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
         caller.require_auth();
+        Self::settle_bill(env, caller, bill_id, None)
+    }
+
+    /// Pay `owner`'s bill on their behalf.
+    ///
+    /// `executor` must be registered by `owner` through
+    /// [`Self::set_authorized_executor`]; the orchestrator uses this to run
+    /// scheduled flows without the owner signing each run. `amount` is what
+    /// the executor routed to the bill and must cover `bill.amount`.
+    /// Otherwise behaves exactly like [`Self::pay_bill`] with `caller == owner`.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If `owner` has not registered `executor`, or the bill
+    ///   is not `owner`'s
+    /// * `InvalidAmount` - If `amount < bill.amount`
+    /// * Any error of [`Self::pay_bill`]
+    pub fn pay_bill_for(
+        env: Env,
+        executor: Address,
+        owner: Address,
+        bill_id: u32,
+        amount: i128,
+    ) -> Result<(), BillPaymentsError> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        executor.require_auth();
+        remitwise_common::require_authorized_executor(&env, &owner, &executor)
+            .map_err(|_| BillPaymentsError::Unauthorized)?;
+        Self::settle_bill(env, owner, bill_id, Some(amount))
+    }
+
+    /// Let `executor` (typically the orchestrator) pay `owner`'s bills through
+    /// [`Self::pay_bill_for`], or revoke it.
+    pub fn set_authorized_executor(env: Env, owner: Address, executor: Address, authorized: bool) {
+        owner.require_auth();
+        Self::extend_instance_ttl(&env);
+        remitwise_common::set_authorized_executor(&env, &owner, &executor, authorized);
+    }
+
+    /// Whether `owner` has registered `executor` for [`Self::pay_bill_for`].
+    pub fn is_authorized_executor(env: Env, owner: Address, executor: Address) -> bool {
+        remitwise_common::is_authorized_executor(&env, &owner, &executor)
+    }
+
+    /// Shared body of [`Self::pay_bill`] and [`Self::pay_bill_for`]; `caller`
+    /// is the bill owner and `routed` the amount an executor routed, if any.
+    fn settle_bill(
+        env: Env,
+        caller: Address,
+        bill_id: u32,
+        routed: Option<i128>,
+    ) -> Result<(), BillPaymentsError> {
         Self::require_not_paused(&env, pause_functions::PAY_BILL)?;
 
         // Check rate limit
@@ -2108,6 +2160,9 @@ impl BillPayments {
         if bill.paid {
             return Err(BillPaymentsError::BillAlreadyPaid);
         }
+        if routed.is_some_and(|amount| amount < bill.amount) {
+            return Err(BillPaymentsError::InvalidAmount);
+        }
 
         let current_time = env.ledger().timestamp();
         require_within_settlement_window(current_time, bill.due_date, MAX_SETTLEMENT_WINDOW_SECS)
@@ -1074,6 +1074,46 @@ mod testsuit {
         assert_eq!(result, Err(Ok(Error::Unauthorized)));
     }
 
+    #[test]
+    fn test_pay_bill_for_requires_registered_executor_and_full_amount() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, BillPayments);
+        let client = BillPaymentsClient::new(&env, &contract_id);
+        let owner = <soroban_sdk::Address as AddressTrait>::generate(&env);
+        let other = <soroban_sdk::Address as AddressTrait>::generate(&env);
+        let executor = <soroban_sdk::Address as AddressTrait>::generate(&env);
+
+        env.mock_all_auths();
+        let bill_id = client.create_bill(
+            &owner,
+            &String::from_str(&env, "Water"),
+            &500,
+            &1000000,
+            &false,
+            &0,
+            &None,
+            &String::from_str(&env, "XLM"),
+            &None,
+        );
+
+        let result = client.try_pay_bill_for(&executor, &owner, &bill_id, &500);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        // Registered by another owner: cannot pay this owner's bill.
+        client.set_authorized_executor(&other, &executor, &true);
+        let result = client.try_pay_bill_for(&executor, &other, &bill_id, &500);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        client.set_authorized_executor(&owner, &executor, &true);
+        assert!(client.is_authorized_executor(&owner, &executor));
+        let result = client.try_pay_bill_for(&executor, &owner, &bill_id, &499);
+        assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+        assert!(!client.get_bill(&bill_id).unwrap().paid);
+
+        client.pay_bill_for(&executor, &owner, &bill_id, &500);
+        assert!(client.get_bill(&bill_id).unwrap().paid);
+    }
+
     #[test]
     fn test_recurring_bill_cancellation() {
         let env = Env::default();
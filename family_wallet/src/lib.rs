@@ -6,22 +6,36 @@ use soroban_sdk::{
 };
 
 use remitwise_common::{
-    EventCategory, EventPriority, FamilyRole, RemitwiseEvents, RoleGrantedEvent, RoleRevokedEvent,
-    CONTRACT_VERSION, SNAPSHOT_KEY, SNAPSHOT_VERSION, STROOPS_PER_XLM,
+    EventCategory, EventPriority, FamilyRole, RemitwiseError, RemitwiseEvents, RoleGrantedEvent,
+    RoleRevokedEvent, CONTRACT_VERSION, STROOPS_PER_XLM,
 };
+#[cfg(any(test, feature = "inheritance"))]
+use remitwise_common::{Timestamp, BASIS_POINTS};
+#[cfg(any(test, feature = "upgrade_snapshots"))]
+use remitwise_common::{SNAPSHOT_KEY, SNAPSHOT_VERSION};
 
 // Storage TTL constants for active data
 const INSTANCE_LIFETIME_THRESHOLD: u32 = 17280;
 const INSTANCE_BUMP_AMOUNT: u32 = 518400;
 
 // Storage TTL constants for archived data
+#[cfg(any(test, feature = "archive"))]
 const ARCHIVE_LIFETIME_THRESHOLD: u32 = 17280;
+#[cfg(any(test, feature = "archive"))]
 const ARCHIVE_BUMP_AMOUNT: u32 = 2592000;
 
 // Signature expiration time constants
 const DEFAULT_PROPOSAL_EXPIRY: u64 = 86400; // 24 hours
 const MAX_PROPOSAL_EXPIRY: u64 = 604_800; // 7 days
 
+// Timelock constants for approved transactions
+const MAX_EXECUTION_DELAY: u64 = 604_800; // 7 days
+/// How long a queued transaction stays executable after its timelock elapses.
+const EXECUTION_GRACE_PERIOD: u64 = 604_800; // 7 days
+/// Conservative ledger close time used to convert timelock windows into
+/// allowance expiration ledgers.
+const LEDGER_CLOSE_SECONDS: u64 = 5;
+
 // Multisig configuration bounds
 const MIN_THRESHOLD: u32 = 1;
 const MAX_SIGNERS: u32 = 20;
@@ -49,12 +63,37 @@ const DEFAULT_EMERGENCY_DAILY_LIMIT: i128 = 100_000 * STROOPS_PER_XLM;
 /// Hard cap on the number of entries retained in `ARCH_TX`.
 /// When the archive reaches this limit the oldest entry (lowest `tx_id`) is
 /// evicted before the new one is inserted, keeping instance-storage rent bounded.
+#[cfg(any(test, feature = "archive"))]
 const MAX_ARCHIVE_ENTRIES: u32 = 500;
 /// Default page size for `get_archived_transactions` when `limit == 0`.
+#[cfg(any(test, feature = "archive"))]
 const DEFAULT_ARCHIVE_PAGE_LIMIT: u32 = 20;
 /// Maximum page size for `get_archived_transactions`.
+#[cfg(any(test, feature = "archive"))]
 const MAX_ARCHIVE_PAGE_LIMIT: u32 = 100;
 
+// Inheritance (dead-man switch) bounds
+/// Shortest allowed owner-inactivity window: 1 day.
+#[cfg(any(test, feature = "inheritance"))]
+const MIN_INACTIVITY_PERIOD: u64 = 86_400;
+/// Longest allowed owner-inactivity window: 2 years.
+#[cfg(any(test, feature = "inheritance"))]
+const MAX_INACTIVITY_PERIOD: u64 = 2 * 365 * 86_400;
+/// Maximum number of beneficiaries in an inheritance policy.
+#[cfg(any(test, feature = "inheritance"))]
+const MAX_BENEFICIARIES: u32 = 10;
+
+/// Longest an unaccepted member invitation stays valid: 30 days.
+#[cfg(any(test, feature = "invitations"))]
+const MAX_INVITATION_TTL: u64 = 30 * 86_400;
+/// Most invitation IDs a single `get_invitations_page` call will examine.
+/// Each ID is a persistent read, so this also bounds the call's footprint.
+#[cfg(any(test, feature = "invitations"))]
+const MAX_INVITATION_SCAN: u64 = 25;
+/// Most invitation IDs a single `create_invitation` call will try to prune.
+#[cfg(any(test, feature = "invitations"))]
+const MAX_INVITATION_PRUNE: u64 = 10;
+
 #[contracttype]
 #[derive(Clone)]
 pub struct AccessAuditEntry {
@@ -105,6 +144,19 @@ pub fn select_withdrawal_tier(amount: i128, spending_limit: i128) -> WithdrawalT
     }
 }
 
+/// How `execute_transaction_internal` funds transfers that spend the
+/// proposer's tokens.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ProposerAuth {
+    /// The proposer already authorized this invocation.
+    Given,
+    /// Require the proposer's authorization now.
+    Require,
+    /// Spend the allowance the proposer granted this contract when proposing
+    /// a timelocked transaction, so execution needs no further signature.
+    Allowance,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct MultiSigConfig {
@@ -212,7 +264,7 @@ pub struct EmergencyConfig {
     pub daily_limit: i128,
 }
 
-#[contracttype]
+#[contracttype(export = false)]
 #[derive(Clone)]
 pub enum EmergencyEvent {
     ModeOn,
@@ -221,7 +273,7 @@ pub enum EmergencyEvent {
     TransferExec,
 }
 
-#[contracttype]
+#[contracttype(export = false)]
 #[derive(Clone)]
 pub struct MemberAddedEvent {
     pub member: Address,
@@ -230,7 +282,7 @@ pub struct MemberAddedEvent {
     pub timestamp: u64,
 }
 
-#[contracttype]
+#[contracttype(export = false)]
 #[derive(Clone)]
 pub struct SpendingLimitUpdatedEvent {
     pub member: Address,
@@ -239,7 +291,7 @@ pub struct SpendingLimitUpdatedEvent {
     pub timestamp: u64,
 }
 
-#[contracttype]
+#[contracttype(export = false)]
 #[derive(Clone)]
 pub struct ProposalInvalidatedEvent {
     pub tx_id: u64,
@@ -250,7 +302,7 @@ pub struct ProposalInvalidatedEvent {
 /// Emitted when `pause` halts the wallet. `reason` lets off-chain monitors
 /// distinguish a routine admin pause from an incident-driven one without
 /// having to correlate against out-of-band reports.
-#[contracttype]
+#[contracttype(export = false)]
 #[derive(Clone)]
 pub struct PauseEvent {
     pub paused_by: Address,
@@ -271,7 +323,7 @@ pub struct PauseEvent {
 /// fixed-size and avoids exposing the membership list on-chain to topic
 /// subscribers; the authoritative signer list remains queryable via
 /// `get_multisig_config`.
-#[contracttype]
+#[contracttype(export = false)]
 #[derive(Clone)]
 pub struct MultisigConfiguredEvent {
     pub tx_type: TransactionType,
@@ -281,6 +333,16 @@ pub struct MultisigConfiguredEvent {
     pub timestamp: u64,
 }
 
+/// Emitted when an approved transaction enters the timelock queue.
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct TransactionQueuedEvent {
+    pub tx_id: u64,
+    pub tx_type: TransactionType,
+    pub executable_at: u64,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct ArchivedTransaction {
@@ -295,7 +357,7 @@ pub struct ArchivedTransaction {
 ///
 /// **Security:** `tx_id` must match the map key; mismatch indicates storage corruption
 /// and must abort archiving (`archive_old_transactions`).
-#[contracttype]
+#[contracttype(export = false)]
 #[derive(Clone)]
 pub struct ExecutedTxMeta {
     pub tx_id: u64,
@@ -319,7 +381,8 @@ const MAX_THRESHOLD: u32 = 100;
 ///
 /// Captures critical instance storage before a contract upgrade so state
 /// can be restored if the upgrade fails or produces inconsistent results.
-#[contracttype]
+#[cfg(any(test, feature = "upgrade_snapshots"))]
+#[contracttype(export = false)]
 #[derive(Clone)]
 pub struct PreUpgradeSnapshot {
     /// Snapshot schema version (`SNAPSHOT_VERSION`).
@@ -353,7 +416,7 @@ pub struct BatchMemberItem {
     pub role: FamilyRole,
 }
 
-#[contracttype]
+#[contracttype(export = false)]
 #[derive(Clone)]
 pub enum ArchiveEvent {
     TransactionsArchived,
@@ -361,6 +424,123 @@ pub enum ArchiveEvent {
     TransactionCancelled,
 }
 
+/// A beneficiary's share of the wallet's token balances, in basis points.
+#[cfg(any(test, feature = "inheritance"))]
+#[contracttype]
+#[derive(Clone)]
+pub struct InheritanceShare {
+    pub beneficiary: Address,
+    pub share_bps: u32,
+}
+
+/// Opt-in inactivity policy for the wallet owner.
+///
+/// Once the owner has performed no authenticated action for
+/// `inactivity_period` seconds, `successor` (if set) may claim the Owner
+/// role and each entry in `beneficiaries` may withdraw its share of any
+/// token balance held by the wallet contract.
+#[cfg(any(test, feature = "inheritance"))]
+#[contracttype]
+#[derive(Clone)]
+pub struct InheritanceConfig {
+    pub inactivity_period: u64,
+    /// Seconds before the deadline at which `check_inheritance` starts
+    /// emitting warning events. `0` disables warnings.
+    pub warning_window: u64,
+    pub successor: Option<Address>,
+    /// Shares must sum to exactly `BASIS_POINTS` when non-empty.
+    pub beneficiaries: Vec<InheritanceShare>,
+}
+
+/// Point-in-time view of the inactivity policy returned by
+/// `get_inheritance_status` and `check_inheritance`.
+#[cfg(any(test, feature = "inheritance"))]
+#[contracttype]
+#[derive(Clone)]
+pub struct InheritanceStatus {
+    pub last_activity: u64,
+    pub deadline: u64,
+    pub warning_at: u64,
+    pub in_warning_window: bool,
+    pub claimable: bool,
+}
+
+#[cfg(any(test, feature = "inheritance"))]
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct InheritanceWarningEvent {
+    pub deadline: u64,
+    pub seconds_remaining: u64,
+    pub timestamp: u64,
+}
+
+#[cfg(any(test, feature = "inheritance"))]
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct OwnershipClaimedEvent {
+    pub previous_owner: Address,
+    pub new_owner: Address,
+    pub timestamp: u64,
+}
+
+#[cfg(any(test, feature = "inheritance"))]
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct InheritancePaidEvent {
+    pub beneficiary: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// A pending offer of membership, created by an Owner or Admin and
+/// turned into a `FamilyMember` when the invitee accepts.
+#[cfg(any(test, feature = "invitations"))]
+#[contracttype]
+#[derive(Clone)]
+pub struct Invitation {
+    pub id: u64,
+    pub invitee: Address,
+    pub role: FamilyRole,
+    pub spending_limit: i128,
+    pub invited_by: Address,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+#[cfg(any(test, feature = "invitations"))]
+#[contracttype]
+#[derive(Clone)]
+pub struct InvitationPage {
+    pub items: Vec<Invitation>,
+    pub next_cursor: u64,
+    pub count: u32,
+}
+
+#[cfg(any(test, feature = "vault"))]
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct VaultDepositEvent {
+    pub depositor: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Pooled balance of `token` after the deposit.
+    pub balance: i128,
+    pub timestamp: u64,
+}
+
+#[cfg(any(test, feature = "vault"))]
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct VaultWithdrawalEvent {
+    pub member: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Pooled balance of `token` after the withdrawal.
+    pub balance: i128,
+    pub timestamp: u64,
+}
+
 /// @title Family Wallet Multisig Proposal Expiry
 /// @notice Manages the lifecycle of multisig proposals with deterministic expiry.
 ///
@@ -414,9 +594,138 @@ pub enum Error {
     /// multisig proposals.  Allowing the change while proposals are
     /// in-flight could cause orphaned signatures, silently-invalid quorum
     /// calculations, or execution against stale configuration.
+    ///
+    /// Also returned by `claim_ownership` while a beneficiary distribution
+    /// is partly paid.
     PendingOperationsExist = 27,
     /// The supplied expiry timestamp is in the past.
     RoleExpiryInPast = 28,
+    /// No inheritance policy has been configured for this wallet.
+    InheritanceNotConfigured = 29,
+    /// The owner's inactivity deadline has not passed yet.
+    InheritanceNotDue = 30,
+    /// Inactivity period, warning window, successor or beneficiary shares
+    /// are out of bounds.
+    InvalidInheritanceConfig = 31,
+    /// The caller is not the designated successor or a configured beneficiary.
+    NotInheritor = 32,
+    /// The beneficiary already withdrew its share of this token.
+    InheritanceAlreadyClaimed = 33,
+    /// The transaction has reached its signature threshold and is waiting
+    /// out its execution delay; it can no longer be signed.
+    TransactionQueued = 34,
+    /// The transaction is not waiting in the timelock queue.
+    TransactionNotQueued = 35,
+    /// The transaction's execution delay has not elapsed yet.
+    TimelockNotElapsed = 36,
+    /// The requested execution delay exceeds `MAX_EXECUTION_DELAY`.
+    InvalidExecutionDelay = 37,
+    /// The amount exceeds the caller's per-transaction spending limit.
+    SpendingLimitExceeded = 38,
+    /// The caller's role has passed its `set_role_expiry` timestamp.
+    RoleExpired = 39,
+    /// Emergency max amount is not positive, or min balance / daily limit is negative.
+    InvalidEmergencyConfig = 40,
+    /// The owner cannot be removed from the wallet.
+    CannotRemoveOwner = 41,
+    /// An emergency transfer with the same token, recipient and amount is already pending.
+    DuplicateEmergencyProposal = 42,
+    /// The proposer already has the maximum number of pending emergency proposals.
+    EmergencyProposalLimitReached = 43,
+    /// The emergency transfer exceeds `EmergencyConfig.max_amount`.
+    EmergencyAmountExceeded = 44,
+    /// The emergency cooldown since the last emergency transfer has not elapsed.
+    EmergencyCooldownActive = 45,
+    /// The emergency transfer would exceed `EmergencyConfig.daily_limit`.
+    EmergencyDailyLimitExceeded = 46,
+    /// The pooled vault balance for the token is lower than the requested amount.
+    InsufficientVaultBalance = 47,
+    /// The amount exceeds the member's remaining sub-account allowance.
+    SubAccountLimitExceeded = 48,
+    /// The wallet is paused; state-changing operations are rejected.
+    ContractPaused = 49,
+    /// Emergency mode is on but `configure_emergency` was never called.
+    EmergencyConfigNotSet = 50,
+}
+
+impl Error {
+    /// Map onto the cross-contract [`RemitwiseError`] taxonomy where the two
+    /// overlap, so callers such as the orchestrator can handle shared failure
+    /// modes uniformly. Wallet-specific errors return `None`.
+    pub fn to_remitwise_error(self) -> Option<RemitwiseError> {
+        match self {
+            Error::Unauthorized
+            | Error::SignerNotMember
+            | Error::NotInheritor
+            | Error::RoleExpired => Some(RemitwiseError::Unauthorized),
+            Error::InvalidAmount | Error::InvalidSpendingLimit => {
+                Some(RemitwiseError::InvalidAmount)
+            }
+            Error::TransactionExpired => Some(RemitwiseError::DeadlineExpired),
+            _ => None,
+        }
+    }
+}
+
+/// Failures of the member-invitation flow. Kept separate from [`Error`],
+/// whose spec is at the 50-variant limit, and numbered from 101 so the two
+/// sets of codes never collide.
+#[cfg(any(test, feature = "invitations"))]
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum InvitationError {
+    /// The caller may not create, revoke or accept this invitation.
+    Unauthorized = 101,
+    /// Invitations cannot grant the `Owner` role.
+    InvalidRole = 102,
+    /// The offered spending limit is negative.
+    InvalidSpendingLimit = 103,
+    /// Invitation lifetime is zero or exceeds `MAX_INVITATION_TTL`.
+    InvalidExpiry = 104,
+    /// The invitee is already a family member.
+    MemberAlreadyExists = 105,
+    /// The invitee already holds an unexpired invitation.
+    AlreadyPending = 106,
+    /// No invitation exists with the given ID (never created, accepted or revoked).
+    NotFound = 107,
+    /// The invitation's `expires_at` has passed.
+    Expired = 108,
+    /// The wallet already has `MAX_FAMILY_MEMBERS` members.
+    MemberCapReached = 109,
+    /// The inviter is no longer an active Owner or Admin.
+    InviterRevoked = 110,
+}
+
+#[cfg(any(test, feature = "invitations"))]
+impl InvitationError {
+    /// Shared [`RemitwiseError`] for the variants that have one.
+    pub fn to_remitwise_error(self) -> Option<RemitwiseError> {
+        match self {
+            InvitationError::Unauthorized => Some(RemitwiseError::Unauthorized),
+            InvitationError::Expired => Some(RemitwiseError::DeadlineExpired),
+            _ => None,
+        }
+    }
+}
+
+/// Failures of wallet setup, batch membership changes and archive
+/// maintenance. Numbered from 201 so the codes never collide with [`Error`]
+/// or [`InvitationError`].
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AdminError {
+    /// `init` was called on a wallet that already has an owner.
+    AlreadyInitialized = 201,
+    /// The owner plus the new members would exceed `MAX_FAMILY_MEMBERS`.
+    MemberCapExceeded = 202,
+    /// A batch call carries more than `MAX_BATCH_MEMBERS` entries.
+    BatchTooLarge = 203,
+    /// The same address appears twice in one batch.
+    DuplicateMember = 204,
+    /// The archive cutoff lies in the future.
+    InvalidArchiveCutoff = 205,
 }
 
 #[contractimpl]
@@ -426,7 +735,7 @@ impl FamilyWallet {
             return false;
         }
         if !Self::try_initialize(env.clone(), owner, initial_members) {
-            panic!("Wallet already initialized");
+            panic_with_error!(&env, AdminError::AlreadyInitialized);
         }
         true
     }
@@ -445,7 +754,7 @@ impl FamilyWallet {
         // (`batch_add_family_members`) already enforces. `+1` accounts for the
         // owner, who is also added as a member below.
         if initial_members.len().saturating_add(1) > MAX_FAMILY_MEMBERS {
-            panic!("Initial member cap exceeded");
+            panic_with_error!(&env, AdminError::MemberCapExceeded);
         }
         let existing: Option<Address> = env.storage().instance().get(&symbol_short!("OWNER"));
         if existing.is_some() {
@@ -876,8 +1185,9 @@ impl FamilyWallet {
         Self::require_role_at_least(&env, &proposer, FamilyRole::Member);
 
         if !Self::is_family_member(&env, &proposer) {
-            panic!("Only family members can propose transactions");
+            panic_with_error!(&env, Error::Unauthorized);
         }
+        Self::record_owner_activity(&env, &proposer);
 
         // Fetch Regular config (source of truth for boundary)
         let reg_config_key = Self::get_config_key(TransactionType::RegularWithdrawal);
@@ -914,7 +1224,7 @@ impl FamilyWallet {
                 &proposer,
                 &resolved_tx_type,
                 &data,
-                false,
+                ProposerAuth::Given,
             );
         }
 
@@ -932,13 +1242,14 @@ impl FamilyWallet {
             }
         }
 
-        if valid_signatures >= config.threshold {
+        let timelocked = Self::execution_delay(&env, resolved_tx_type) > 0;
+        if valid_signatures >= config.threshold && !timelocked {
             let executed = Self::execute_transaction_internal(
                 &env,
                 &proposer,
                 &resolved_tx_type,
                 &data,
-                false,
+                ProposerAuth::Given,
             );
             if executed == 0 {
                 return 0;
@@ -977,7 +1288,7 @@ impl FamilyWallet {
         let mut signatures = Vec::new(&env);
         signatures.push_back(proposer.clone());
 
-        let pending_tx = PendingTransaction {
+        let mut pending_tx = PendingTransaction {
             tx_id,
             tx_type: resolved_tx_type, // normalized
             proposer: proposer.clone(),
@@ -993,6 +1304,13 @@ impl FamilyWallet {
             .get(&symbol_short!("PEND_TXS"))
             .unwrap_or_else(|| panic!("Pending transactions map not initialized"));
 
+        if timelocked {
+            Self::approve_queued_spend(&env, &pending_tx);
+            if valid_signatures >= config.threshold {
+                Self::queue_if_timelocked(&env, &mut pending_tx);
+            }
+        }
+
         pending_txs.set(tx_id, pending_tx);
 
         env.storage()
@@ -1012,6 +1330,7 @@ impl FamilyWallet {
             return Err(Error::SignerNotMember);
         }
         Self::require_role_at_least(&env, &signer, FamilyRole::Member);
+        Self::record_owner_activity(&env, &signer);
 
         Self::extend_instance_ttl(&env);
 
@@ -1021,14 +1340,15 @@ impl FamilyWallet {
             .get(&symbol_short!("PEND_TXS"))
             .unwrap_or_else(|| panic!("Pending transactions map not initialized"));
 
-        let mut pending_tx = pending_txs
-            .get(tx_id)
-            .unwrap_or_else(|| panic!("Transaction not found"));
+        let mut pending_tx = pending_txs.get(tx_id).ok_or(Error::TransactionNotFound)?;
 
         let current_time = env.ledger().timestamp();
         if current_time > pending_tx.expires_at {
             return Err(Error::TransactionExpired);
         }
+        if Self::get_transaction_eta(env.clone(), tx_id).is_some() {
+            return Err(Error::TransactionQueued);
+        }
 
         // If signer already recorded, reject with typed error.
         for sig in pending_tx.signatures.iter() {
@@ -1071,39 +1391,25 @@ impl FamilyWallet {
         }
 
         if valid_signatures >= config.threshold {
+            // Timelocked types wait in the queue instead of executing now.
+            if Self::queue_if_timelocked(&env, &mut pending_tx) {
+                pending_txs.set(tx_id, pending_tx);
+                env.storage()
+                    .instance()
+                    .set(&symbol_short!("PEND_TXS"), &pending_txs);
+                return Ok(true);
+            }
+
             let executed = Self::execute_transaction_internal(
                 &env,
                 &pending_tx.proposer,
                 &pending_tx.tx_type,
                 &pending_tx.data,
-                true,
+                ProposerAuth::Require,
             );
 
             if executed == 0 {
-                pending_txs.remove(tx_id);
-                env.storage()
-                    .instance()
-                    .set(&symbol_short!("PEND_TXS"), &pending_txs);
-
-                let mut executed_txs: Map<u64, ExecutedTxMeta> = env
-                    .storage()
-                    .instance()
-                    .get(&symbol_short!("EXEC_TXS"))
-                    .unwrap_or_else(|| panic!("Executed transactions map not initialized"));
-
-                let executed_at = env.ledger().timestamp();
-                executed_txs.set(
-                    tx_id,
-                    ExecutedTxMeta {
-                        tx_id,
-                        tx_type: pending_tx.tx_type,
-                        proposer: pending_tx.proposer.clone(),
-                        executed_at,
-                    },
-                );
-                env.storage()
-                    .instance()
-                    .set(&symbol_short!("EXEC_TXS"), &executed_txs);
+                Self::record_executed_transaction(&env, &mut pending_txs, &pending_tx);
             }
 
             return Ok(true);
@@ -1120,26 +1426,28 @@ impl FamilyWallet {
     /// Withdraw funds using the appropriate spending limit and multi-sig configuration.
     ///
     /// # Errors
-    /// Panics if the contract is paused.
+    /// - `InvalidAmount` if `amount` is not positive
+    /// - `SpendingLimitExceeded` if `amount` exceeds the proposer's spending limit
+    /// - any precision-limit error from `validate_precision_spending`
+    ///
+    /// Fails with `Error::ContractPaused` if the contract is paused.
     pub fn withdraw(
         env: Env,
         proposer: Address,
         token: Address,
         recipient: Address,
         amount: i128,
-    ) -> u64 {
+    ) -> Result<u64, Error> {
         Self::require_not_paused(&env);
         if amount <= 0 {
-            panic!("Amount must be positive");
+            return Err(Error::InvalidAmount);
         }
 
         if !Self::check_spending_limit(env.clone(), proposer.clone(), amount) {
-            panic!("Spending limit exceeded");
+            return Err(Error::SpendingLimitExceeded);
         }
 
-        if let Err(e) = Self::validate_precision_spending_internal(env.clone(), proposer.clone(), amount) {
-            panic_with_error!(env, e);
-        }
+        Self::validate_precision_spending_internal(env.clone(), proposer.clone(), amount)?;
 
         let config: MultiSigConfig = env
             .storage()
@@ -1153,18 +1461,18 @@ impl FamilyWallet {
             TransactionType::RegularWithdrawal
         };
 
-        Self::propose_transaction(
+        Ok(Self::propose_transaction(
             env,
             proposer,
             tx_type,
             TransactionData::Withdrawal(token, recipient, amount),
-        )
+        ))
     }
 
     /// Propose a split configuration change.
     ///
     /// # Errors
-    /// Panics if the contract is paused.
+    /// Fails with `Error::ContractPaused` if the contract is paused.
     /// Returns [`Error::InvalidSplitConfig`] if any percentage exceeds 100
     /// or the four percentages do not sum to exactly 100.
     pub fn propose_split_config_change(
@@ -1203,7 +1511,7 @@ impl FamilyWallet {
     /// Propose a family member role change.
     ///
     /// # Errors
-    /// Panics if the contract is paused.
+    /// Fails with `Error::ContractPaused` if the contract is paused.
     pub fn propose_role_change(
         env: Env,
         proposer: Address,
@@ -1219,76 +1527,10 @@ impl FamilyWallet {
         )
     }
 
-    /// Propose or execute an emergency transfer.
-    ///
-    /// # Errors
-    /// Panics if the contract is paused.
-    pub fn propose_emergency_transfer(
-        env: Env,
-        proposer: Address,
-        token: Address,
-        recipient: Address,
-        amount: i128,
-    ) -> u64 {
-        Self::require_not_paused(&env);
-        if amount <= 0 {
-            panic!("Amount must be positive");
-        }
-
-        let em_mode: bool = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("EM_MODE"))
-            .unwrap_or(false);
-
-        if em_mode {
-            return Self::execute_emergency_transfer_now(env, proposer, token, recipient, amount);
-        }
-
-        let pending_txs: Map<u64, PendingTransaction> = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("PEND_TXS"))
-            .unwrap_or_else(|| Map::new(&env));
-
-        let mut active_proposals = 0;
-        for (_, tx) in pending_txs.iter() {
-            if tx.proposer == proposer && tx.tx_type == TransactionType::EmergencyTransfer {
-                if let TransactionData::EmergencyTransfer(t, r, a) = &tx.data {
-                    if t == &token && r == &recipient && *a == amount {
-                        panic!("Identical emergency transfer proposal already pending");
-                    }
-                }
-                active_proposals += 1;
-            }
-        }
-
-        if active_proposals >= 1 {
-            panic!("Maximum pending emergency proposals reached");
-        }
-
-        let tx_id = Self::propose_transaction(
-            env.clone(),
-            proposer.clone(),
-            TransactionType::EmergencyTransfer,
-            TransactionData::EmergencyTransfer(token.clone(), recipient.clone(), amount),
-        );
-
-        Self::append_access_audit(
-            &env,
-            symbol_short!("em_prop"),
-            &proposer,
-            Some(recipient.clone()),
-            true,
-        );
-
-        tx_id
-    }
-
     /// Propose a policy cancellation.
     ///
     /// # Errors
-    /// Panics if the contract is paused.
+    /// Fails with `Error::ContractPaused` if the contract is paused.
     pub fn propose_policy_cancellation(env: Env, proposer: Address, policy_id: u32) -> u64 {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
             return 0;
@@ -1302,129 +1544,54 @@ impl FamilyWallet {
         )
     }
 
-    /// Configure emergency transfer guardrails.
+    /// Add a family member with the given role and no spending limit.
     ///
-    /// Only `Owner` or `Admin` may update emergency settings.
-    /// Successful configuration is recorded in the access audit trail.
-    pub fn configure_emergency(
+    /// # Errors
+    /// - `InvalidRole` if `role` is `Owner`
+    /// - `Unauthorized` if `caller` is not an unexpired Owner or Admin
+    pub fn add_family_member(
         env: Env,
         caller: Address,
-        max_amount: i128,
-        cooldown: u64,
-        min_balance: i128,
-        daily_limit: i128,
-    ) -> bool {
+        member: Address,
+        role: FamilyRole,
+    ) -> Result<bool, Error> {
+        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
+            return Ok(false);
+        }
         caller.require_auth();
         Self::require_not_paused(&env);
-
-        if !Self::is_owner_or_admin(&env, &caller) {
-            panic!("Only Owner or Admin can configure emergency settings");
-        }
-        if max_amount <= 0 {
-            panic!("Emergency max amount must be positive");
-        }
-        if min_balance < 0 {
-            panic!("Emergency min balance must be non-negative");
-        }
-        if daily_limit < 0 {
-            panic!("Emergency daily limit must be non-negative");
+        if role == FamilyRole::Owner {
+            return Err(Error::InvalidRole);
         }
+        Self::require_governance_ok(&env, &caller)?;
 
         Self::extend_instance_ttl(&env);
 
-        env.storage().instance().set(
-            &symbol_short!("EM_CONF"),
-            &EmergencyConfig {
-                max_amount,
-                cooldown,
-                min_balance,
-                daily_limit,
+        let mut members: Map<Address, FamilyMember> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("MEMBERS"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+
+        let timestamp = env.ledger().timestamp();
+        members.set(
+            member.clone(),
+            FamilyMember {
+                address: member.clone(),
+                role,
+                spending_limit: 0,
+                precision_limit: PrecisionLimitOpt::None,
+                added_at: timestamp,
             },
         );
 
-        Self::append_access_audit(&env, symbol_short!("em_conf"), &caller, None, true);
-
-        true
-    }
-
-    /// Enable or disable emergency mode.
-    ///
-    /// This operation is restricted to `Owner` or `Admin` and is recorded in the access audit trail.
-    pub fn set_emergency_mode(env: Env, caller: Address, enabled: bool) -> bool {
-        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
-        }
-        caller.require_auth();
-        Self::require_not_paused(&env);
-
-        if !Self::is_owner_or_admin(&env, &caller) {
-            panic!("Only Owner or Admin can change emergency mode");
-        }
-
-        Self::extend_instance_ttl(&env);
-
         env.storage()
             .instance()
-            .set(&symbol_short!("EM_MODE"), &enabled);
+            .set(&symbol_short!("MEMBERS"), &members);
 
-        let event = if enabled {
-            EmergencyEvent::ModeOn
-        } else {
-            EmergencyEvent::ModeOff
-        };
         RemitwiseEvents::emit(
             &env,
-            EventCategory::System,
-            EventPriority::High,
-            symbol_short!("em_mode"),
-            event,
-        );
-
-        Self::append_access_audit(&env, symbol_short!("em_mode"), &caller, None, true);
-
-        true
-    }
-
-    pub fn add_family_member(env: Env, caller: Address, member: Address, role: FamilyRole) -> bool {
-        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
-        }
-        caller.require_auth();
-        Self::require_not_paused(&env);
-        if role == FamilyRole::Owner {
-            panic!("Cannot add Owner via add_family_member");
-        }
-        if !Self::is_owner_or_admin(&env, &caller) {
-            panic!("Only Owner or Admin can add family members");
-        }
-
-        Self::extend_instance_ttl(&env);
-
-        let mut members: Map<Address, FamilyMember> = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("MEMBERS"))
-            .unwrap_or_else(|| panic!("Wallet not initialized"));
-
-        let timestamp = env.ledger().timestamp();
-        members.set(
-            member.clone(),
-            FamilyMember {
-                address: member.clone(),
-                role,
-                spending_limit: 0,
-                precision_limit: PrecisionLimitOpt::None,
-                added_at: timestamp,
-            },
-        );
-
-        env.storage()
-            .instance()
-            .set(&symbol_short!("MEMBERS"), &members);
-
-        RemitwiseEvents::emit(
-            &env,
-            EventCategory::Access,
+            EventCategory::Access,
             EventPriority::High,
             symbol_short!("role_grnt"),
             RoleGrantedEvent {
@@ -1435,7 +1602,7 @@ impl FamilyWallet {
         );
 
         Self::append_access_audit(&env, symbol_short!("add_mem"), &caller, Some(member), true);
-        true
+        Ok(true)
     }
 
     /// Remove a family member from the wallet.
@@ -1458,7 +1625,13 @@ impl FamilyWallet {
     /// * `member` - The member address to remove
     ///
     /// # Returns
-    /// `bool` - true on successful removal
+    /// `bool` - true on successful removal, false if `member` was not in the wallet
+    ///
+    /// # Errors
+    /// - `PendingOperationsExist` while multisig proposals are in flight
+    /// - `RoleExpired` if the caller's role has expired
+    /// - `Unauthorized` if `caller` is not the Owner
+    /// - `CannotRemoveOwner` if `member` is the Owner
     ///
     /// # Security
     /// - Validates caller is Owner
@@ -1466,16 +1639,16 @@ impl FamilyWallet {
     /// - Silently succeeds if member doesn't exist
     /// - Records access audit entry
     /// - Prevents a re-added member from inheriting previous member's state
-    pub fn remove_family_member(env: Env, caller: Address, member: Address) -> bool {
+    pub fn remove_family_member(env: Env, caller: Address, member: Address) -> Result<bool, Error> {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
+            return Ok(false);
         }
         caller.require_auth();
         Self::require_not_paused(&env);
 
         // Defence-in-depth: block removal while multisig proposals are in-flight
         // to prevent orphaned signatures and stale quorum calculations.
-        Self::require_no_pending_operations(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+        Self::require_no_pending_operations(&env)?;
 
         let owner: Address = env
             .storage()
@@ -1484,13 +1657,13 @@ impl FamilyWallet {
             .unwrap_or_else(|| panic!("Wallet not initialized"));
 
         if Self::role_has_expired(&env, &caller) {
-            panic!("Role has expired");
+            return Err(Error::RoleExpired);
         }
         if caller != owner {
-            panic!("Only Owner can remove family members");
+            return Err(Error::Unauthorized);
         }
         if member == owner {
-            panic!("Cannot remove owner");
+            return Err(Error::CannotRemoveOwner);
         }
 
         Self::extend_instance_ttl(&env);
@@ -1506,6 +1679,8 @@ impl FamilyWallet {
             env.storage()
                 .instance()
                 .set(&symbol_short!("MEMBERS"), &members);
+            Self::clear_member_state(&env, &member);
+            Self::clear_sub_accounts(&env, &member);
 
             RemitwiseEvents::emit(
                 &env,
@@ -1520,9 +1695,9 @@ impl FamilyWallet {
             );
 
             Self::append_access_audit(&env, symbol_short!("rem_mem"), &caller, Some(member), true);
-            true
+            Ok(true)
         } else {
-            false
+            Ok(false)
         }
     }
 
@@ -1620,55 +1795,14 @@ impl FamilyWallet {
             .unwrap_or_else(|| panic!("Wallet not initialized"))
     }
 
-    pub fn get_emergency_config(env: Env) -> Option<EmergencyConfig> {
-        env.storage().instance().get(&symbol_short!("EM_CONF"))
-    }
-
-    pub fn is_emergency_mode(env: Env) -> bool {
-        env.storage()
-            .instance()
-            .get(&symbol_short!("EM_MODE"))
-            .unwrap_or(false)
-    }
-
-    pub fn get_last_emergency_at(env: Env) -> Option<u64> {
-        let ts: u64 = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("EM_LAST"))
-            .unwrap_or(0u64);
-        if ts == 0 {
-            None
-        } else {
-            Some(ts)
-        }
-    }
-
-    /// Moves **eligible** multisig-executed transactions from `EXEC_TXS` into `ARCH_TX`.
-    ///
-    /// # Semantics
-    /// - `before_timestamp` is a **retention cutoff** (ledger seconds): a row is archived iff
-    ///   `executed_at < before_timestamp` (strictly less-than — entries executed *at* the cutoff
-    ///   are **not** archived, preserving the most recent boundary entry in `EXEC_TXS`).
-    /// - The cutoff must satisfy `before_timestamp <= ledger timestamp`. A future cutoff would
-    ///   treat recent executions as "old" relative to an incorrect clock and could archive too much.
-    ///
-    /// # Bounded growth invariant
-    /// `ARCH_TX` is capped at `MAX_ARCHIVE_ENTRIES`. Before inserting each new entry, if the
-    /// archive is already at capacity the entry with the **lowest `tx_id`** (oldest) is evicted.
-    /// This keeps instance-storage rent bounded regardless of how many transactions are executed
-    /// over the contract's lifetime.
+    /// Removes pending proposals whose `expires_at` is strictly before the ledger time.
     ///
     /// # Authorization
-    /// Owner or Admin only (`caller.require_auth()`).
-    ///
-    /// # Data integrity
-    /// Archived rows copy **proposer**, **tx_type**, and **executed_at** from `ExecutedTxMeta`.
-    /// If `meta.tx_id != map_key`, the contract panics to avoid corrupting the archive.
+    /// Owner or Admin only.
     ///
-    /// # Returns
-    /// The number of transactions moved from `EXEC_TXS` to `ARCH_TX` in this call.
-    pub fn archive_old_transactions(env: Env, caller: Address, before_timestamp: u64) -> u32 {
+    /// # Integrity
+    /// Aborts if `pending.tx_id` does not match the map key (prevents silent corruption during cleanup).
+    pub fn cleanup_expired_pending(env: Env, caller: Address) -> u32 {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
             return 0;
         }
@@ -1676,264 +1810,84 @@ impl FamilyWallet {
         Self::require_not_paused(&env);
 
         if !Self::is_owner_or_admin(&env, &caller) {
-            panic!("Only Owner or Admin can archive transactions");
+            panic_with_error!(&env, Error::Unauthorized);
         }
 
         Self::extend_instance_ttl(&env);
 
-        let now = env.ledger().timestamp();
-        if before_timestamp > now {
-            panic!("Archive retention cutoff must not exceed ledger time");
-        }
-
-        let mut executed_txs: Map<u64, ExecutedTxMeta> = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("EXEC_TXS"))
-            .unwrap_or_else(|| Map::new(&env));
-
-        let mut archived: Map<u64, ArchivedTransaction> = env
+        let mut pending_txs: Map<u64, PendingTransaction> = env
             .storage()
             .instance()
-            .get(&symbol_short!("ARCH_TX"))
+            .get(&symbol_short!("PEND_TXS"))
             .unwrap_or_else(|| Map::new(&env));
 
         let current_time = env.ledger().timestamp();
-        let mut archived_count = 0u32;
+        let mut removed_count = 0u32;
         let mut to_remove: Vec<u64> = Vec::new(&env);
 
-        // Pre-compute archive length and oldest tx_id once, before the main loop.
-        // This avoids O(n²) nested iteration which exhausts the Soroban WASM budget.
-        let mut arch_len = 0u32;
-        let mut oldest_arch_id: Option<u64> = None;
-        for (aid, _) in archived.iter() {
-            arch_len += 1;
-            oldest_arch_id = Some(match oldest_arch_id {
-                None => aid,
-                Some(prev) => {
-                    if aid < prev {
-                        aid
-                    } else {
-                        prev
-                    }
-                }
-            });
-        }
-
-        for (tx_id, meta) in executed_txs.iter() {
-            if meta.tx_id != tx_id {
-                panic!("Inconsistent executed transaction metadata");
+        for (tx_id, tx) in pending_txs.iter() {
+            if tx.tx_id != tx_id {
+                panic!("Inconsistent pending transaction data");
             }
-            // Strictly less-than: entries executed AT before_timestamp are retained.
-            if meta.executed_at < before_timestamp {
-                // Enforce the archive size cap: evict the oldest entry (lowest tx_id)
-                // before inserting so ARCH_TX never exceeds MAX_ARCHIVE_ENTRIES.
-                if arch_len >= MAX_ARCHIVE_ENTRIES {
-                    if let Some(oid) = oldest_arch_id {
-                        archived.remove(oid);
-                        // After eviction, find the new oldest from the remaining entries.
-                        let mut new_oldest: Option<u64> = None;
-                        for (aid, _) in archived.iter() {
-                            new_oldest = Some(match new_oldest {
-                                None => aid,
-                                Some(prev) => {
-                                    if aid < prev {
-                                        aid
-                                    } else {
-                                        prev
-                                    }
-                                }
-                            });
-                        }
-                        oldest_arch_id = new_oldest;
-                        // arch_len stays the same: we removed one and will add one below.
-                    }
-                } else {
-                    arch_len += 1;
-                    // Update oldest_arch_id if this new entry is older (lower tx_id).
-                    oldest_arch_id = Some(match oldest_arch_id {
-                        None => tx_id,
-                        Some(prev) => {
-                            if tx_id < prev {
-                                tx_id
-                            } else {
-                                prev
-                            }
-                        }
-                    });
-                }
-
-                let archived_tx = ArchivedTransaction {
-                    tx_id: meta.tx_id,
-                    tx_type: meta.tx_type,
-                    proposer: meta.proposer.clone(),
-                    executed_at: meta.executed_at,
-                    archived_at: current_time,
-                };
-                archived.set(tx_id, archived_tx);
+            if tx.expires_at < current_time {
                 to_remove.push_back(tx_id);
-                archived_count += 1;
+                removed_count += 1;
             }
         }
 
         for i in 0..to_remove.len() {
             if let Some(id) = to_remove.get(i) {
-                executed_txs.remove(id);
+                if let Some(tx) = pending_txs.get(id) {
+                    Self::release_queued_spend(&env, &tx);
+                }
+                pending_txs.remove(id);
+                Self::dequeue_transaction(&env, id);
             }
         }
 
         env.storage()
             .instance()
-            .set(&symbol_short!("EXEC_TXS"), &executed_txs);
-
-        env.storage()
-            .instance()
-            .set(&symbol_short!("ARCH_TX"), &archived);
+            .set(&symbol_short!("PEND_TXS"), &pending_txs);
 
-        Self::extend_archive_ttl(&env);
         Self::update_storage_stats(&env);
 
         env.events().publish(
-            (symbol_short!("archive"), ArchiveEvent::TransactionsArchived),
-            (archived_count, caller.clone()),
+            (symbol_short!("archive"), ArchiveEvent::ExpiredCleaned),
+            (removed_count, caller.clone()),
         );
+        Self::append_access_audit(&env, symbol_short!("cln_exp"), &caller, None, true);
+        removed_count
+    }
 
-        Self::append_access_audit(&env, symbol_short!("arch_tx"), &caller, None, true);
-
-        archived_count
+    pub fn get_storage_stats(env: Env) -> StorageStats {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("STOR_STAT"))
+            .unwrap_or(StorageStats {
+                pending_transactions: 0,
+                archived_transactions: 0,
+                total_members: 0,
+                last_updated: 0,
+            })
     }
 
-    /// Returns a page of archived transactions ordered by ascending `tx_id`.
-    ///
-    /// # Parameters
-    /// - `limit`: entries to return; `0` → `DEFAULT_ARCHIVE_PAGE_LIMIT`; clamped to
-    ///   `MAX_ARCHIVE_PAGE_LIMIT`. Ordering follows the map's natural key order (ascending `tx_id`).
-    ///
-    /// # Authorization
-    /// Only Owner or Admin. Requires `caller.require_auth()` to prevent unauthenticated reads
-    /// of historical transaction metadata (ownership / privacy leakage).
-    pub fn get_archived_transactions(
+    /// @notice Set or clear a role-expiry timestamp for an existing family member.
+    /// @dev Expiry is inclusive: at `ledger.timestamp() >= expires_at` the member is treated as expired.
+    /// @param caller Admin/Owner authorizing the change.
+    /// @param member Target family member.
+    /// @param expires_at Unix timestamp in seconds; `None` clears expiry.
+    /// @return Ok(true) on success; `Unauthorized` / `RoleExpired` for an
+    ///         ineligible caller, `MemberNotFound`, or `RoleExpiryInPast`.
+    pub fn set_role_expiry(
         env: Env,
         caller: Address,
-        limit: u32,
-    ) -> Vec<ArchivedTransaction> {
+        member: Address,
+        expires_at: Option<u64>,
+    ) -> Result<bool, Error> {
         caller.require_auth();
-        if !Self::is_owner_or_admin(&env, &caller) {
-            panic!("Only Owner or Admin can view archived transactions");
-        }
-
-        // Clamp limit: 0 → default, >max → max.
-        let effective_limit = if limit == 0 {
-            DEFAULT_ARCHIVE_PAGE_LIMIT
-        } else if limit > MAX_ARCHIVE_PAGE_LIMIT {
-            MAX_ARCHIVE_PAGE_LIMIT
-        } else {
-            limit
-        };
-
-        let archived: Map<u64, ArchivedTransaction> = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("ARCH_TX"))
-            .unwrap_or_else(|| Map::new(&env));
-
-        let mut result = Vec::new(&env);
-        for (count, (_, tx)) in archived.iter().enumerate() {
-            if count as u32 >= effective_limit {
-                break;
-            }
-            result.push_back(tx);
-        }
-        result
-    }
-
-    /// Removes pending proposals whose `expires_at` is strictly before the ledger time.
-    ///
-    /// # Authorization
-    /// Owner or Admin only.
-    ///
-    /// # Integrity
-    /// Aborts if `pending.tx_id` does not match the map key (prevents silent corruption during cleanup).
-    pub fn cleanup_expired_pending(env: Env, caller: Address) -> u32 {
-        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return 0;
-        }
-        caller.require_auth();
-        Self::require_not_paused(&env);
-
-        if !Self::is_owner_or_admin(&env, &caller) {
-            panic!("Only Owner or Admin can cleanup expired transactions");
-        }
-
-        Self::extend_instance_ttl(&env);
-
-        let mut pending_txs: Map<u64, PendingTransaction> = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("PEND_TXS"))
-            .unwrap_or_else(|| Map::new(&env));
-
-        let current_time = env.ledger().timestamp();
-        let mut removed_count = 0u32;
-        let mut to_remove: Vec<u64> = Vec::new(&env);
-
-        for (tx_id, tx) in pending_txs.iter() {
-            if tx.tx_id != tx_id {
-                panic!("Inconsistent pending transaction data");
-            }
-            if tx.expires_at < current_time {
-                to_remove.push_back(tx_id);
-                removed_count += 1;
-            }
-        }
-
-        for i in 0..to_remove.len() {
-            if let Some(id) = to_remove.get(i) {
-                pending_txs.remove(id);
-            }
-        }
-
-        env.storage()
-            .instance()
-            .set(&symbol_short!("PEND_TXS"), &pending_txs);
-
-        Self::update_storage_stats(&env);
-
-        env.events().publish(
-            (symbol_short!("archive"), ArchiveEvent::ExpiredCleaned),
-            (removed_count, caller.clone()),
-        );
-        Self::append_access_audit(&env, symbol_short!("cln_exp"), &caller, None, true);
-        removed_count
-    }
-
-    pub fn get_storage_stats(env: Env) -> StorageStats {
-        env.storage()
-            .instance()
-            .get(&symbol_short!("STOR_STAT"))
-            .unwrap_or(StorageStats {
-                pending_transactions: 0,
-                archived_transactions: 0,
-                total_members: 0,
-                last_updated: 0,
-            })
-    }
-
-    /// @notice Set or clear a role-expiry timestamp for an existing family member.
-    /// @dev Expiry is inclusive: at `ledger.timestamp() >= expires_at` the member is treated as expired.
-    /// @param caller Admin/Owner authorizing the change.
-    /// @param member Target family member.
-    /// @param expires_at Unix timestamp in seconds; `None` clears expiry.
-    pub fn set_role_expiry(
-        env: Env,
-        caller: Address,
-        member: Address,
-        expires_at: Option<u64>,
-    ) -> bool {
-        caller.require_auth();
-        Self::require_role_at_least(&env, &caller, FamilyRole::Admin);
-        Self::require_not_paused(&env);
-        Self::extend_instance_ttl(&env);
+        Self::check_role_at_least(&env, &caller, FamilyRole::Admin)?;
+        Self::require_not_paused(&env);
+        Self::extend_instance_ttl(&env);
 
         let members: Map<Address, FamilyMember> = env
             .storage()
@@ -1941,7 +1895,7 @@ impl FamilyWallet {
             .get(&symbol_short!("MEMBERS"))
             .unwrap_or_else(|| panic!("Wallet not initialized"));
         if members.get(member.clone()).is_none() {
-            panic!("Member not found");
+            return Err(Error::MemberNotFound);
         }
 
         // Reject expiry timestamps that are in the past — setting an already-
@@ -1949,7 +1903,7 @@ impl FamilyWallet {
         // their role with no way to recover except through admin intervention.
         if let Some(t) = expires_at {
             if remitwise_common::require_future_timestamp(&env, t).is_err() {
-                panic_with_error!(&env, Error::RoleExpiryInPast);
+                return Err(Error::RoleExpiryInPast);
             }
         }
 
@@ -1966,7 +1920,7 @@ impl FamilyWallet {
         }
         env.storage().instance().set(&symbol_short!("ROLE_EXP"), &m);
         Self::append_access_audit(&env, symbol_short!("role_exp"), &caller, Some(member), true);
-        true
+        Ok(true)
     }
 
     pub fn get_role_expiry_public(env: Env, address: Address) -> Option<u64> {
@@ -2128,6 +2082,8 @@ impl FamilyWallet {
         env.storage()
             .instance()
             .set(&symbol_short!("PEND_TXS"), &pending_txs);
+        Self::dequeue_transaction(&env, tx_id);
+        Self::release_queued_spend(&env, &pending_tx);
 
         env.events().publish(
             (symbol_short!("archive"), ArchiveEvent::TransactionCancelled),
@@ -2150,11 +2106,14 @@ impl FamilyWallet {
                 .unwrap_or_else(|| panic!("Wallet not initialized"))
         });
         if admin != caller {
-            panic!("Only pause admin can pause");
+            panic_with_error!(&env, Error::Unauthorized);
         }
         env.storage()
             .instance()
             .set(&symbol_short!("PAUSED"), &true);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("PAUSED_AT"), &env.ledger().timestamp());
         env.events().publish(
             (symbol_short!("wallet"), symbol_short!("paused")),
             PauseEvent {
@@ -2179,10 +2138,10 @@ impl FamilyWallet {
                 .unwrap_or_else(|| panic!("Wallet not initialized"))
         });
         if admin != caller {
-            panic!("Only pause admin can unpause");
+            panic_with_error!(&env, Error::Unauthorized);
         }
         if Self::role_has_expired(&env, &caller) {
-            panic!("Role has expired");
+            panic_with_error!(&env, Error::RoleExpired);
         }
         env.storage()
             .instance()
@@ -2239,7 +2198,7 @@ impl FamilyWallet {
     /// Values greater than `MAX_PROPOSAL_EXPIRY` are rejected.
     ///
     /// # Errors
-    /// Panics if the contract is paused.
+    /// Fails with `Error::ContractPaused` if the contract is paused.
     pub fn set_proposal_expiry(env: Env, caller: Address, expiry: u64) -> bool {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
             return false;
@@ -2257,7 +2216,7 @@ impl FamilyWallet {
             panic_with_error!(&env, Error::Unauthorized);
         }
         if Self::role_has_expired(&env, &caller) {
-            panic!("Role has expired");
+            panic_with_error!(&env, Error::RoleExpired);
         }
 
         if expiry > MAX_PROPOSAL_EXPIRY {
@@ -2279,6 +2238,88 @@ impl FamilyWallet {
             .unwrap_or(DEFAULT_PROPOSAL_EXPIRY)
     }
 
+    /// Ledger timestamp from which a queued transaction may be executed, or
+    /// `None` if `tx_id` is not in the timelock queue.
+    pub fn get_transaction_eta(env: Env, tx_id: u64) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get::<_, Map<u64, u64>>(&symbol_short!("TX_QUEUE"))
+            .unwrap_or_else(|| Map::new(&env))
+            .get(tx_id)
+    }
+
+    fn vault_balances(env: &Env) -> Map<Address, i128> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("VAULT"))
+            .unwrap_or_else(|| Map::new(env))
+    }
+
+    fn sub_accounts(env: &Env) -> Map<(Address, Address), i128> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("SUB_ACCT"))
+            .unwrap_or_else(|| Map::new(env))
+    }
+
+    #[cfg(any(test, feature = "vault"))]
+    fn vault_routed_tokens(env: &Env) -> Map<Address, bool> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("VAULT_RT"))
+            .unwrap_or_else(|| Map::new(env))
+    }
+
+    /// Whether an Owner or Admin has opted `token` into vault routing. Routed
+    /// tokens are paid out of the pool; the rest keep the legacy behaviour of
+    /// moving funds straight from the proposer.
+    #[cfg(any(test, feature = "vault"))]
+    fn is_vault_token(env: &Env, token: &Address) -> bool {
+        Self::vault_routed_tokens(env).contains_key(token.clone())
+    }
+
+    /// Without the `vault` feature no token can be routed.
+    #[cfg(not(any(test, feature = "vault")))]
+    fn is_vault_token(_env: &Env, _token: &Address) -> bool {
+        false
+    }
+
+    /// Subtract `amount` from the pooled balance of `token`, refusing to leave
+    /// less than `floor` behind. Returns the new pooled balance.
+    fn debit_vault(env: &Env, token: &Address, amount: i128, floor: i128) -> Result<i128, Error> {
+        let mut balances = Self::vault_balances(env);
+        let current = balances.get(token.clone()).unwrap_or(0);
+        if amount > current {
+            return Err(Error::InsufficientVaultBalance);
+        }
+        let remaining = current - amount;
+        if remaining < floor {
+            return Err(Error::MinBalanceViolation);
+        }
+        balances.set(token.clone(), remaining);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("VAULT"), &balances);
+        Ok(remaining)
+    }
+
+    /// Drop every vault allowance held by `member`.
+    fn clear_sub_accounts(env: &Env, member: &Address) {
+        let mut accounts = Self::sub_accounts(env);
+        let mut changed = false;
+        for (key, _) in accounts.clone().iter() {
+            if &key.0 == member {
+                accounts.remove(key);
+                changed = true;
+            }
+        }
+        if changed {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("SUB_ACCT"), &accounts);
+        }
+    }
+
     fn get_upgrade_admin(env: &Env) -> Option<Address> {
         env.storage().instance().get(&symbol_short!("UPG_ADM"))
     }
@@ -2443,8 +2484,8 @@ impl FamilyWallet {
     /// - `true` on successful admin transfer
     ///
     /// # Panics
-    /// - If caller lacks Owner role or higher
-    /// - If the contract is paused
+    /// - `Error::Unauthorized` if caller lacks Owner role or higher
+    /// - `Error::ContractPaused` if the contract is paused
     pub fn set_upgrade_admin(env: Env, caller: Address, new_admin: Address) -> bool {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
             return false;
@@ -2488,7 +2529,7 @@ impl FamilyWallet {
     /// Set the contract version (upgrade support).
     ///
     /// # Errors
-    /// Panics if the contract is paused.
+    /// Fails with `Error::ContractPaused` if the contract is paused.
     pub fn set_version(env: Env, caller: Address, new_version: u32) -> bool {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
             return false;
@@ -2502,10 +2543,10 @@ impl FamilyWallet {
                 .unwrap_or_else(|| panic!("Wallet not initialized"))
         });
         if admin != caller {
-            panic!("Only upgrade admin can set version");
+            panic_with_error!(&env, Error::Unauthorized);
         }
         if Self::role_has_expired(&env, &caller) {
-            panic!("Role has expired");
+            panic_with_error!(&env, Error::RoleExpired);
         }
         let prev = Self::get_version(env.clone());
         env.storage()
@@ -2519,265 +2560,75 @@ impl FamilyWallet {
         true
     }
 
-    /// Add a batch of family members atomically.
-    ///
-    /// Semantics:
-    /// - The whole batch succeeds or the whole batch fails.
-    /// - Empty batches are accepted and return `0`.
-    /// - Any duplicate address in the batch, pre-existing member, owner-role item,
-    ///   or batch that would exceed the family-member cap aborts the entire call.
-    /// - On success, the return value is the number of members added.
-    pub fn batch_add_family_members(
+    pub fn get_access_audit(env: Env, limit: u32) -> Vec<AccessAuditEntry> {
+        let entries: Vec<AccessAuditEntry> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("ACC_AUDIT"))
+            .unwrap_or_else(|| Vec::new(&env));
+        let n = entries.len().min(limit);
+        let mut out = Vec::new(&env);
+        for i in (entries.len().saturating_sub(n))..entries.len() {
+            if let Some(e) = entries.get(i) {
+                out.push_back(e);
+            }
+        }
+        out
+    }
+
+    // Owner/Admin only: audit data is privacy-sensitive — reveals who accessed
+    // what and when, so Members are excluded from reading the full trail.
+    //
+    // ## Pagination cursor semantics
+    //
+    // `from_index` is the **inclusive** zero-based index of the first entry to
+    // return.  `next_cursor` in the returned page is the index to pass as
+    // `from_index` on the next call.
+    //
+    // **Sentinel value:** when `next_cursor == total` (i.e. equals the length
+    // of the log at the time of the call) there are no more entries.  Callers
+    // MUST stop iterating when `next_cursor >= count` returned by a previous
+    // page, or when the returned page is empty.
+    //
+    // **Clamping rules (no panic on adversarial input):**
+    // - `limit == 0`            → silently promoted to `DEFAULT_AUDIT_PAGE_LIMIT`.
+    // - `limit > MAX_AUDIT_PAGE_LIMIT` → clamped to `MAX_AUDIT_PAGE_LIMIT`.
+    // - `from_index >= total`   → returns an empty page with
+    //                             `next_cursor = total` (end-of-log sentinel).
+    // - `from_index = u32::MAX` → handled by the `>= total` check above; no
+    //                             arithmetic overflow is possible.
+    pub fn get_access_audit_page(
         env: Env,
         caller: Address,
-        members: Vec<BatchMemberItem>,
-    ) -> u32 {
+        from_index: u32,
+        limit: u32,
+    ) -> AccessAuditPage {
         caller.require_auth();
         Self::require_role_at_least(&env, &caller, FamilyRole::Admin);
-        Self::require_not_paused(&env);
-        if members.len() > MAX_BATCH_MEMBERS {
-            panic!("Batch too large");
-        }
-        Self::extend_instance_ttl(&env);
 
-        let mut members_map: Map<Address, FamilyMember> = env
+        let entries: Vec<AccessAuditEntry> = env
             .storage()
             .instance()
-            .get(&symbol_short!("MEMBERS"))
-            .unwrap_or_else(|| panic!("Wallet not initialized"));
+            .get(&symbol_short!("ACC_AUDIT"))
+            .unwrap_or_else(|| Vec::new(&env));
 
-        let mut current_member_count = 0u32;
-        for _ in members_map.iter() {
-            current_member_count += 1;
-        }
+        // Clamp limit: 0 → default, oversized → max.
+        let capped_limit = if limit == 0 {
+            DEFAULT_AUDIT_PAGE_LIMIT
+        } else {
+            limit.min(MAX_AUDIT_PAGE_LIMIT)
+        };
 
-        let mut seen_addrs: Map<Address, bool> = Map::new(&env);
-        let mut additions = 0u32;
-        for item in members.iter() {
-            if item.role == FamilyRole::Owner {
-                panic!("Cannot add Owner via batch");
-            }
-            if seen_addrs.get(item.address.clone()).is_some() {
-                panic!("Duplicate member in batch");
-            }
-            seen_addrs.set(item.address.clone(), true);
-            if members_map.get(item.address.clone()).is_some() {
-                panic!("Member already exists");
-            }
-            additions += 1;
-        }
+        let total = entries.len();
 
-        if current_member_count + additions > MAX_FAMILY_MEMBERS {
-            panic!("Member cap exceeded");
-        }
-
-        let timestamp = env.ledger().timestamp();
-        let mut count = 0u32;
-        for item in members.iter() {
-            members_map.set(
-                item.address.clone(),
-                FamilyMember {
-                    address: item.address.clone(),
-                    role: item.role,
-                    spending_limit: 0,
-                    precision_limit: PrecisionLimitOpt::None,
-                    added_at: timestamp,
-                },
-            );
-            RemitwiseEvents::emit(
-                &env,
-                EventCategory::Access,
-                EventPriority::High,
-                symbol_short!("role_grnt"),
-                RoleGrantedEvent {
-                    member: item.address.clone(),
-                    role: item.role,
-                    timestamp,
-                },
-            );
-            Self::append_access_audit(
-                &env,
-                symbol_short!("add_mem"),
-                &caller,
-                Some(item.address.clone()),
-                true,
-            );
-            count += 1;
-        }
-        env.storage()
-            .instance()
-            .set(&symbol_short!("MEMBERS"), &members_map);
-        RemitwiseEvents::emit(
-            &env,
-            EventCategory::Access,
-            EventPriority::Medium,
-            symbol_short!("batch_mem"),
-            count,
-        );
-        Self::update_storage_stats(&env);
-        count
-    }
-
-    /// Remove a batch of family members atomically.
-    ///
-    /// Semantics:
-    /// - The whole batch succeeds or the whole batch fails.
-    /// - Empty batches are accepted and return `0`.
-    /// - Any duplicate address in the batch, missing member, or attempt to remove
-    ///   the owner aborts the entire call.
-    /// - On success, the return value is the number of members removed.
-    pub fn batch_remove_family_members(env: Env, caller: Address, addresses: Vec<Address>) -> u32 {
-        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return 0;
-        }
-        caller.require_auth();
-        Self::require_role_at_least(&env, &caller, FamilyRole::Owner);
-        let owner: Address = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("OWNER"))
-            .unwrap_or_else(|| panic!("Wallet not initialized"));
-        if caller != owner {
-            panic!("Only Owner can remove members");
-        }
-        Self::require_not_paused(&env);
-        if addresses.len() > MAX_BATCH_MEMBERS {
-            panic!("Batch too large");
-        }
-        Self::extend_instance_ttl(&env);
-        let mut members_map: Map<Address, FamilyMember> = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("MEMBERS"))
-            .unwrap_or_else(|| panic!("Wallet not initialized"));
-
-        let mut seen_addrs: Map<Address, bool> = Map::new(&env);
-        let mut count = 0u32;
-        for addr in addresses.iter() {
-            if addr.clone() == owner {
-                panic!("Cannot remove owner");
-            }
-            if let Some(removed_member) = members_map.get(addr.clone()) {
-                members_map.remove(addr.clone());
-
-                RemitwiseEvents::emit(
-                    &env,
-                    EventCategory::Access,
-                    EventPriority::High,
-                    symbol_short!("role_revk"),
-                    RoleRevokedEvent {
-                        member: addr.clone(),
-                        role: removed_member.role,
-                        timestamp: env.ledger().timestamp(),
-                    },
-                );
-
-                Self::append_access_audit(
-                    &env,
-                    symbol_short!("rem_mem"),
-                    &caller,
-                    Some(addr.clone()),
-                    true,
-                );
-                count += 1;
-            }
-        }
-
-        for addr in addresses.iter() {
-            members_map.remove(addr.clone());
-            // Clear all per-member state to prevent storage bloat and stale state
-            // from affecting re-added members.
-            Self::clear_member_state(&env, &addr);
-            Self::append_access_audit(
-                &env,
-                symbol_short!("rem_mem"),
-                &caller,
-                Some(addr.clone()),
-                true,
-            );
-            count += 1;
-        }
-        env.storage()
-            .instance()
-            .set(&symbol_short!("MEMBERS"), &members_map);
-        Self::update_storage_stats(&env);
-
-        // Re-validate in-flight proposals after batch removal: strip signatures
-        // from removed members and invalidate proposals that can no longer reach quorum.
-        Self::revalidate_proposals_after_membership_change(&env);
-
-        count
-    }
-
-    pub fn get_access_audit(env: Env, limit: u32) -> Vec<AccessAuditEntry> {
-        let entries: Vec<AccessAuditEntry> = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("ACC_AUDIT"))
-            .unwrap_or_else(|| Vec::new(&env));
-        let n = entries.len().min(limit);
-        let mut out = Vec::new(&env);
-        for i in (entries.len().saturating_sub(n))..entries.len() {
-            if let Some(e) = entries.get(i) {
-                out.push_back(e);
-            }
-        }
-        out
-    }
-
-    // Owner/Admin only: audit data is privacy-sensitive — reveals who accessed
-    // what and when, so Members are excluded from reading the full trail.
-    //
-    // ## Pagination cursor semantics
-    //
-    // `from_index` is the **inclusive** zero-based index of the first entry to
-    // return.  `next_cursor` in the returned page is the index to pass as
-    // `from_index` on the next call.
-    //
-    // **Sentinel value:** when `next_cursor == total` (i.e. equals the length
-    // of the log at the time of the call) there are no more entries.  Callers
-    // MUST stop iterating when `next_cursor >= count` returned by a previous
-    // page, or when the returned page is empty.
-    //
-    // **Clamping rules (no panic on adversarial input):**
-    // - `limit == 0`            → silently promoted to `DEFAULT_AUDIT_PAGE_LIMIT`.
-    // - `limit > MAX_AUDIT_PAGE_LIMIT` → clamped to `MAX_AUDIT_PAGE_LIMIT`.
-    // - `from_index >= total`   → returns an empty page with
-    //                             `next_cursor = total` (end-of-log sentinel).
-    // - `from_index = u32::MAX` → handled by the `>= total` check above; no
-    //                             arithmetic overflow is possible.
-    pub fn get_access_audit_page(
-        env: Env,
-        caller: Address,
-        from_index: u32,
-        limit: u32,
-    ) -> AccessAuditPage {
-        caller.require_auth();
-        Self::require_role_at_least(&env, &caller, FamilyRole::Admin);
-
-        let entries: Vec<AccessAuditEntry> = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("ACC_AUDIT"))
-            .unwrap_or_else(|| Vec::new(&env));
-
-        // Clamp limit: 0 → default, oversized → max.
-        let capped_limit = if limit == 0 {
-            DEFAULT_AUDIT_PAGE_LIMIT
-        } else {
-            limit.min(MAX_AUDIT_PAGE_LIMIT)
-        };
-
-        let total = entries.len();
-
-        // Out-of-range offset: return empty page with end-of-log sentinel so
-        // callers can detect exhaustion without a separate length query.
-        if from_index >= total {
-            return AccessAuditPage {
-                items: Vec::new(&env),
-                next_cursor: total, // sentinel: no more entries
-                count: 0,
-            };
+        // Out-of-range offset: return empty page with end-of-log sentinel so
+        // callers can detect exhaustion without a separate length query.
+        if from_index >= total {
+            return AccessAuditPage {
+                items: Vec::new(&env),
+                next_cursor: total, // sentinel: no more entries
+                count: 0,
+            };
         }
 
         let mut items = Vec::new(&env);
@@ -2941,196 +2792,26 @@ impl FamilyWallet {
         invalidated_count
     }
 
-    /// Enforces the emergency transfer daily volume cap and persists the updated `EM_VOL`.
-    ///
-    /// # Day-boundary rollover
-    ///
-    /// The window is anchored to **UTC midnight** boundaries derived from `EM_LAST`
-    /// (the timestamp of the most-recently completed emergency transfer):
-    ///
-    /// ```text
-    /// is_new_day = (now / 86_400) > (EM_LAST / 86_400)
-    /// ```
-    ///
-    /// When `is_new_day` is true `EM_VOL` is reset to zero before adding `amount`.
-    /// This prevents the sliding-window attack where an attacker splits transfers
-    /// across an artificial 24-hour boundary to reset the counter early and
-    /// effectively drain up to `2 × daily_limit` across two adjacent calls.
-    ///
-    /// # Checked arithmetic
-    ///
-    /// `checked_add` is used instead of `saturating_add`.  An `i128` overflow would
-    /// silently wrap to a value that passes the cap comparison; `checked_add` panics
-    /// instead, treating overflow as a hard protocol error rather than masking it.
-    ///
-    /// # Panics
-    /// - `"Emergency volume arithmetic overflow"` — `current_vol + amount` overflows `i128`.
-    /// - `"Emergency daily limit exceeded"` — accumulated volume would exceed `daily_limit`.
-    fn check_and_update_emergency_volume(env: &Env, now: u64, amount: i128, daily_limit: i128) {
-        const DAY: u64 = 86_400;
-
-        // EM_LAST: timestamp of the last recorded emergency transfer.
-        // Initialized to 0 in `init`; 0 places the last transfer at the Unix epoch
-        // (day 0), so any transfer at timestamp >= 86_400 triggers a fresh window.
-        let last_ts: u64 = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("EM_LAST"))
-            .unwrap_or(0u64);
+    fn execute_transaction_internal(
+        env: &Env,
+        proposer: &Address,
+        tx_type: &TransactionType,
+        data: &TransactionData,
+        auth: ProposerAuth,
+    ) -> u64 {
+        match data {
+            TransactionData::Withdrawal(token, recipient, amount) => {
+                // RE-COMPUTE TIER (CRITICAL FIX)
+                let reg_config_key = Self::get_config_key(TransactionType::RegularWithdrawal);
 
-        // EM_VOL: accumulated volume for the current UTC day.
-        let stored_vol: i128 = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("EM_VOL"))
-            .unwrap_or(0i128);
+                let reg_config: MultiSigConfig = env
+                    .storage()
+                    .instance()
+                    .get(&reg_config_key)
+                    .unwrap_or_else(|| panic!("Regular multi-sig config not found"));
 
-        // Integer division truncates to the start of each UTC day.
-        // e.g. 86_399 / 86_400 = 0  (day 0)
-        //      86_400 / 86_400 = 1  (day 1) ← triggers reset
-        let current_vol = if (now / DAY) > (last_ts / DAY) {
-            0i128 // new UTC day — discard previous window's volume
-        } else {
-            stored_vol
-        };
-
-        // checked_add: overflow is a hard error, not a user-correctable condition.
-        let new_vol = current_vol
-            .checked_add(amount)
-            .unwrap_or_else(|| panic!("Emergency volume arithmetic overflow"));
-
-        if new_vol > daily_limit {
-            panic!("Emergency daily limit exceeded");
-        }
-
-        // Persist updated volume. EM_LAST is written by the caller *after* the
-        // token transfer succeeds, so on the next call this helper sees the correct
-        // "last transfer day" for rollover detection.
-        env.storage()
-            .instance()
-            .set(&symbol_short!("EM_VOL"), &new_vol);
-    }
-
-    fn execute_emergency_transfer_now(
-        env: Env,
-        proposer: Address,
-        token: Address,
-        recipient: Address,
-        amount: i128,
-    ) -> u64 {
-        let config: EmergencyConfig = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("EM_CONF"))
-            .unwrap_or_else(|| panic!("Emergency config not set"));
-
-        if amount > config.max_amount {
-            panic!("Emergency amount exceeds maximum allowed");
-        }
-
-        let now = env.ledger().timestamp();
-        let last_ts: u64 = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("EM_LAST"))
-            .unwrap_or(0u64);
-        if last_ts != 0 && now < last_ts.saturating_add(config.cooldown) {
-            panic!("Emergency transfer cooldown period not elapsed");
-        }
-
-        // Enforce daily volume cap — correct day-boundary rollover + checked arithmetic.
-        Self::check_and_update_emergency_volume(&env, now, amount, config.daily_limit);
-
-        // --- Minimum balance floor -------------------------------------------------
-        //
-        // Invariant: an emergency transfer must never drain the proposer's balance
-        // below `EmergencyConfig.min_balance`. This floor exists so a wallet stays
-        // solvent for recurring obligations (bills, premiums) even during an
-        // emergency drain; if it were unenforced it would be a purely decorative
-        // setting.
-        //
-        // `min_balance == 0` intentionally disables the floor (any non-negative
-        // post-transfer balance is allowed), matching `configure_emergency`'s
-        // validation that only rejects *negative* `min_balance` values.
-        //
-        // TOCTOU safety: this reads `current_balance` from the same `token_client`
-        // (same token address) that `execute_transaction_internal` uses to perform
-        // the actual transfer below, and no external/cross-contract call happens
-        // between this read and that transfer — so there is no window in which the
-        // balance could change between the check and the transfer.
-        //
-        // `checked_sub` (rather than plain `-`) mirrors the daily-volume cap's
-        // checked-arithmetic discipline: an overflow/underflow here must surface as
-        // a hard error rather than silently wrapping and bypassing the floor.
-        let token_client = TokenClient::new(&env, &token);
-        let current_balance = token_client.balance(&proposer);
-        let post_transfer_balance = current_balance
-            .checked_sub(amount)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::MinBalanceViolation));
-        if post_transfer_balance < config.min_balance {
-            panic_with_error!(&env, Error::MinBalanceViolation);
-        }
-
-        RemitwiseEvents::emit(
-            &env,
-            EventCategory::Transaction,
-            EventPriority::High,
-            symbol_short!("em_init"),
-            (proposer.clone(), recipient.clone(), amount),
-        );
-
-        proposer.require_auth();
-        let _ = Self::execute_transaction_internal(
-            &env,
-            &proposer,
-            &TransactionType::EmergencyTransfer,
-            &TransactionData::EmergencyTransfer(token.clone(), recipient.clone(), amount),
-            false,
-        );
-
-        // Avoid storing 0: `get_last_emergency_at` treats 0 as "none", and cooldown logic uses `last_ts != 0`.
-        let ts = env.ledger().timestamp();
-        let store_ts: u64 = if ts == 0 { 1u64 } else { ts };
-        env.storage()
-            .instance()
-            .set(&symbol_short!("EM_LAST"), &store_ts);
-
-        env.events().publish(
-            (symbol_short!("emerg"), EmergencyEvent::TransferExec),
-            (proposer.clone(), recipient.clone(), amount),
-        );
-
-        Self::append_access_audit(
-            &env,
-            symbol_short!("em_exec"),
-            &proposer,
-            Some(recipient.clone()),
-            true,
-        );
-
-        0
-    }
-
-    fn execute_transaction_internal(
-        env: &Env,
-        proposer: &Address,
-        tx_type: &TransactionType,
-        data: &TransactionData,
-        require_auth: bool,
-    ) -> u64 {
-        match data {
-            TransactionData::Withdrawal(token, recipient, amount) => {
-                // RE-COMPUTE TIER (CRITICAL FIX)
-                let reg_config_key = Self::get_config_key(TransactionType::RegularWithdrawal);
-
-                let reg_config: MultiSigConfig = env
-                    .storage()
-                    .instance()
-                    .get(&reg_config_key)
-                    .unwrap_or_else(|| panic!("Regular multi-sig config not found"));
-
-                let limit_i128 = reg_config.spending_limit;
-                let expected_tier = select_withdrawal_tier(*amount, limit_i128);
+                let limit_i128 = reg_config.spending_limit;
+                let expected_tier = select_withdrawal_tier(*amount, limit_i128);
 
                 let expected_tx_type = match expected_tier {
                     WithdrawalTier::Regular => TransactionType::RegularWithdrawal,
@@ -3139,11 +2820,7 @@ impl FamilyWallet {
 
                 // HARD SECURITY CHECK
                 if tx_type != &expected_tx_type {
-                    panic!("Transaction tier mismatch: invalid multisig enforcement");
-                }
-
-                if require_auth {
-                    proposer.require_auth();
+                    panic_with_error!(env, Error::InvalidTransactionType);
                 }
 
                 if let Err(e) = Self::validate_precision_spending_internal(
@@ -3156,8 +2833,7 @@ impl FamilyWallet {
 
                 Self::record_precision_spending(env, proposer, *amount);
 
-                let token_client = TokenClient::new(env, token);
-                token_client.transfer(proposer, recipient, amount);
+                Self::spend_from_proposer(env, proposer, token, recipient, *amount, auth);
 
                 0
             }
@@ -3191,13 +2867,25 @@ impl FamilyWallet {
             }
 
             TransactionData::EmergencyTransfer(token, recipient, amount) => {
-                if require_auth {
-                    proposer.require_auth();
+                if Self::is_vault_token(env, token) {
+                    let floor = env
+                        .storage()
+                        .instance()
+                        .get::<_, EmergencyConfig>(&symbol_short!("EM_CONF"))
+                        .map(|c| c.min_balance)
+                        .unwrap_or(0);
+                    if let Err(e) = Self::debit_vault(env, token, *amount, floor) {
+                        panic_with_error!(env, e);
+                    }
+                    TokenClient::new(env, token).transfer(
+                        &env.current_contract_address(),
+                        recipient,
+                        amount,
+                    );
+                } else {
+                    Self::spend_from_proposer(env, proposer, token, recipient, *amount, auth);
                 }
 
-                let token_client = TokenClient::new(env, token);
-                token_client.transfer(proposer, recipient, amount);
-
                 0
             }
 
@@ -3205,6 +2893,124 @@ impl FamilyWallet {
         }
     }
 
+    /// Move `amount` of the proposer's `token` to `recipient`.
+    ///
+    /// Under `ProposerAuth::Allowance` the transfer draws on the allowance
+    /// granted by `approve_queued_spend`; if it does not cover `amount` (the
+    /// delay was configured after the proposal was made) the proposer must
+    /// authorize the execution instead.
+    fn spend_from_proposer(
+        env: &Env,
+        proposer: &Address,
+        token: &Address,
+        recipient: &Address,
+        amount: i128,
+        auth: ProposerAuth,
+    ) {
+        let token_client = TokenClient::new(env, token);
+        let spender = env.current_contract_address();
+        if auth == ProposerAuth::Allowance && token_client.allowance(proposer, &spender) >= amount {
+            token_client.transfer_from(&spender, proposer, recipient, &amount);
+            return;
+        }
+        if auth != ProposerAuth::Given {
+            proposer.require_auth();
+        }
+        token_client.transfer(proposer, recipient, &amount);
+    }
+
+    /// Let a timelocked proposal spend the proposer's funds without the
+    /// proposer authorizing again at execution time.
+    ///
+    /// Adds the transfer amount to this contract's allowance over the
+    /// proposer's tokens, live until the proposal's grace period could end.
+    /// Proposals that draw on the vault or move no tokens need nothing.
+    fn approve_queued_spend(env: &Env, pending_tx: &PendingTransaction) {
+        let (token, amount) = match &pending_tx.data {
+            TransactionData::Withdrawal(token, _, amount) => (token, *amount),
+            TransactionData::EmergencyTransfer(token, _, amount)
+                if !Self::is_vault_token(env, token) =>
+            {
+                (token, *amount)
+            }
+            _ => return,
+        };
+
+        let live_for = pending_tx
+            .expires_at
+            .saturating_sub(env.ledger().timestamp())
+            .saturating_add(MAX_EXECUTION_DELAY + EXECUTION_GRACE_PERIOD)
+            / LEDGER_CLOSE_SECONDS;
+        let max_ttl = env.storage().max_ttl();
+        let live_until =
+            env.ledger().sequence() + u32::try_from(live_for).unwrap_or(max_ttl).min(max_ttl);
+
+        let token_client = TokenClient::new(env, token);
+        let spender = env.current_contract_address();
+        let current = token_client.allowance(&pending_tx.proposer, &spender);
+        token_client.approve(
+            &pending_tx.proposer,
+            &spender,
+            &current.saturating_add(amount),
+            &live_until,
+        );
+
+        let mut approved: Map<u64, i128> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("TX_ALLOW"))
+            .unwrap_or_else(|| Map::new(env));
+        approved.set(pending_tx.tx_id, amount);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("TX_ALLOW"), &approved);
+    }
+
+    /// Forget the allowance `approve_queued_spend` recorded for `tx_id`,
+    /// returning the approved amount if there was one.
+    fn take_queued_spend(env: &Env, tx_id: u64) -> Option<i128> {
+        let mut approved: Map<u64, i128> =
+            env.storage().instance().get(&symbol_short!("TX_ALLOW"))?;
+        let amount = approved.get(tx_id)?;
+        approved.remove(tx_id);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("TX_ALLOW"), &approved);
+        Some(amount)
+    }
+
+    /// Give back the allowance a proposal was granted at proposal time when
+    /// it is dropped without executing (vetoed, cancelled or expired).
+    ///
+    /// Only the owner of the tokens can lower an allowance, so the contract
+    /// spends it instead by moving the amount from the proposer back to the
+    /// proposer. At most the proposer's current balance can be spent this
+    /// way; any remainder stays approved until it expires.
+    fn release_queued_spend(env: &Env, pending_tx: &PendingTransaction) {
+        let Some(amount) = Self::take_queued_spend(env, pending_tx.tx_id) else {
+            return;
+        };
+        let token = match &pending_tx.data {
+            TransactionData::Withdrawal(token, _, _)
+            | TransactionData::EmergencyTransfer(token, _, _) => token,
+            _ => return,
+        };
+
+        let token_client = TokenClient::new(env, token);
+        let spender = env.current_contract_address();
+        let release = amount
+            .min(token_client.allowance(&pending_tx.proposer, &spender))
+            .min(token_client.balance(&pending_tx.proposer));
+        if release > 0 {
+            token_client.transfer_from(
+                &spender,
+                &pending_tx.proposer,
+                &pending_tx.proposer,
+                &release,
+            );
+        }
+    }
+
     fn get_config_key(tx_type: TransactionType) -> Symbol {
         match tx_type {
             TransactionType::LargeWithdrawal => symbol_short!("MS_WDRAW"),
@@ -3280,13 +3086,31 @@ impl FamilyWallet {
             .unwrap_or_else(|| panic!("Wallet not initialized"));
         let member = members
             .get(caller.clone())
-            .unwrap_or_else(|| panic!("Not a family member"));
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        if Self::role_has_expired(env, caller) {
+            panic_with_error!(env, Error::RoleExpired);
+        }
+        if Self::role_ordinal(member.role) > Self::role_ordinal(min_role) {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+    }
+
+    /// Typed-error counterpart of `require_role_at_least` for
+    /// `Result`-returning entrypoints.
+    fn check_role_at_least(env: &Env, caller: &Address, min_role: FamilyRole) -> Result<(), Error> {
+        let members: Map<Address, FamilyMember> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("MEMBERS"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+        let member = members.get(caller.clone()).ok_or(Error::Unauthorized)?;
         if Self::role_has_expired(env, caller) {
-            panic!("Role has expired");
+            return Err(Error::RoleExpired);
         }
         if Self::role_ordinal(member.role) > Self::role_ordinal(min_role) {
-            panic!("Insufficient role");
+            return Err(Error::Unauthorized);
         }
+        Ok(())
     }
 
     /// Helper to enforce role expiry on admin-level operations.
@@ -3297,10 +3121,10 @@ impl FamilyWallet {
     #[allow(dead_code)]
     fn require_not_expired_admin(env: &Env, caller: &Address) {
         if !Self::is_owner_or_admin(env, caller) {
-            panic!("Only Owner or Admin can perform this operation");
+            panic_with_error!(env, Error::Unauthorized);
         }
         if Self::role_has_expired(env, caller) {
-            panic!("Role has expired");
+            panic_with_error!(env, Error::RoleExpired);
         }
     }
 
@@ -3325,6 +3149,131 @@ impl FamilyWallet {
         }
     }
 
+    #[cfg(any(test, feature = "timelock"))]
+    fn execution_delay(env: &Env, tx_type: TransactionType) -> u64 {
+        env.storage()
+            .instance()
+            .get::<_, Map<TransactionType, u64>>(&symbol_short!("TX_DELAY"))
+            .unwrap_or_else(|| Map::new(env))
+            .get(tx_type)
+            .unwrap_or(0)
+    }
+
+    /// Without the `timelock` feature no delay can be configured, so nothing
+    /// is ever queued.
+    #[cfg(not(any(test, feature = "timelock")))]
+    fn execution_delay(_env: &Env, _tx_type: TransactionType) -> u64 {
+        0
+    }
+
+    /// Place a fully-signed proposal in the timelock queue if its type has an
+    /// execution delay. Extends `expires_at` so the proposal stays executable
+    /// for `EXECUTION_GRACE_PERIOD` after the delay elapses.
+    ///
+    /// Returns `false` (and leaves `pending_tx` untouched) when no delay is
+    /// configured and the caller should execute immediately.
+    fn queue_if_timelocked(env: &Env, pending_tx: &mut PendingTransaction) -> bool {
+        let delay = Self::execution_delay(env, pending_tx.tx_type);
+        if delay == 0 {
+            return false;
+        }
+
+        let now = env.ledger().timestamp();
+        let executable_at = now.saturating_add(delay);
+        let grace_end = executable_at.saturating_add(EXECUTION_GRACE_PERIOD);
+        if pending_tx.expires_at < grace_end {
+            pending_tx.expires_at = grace_end;
+        }
+
+        let mut queue: Map<u64, u64> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("TX_QUEUE"))
+            .unwrap_or_else(|| Map::new(env));
+        queue.set(pending_tx.tx_id, executable_at);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("TX_QUEUE"), &queue);
+
+        RemitwiseEvents::emit(
+            env,
+            EventCategory::Transaction,
+            EventPriority::High,
+            symbol_short!("tx_queued"),
+            TransactionQueuedEvent {
+                tx_id: pending_tx.tx_id,
+                tx_type: pending_tx.tx_type,
+                executable_at,
+                timestamp: now,
+            },
+        );
+        true
+    }
+
+    fn dequeue_transaction(env: &Env, tx_id: u64) {
+        let mut queue: Map<u64, u64> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("TX_QUEUE"))
+            .unwrap_or_else(|| Map::new(env));
+        if queue.contains_key(tx_id) {
+            queue.remove(tx_id);
+            env.storage()
+                .instance()
+                .set(&symbol_short!("TX_QUEUE"), &queue);
+        }
+    }
+
+    /// Move an executed proposal from `PEND_TXS` into `EXEC_TXS`.
+    fn record_executed_transaction(
+        env: &Env,
+        pending_txs: &mut Map<u64, PendingTransaction>,
+        pending_tx: &PendingTransaction,
+    ) {
+        Self::take_queued_spend(env, pending_tx.tx_id);
+        pending_txs.remove(pending_tx.tx_id);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("PEND_TXS"), pending_txs);
+
+        let mut executed_txs: Map<u64, ExecutedTxMeta> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("EXEC_TXS"))
+            .unwrap_or_else(|| panic!("Executed transactions map not initialized"));
+
+        executed_txs.set(
+            pending_tx.tx_id,
+            ExecutedTxMeta {
+                tx_id: pending_tx.tx_id,
+                tx_type: pending_tx.tx_type,
+                proposer: pending_tx.proposer.clone(),
+                executed_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage()
+            .instance()
+            .set(&symbol_short!("EXEC_TXS"), &executed_txs);
+    }
+
+    /// Refresh the owner's last-activity timestamp used by the inactivity
+    /// policy. No-op unless `caller` is the owner and a policy is configured.
+    #[cfg(any(test, feature = "inheritance"))]
+    fn record_owner_activity(env: &Env, caller: &Address) {
+        if !env.storage().instance().has(&symbol_short!("INH_CONF")) {
+            return;
+        }
+        let owner: Option<Address> = env.storage().instance().get(&symbol_short!("OWNER"));
+        if owner.as_ref() == Some(caller) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("OWN_ACT"), &env.ledger().timestamp());
+        }
+    }
+
+    #[cfg(not(any(test, feature = "inheritance")))]
+    fn record_owner_activity(_env: &Env, _caller: &Address) {}
+
     fn append_access_audit(
         env: &Env,
         operation: Symbol,
@@ -3332,6 +3281,9 @@ impl FamilyWallet {
         target: Option<Address>,
         success: bool,
     ) {
+        if success {
+            Self::record_owner_activity(env, caller);
+        }
         let mut entries: Vec<AccessAuditEntry> = env
             .storage()
             .instance()
@@ -3358,217 +3310,6 @@ impl FamilyWallet {
             .set(&symbol_short!("ACC_AUDIT"), &entries);
     }
 
-    /// Capture a pre-upgrade snapshot of critical instance storage.
-    ///
-    /// Call this before performing a contract upgrade. The snapshot is stored
-    /// under `SNAPSHOT_KEY` in persistent storage and can be restored via
-    /// `restore_from_snapshot` if the upgrade needs to be rolled back.
-    ///
-    /// # Authorization
-    /// Only the upgrade admin (or owner if no upgrade admin is set) may take
-    /// a snapshot.
-    ///
-    /// # Panics
-    /// - If the wallet is not initialized
-    /// - If `caller` lacks authorization
-    pub fn pre_upgrade(env: Env, caller: Address) -> bool {
-        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
-        }
-        caller.require_auth();
-        let owner: Address = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("OWNER"))
-            .unwrap_or_else(|| panic!("Wallet not initialized"));
-        let admin = Self::get_upgrade_admin(&env).unwrap_or(owner.clone());
-        if admin != caller {
-            panic!("Unauthorized");
-        }
-        Self::extend_instance_ttl(&env);
-        let em_config: EmergencyConfig = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("EM_CONF"))
-            .unwrap_or_else(|| panic!("Wallet not initialized"));
-        let snapshot = PreUpgradeSnapshot {
-            schema_version: SNAPSHOT_VERSION,
-            owner: owner.clone(),
-            paused: Self::get_global_paused(&env),
-            pause_admin: Self::get_pause_admin(&env),
-            version: Self::get_version(env.clone()),
-            upgrade_admin: Self::get_upgrade_admin(&env),
-            emergency_mode: env
-                .storage()
-                .instance()
-                .get(&symbol_short!("EM_MODE"))
-                .unwrap_or(false),
-            emergency_config: em_config,
-            emergency_last: env
-                .storage()
-                .instance()
-                .get(&symbol_short!("EM_LAST"))
-                .unwrap_or(0),
-            next_tx: env
-                .storage()
-                .instance()
-                .get(&symbol_short!("NEXT_TX"))
-                .unwrap_or(1),
-            proposal_expiry: env
-                .storage()
-                .instance()
-                .get(&symbol_short!("PROP_EXP"))
-                .unwrap_or(DEFAULT_PROPOSAL_EXPIRY),
-        };
-        env.storage().persistent().set(&SNAPSHOT_KEY, &snapshot);
-        env.storage()
-            .persistent()
-            .set(&symbol_short!("SNAP_TS"), &env.ledger().timestamp());
-        env.events().publish(
-            (symbol_short!("family"), symbol_short!("snap_pre")),
-            SNAPSHOT_VERSION,
-        );
-        true
-    }
-
-    /// Restore critical instance storage from a pre-upgrade snapshot.
-    ///
-    /// Reads the snapshot stored by `pre_upgrade` and writes the captured
-    /// owner, pause state, version, upgrade admin, emergency config, next
-    /// transaction ID, and proposal expiry back to instance storage.
-    /// The snapshot is consumed after a successful restore.
-    ///
-    /// # Authorization
-    /// Only the upgrade admin (or owner if no upgrade admin is set) may
-    /// restore from a snapshot.
-    ///
-    /// # Panics
-    /// - If no snapshot exists
-    /// - If the snapshot version is unsupported
-    /// - If `caller` lacks authorization
-    ///
-    /// # Events
-    /// Emits `(symbol_short!("family"), symbol_short!("snap_rst"))`.
-    pub fn restore_from_snapshot(env: Env, caller: Address) -> bool {
-        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
-        }
-        caller.require_auth();
-        let owner: Address = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("OWNER"))
-            .unwrap_or_else(|| panic!("Wallet not initialized"));
-        let admin = Self::get_upgrade_admin(&env).unwrap_or(owner.clone());
-        if admin != caller {
-            panic!("Unauthorized");
-        }
-        let snapshot: PreUpgradeSnapshot = env
-            .storage()
-            .persistent()
-            .get(&SNAPSHOT_KEY)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
-        if snapshot.schema_version != SNAPSHOT_VERSION {
-            panic_with_error!(&env, Error::Unauthorized);
-        }
-        let snapshot_taken_at: u64 = env
-            .storage()
-            .persistent()
-            .get(&symbol_short!("SNAP_TS"))
-            .unwrap_or(0);
-        if remitwise_common::require_recent_snapshot(&env, snapshot_taken_at).is_err() {
-            panic_with_error!(&env, Error::SnapshotTooOld);
-        }
-        if snapshot.owner != owner {
-            panic!("Snapshot owner mismatch");
-        }
-        Self::extend_instance_ttl(&env);
-
-        // Restore pause state
-        env.storage()
-            .instance()
-            .set(&symbol_short!("PAUSED"), &snapshot.paused);
-        match &snapshot.pause_admin {
-            Some(addr) => env
-                .storage()
-                .instance()
-                .set(&symbol_short!("PAUSE_ADM"), addr),
-            None => env.storage().instance().remove(&symbol_short!("PAUSE_ADM")),
-        }
-
-        // Restore version
-        env.storage()
-            .instance()
-            .set(&symbol_short!("VERSION"), &snapshot.version);
-
-        // Restore upgrade admin
-        match &snapshot.upgrade_admin {
-            Some(addr) => env
-                .storage()
-                .instance()
-                .set(&symbol_short!("UPG_ADM"), addr),
-            None => env.storage().instance().remove(&symbol_short!("UPG_ADM")),
-        }
-
-        // Restore emergency config
-        env.storage()
-            .instance()
-            .set(&symbol_short!("EM_CONF"), &snapshot.emergency_config);
-        env.storage()
-            .instance()
-            .set(&symbol_short!("EM_MODE"), &snapshot.emergency_mode);
-        env.storage()
-            .instance()
-            .set(&symbol_short!("EM_LAST"), &snapshot.emergency_last);
-
-        // Restore next TX and proposal expiry
-        env.storage()
-            .instance()
-            .set(&symbol_short!("NEXT_TX"), &snapshot.next_tx);
-        env.storage()
-            .instance()
-            .set(&symbol_short!("PROP_EXP"), &snapshot.proposal_expiry);
-
-        // Consume the snapshot
-        env.storage().persistent().remove(&SNAPSHOT_KEY);
-
-        env.events().publish(
-            (symbol_short!("family"), symbol_short!("snap_rst")),
-            snapshot.version,
-        );
-        true
-    }
-
-    /// Discard a pre-upgrade snapshot without restoring it.
-    ///
-    /// Use after a successful upgrade to free persistent storage.
-    ///
-    /// # Authorization
-    /// Only the upgrade admin (or owner if no upgrade admin is set) may
-    /// discard a snapshot.
-    ///
-    /// # Panics
-    /// - If `caller` lacks authorization
-    pub fn discard_snapshot(env: Env, caller: Address) -> bool {
-        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
-        }
-        caller.require_auth();
-        let owner: Address = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("OWNER"))
-            .unwrap_or_else(|| panic!("Wallet not initialized"));
-        let admin = Self::get_upgrade_admin(&env).unwrap_or(owner);
-        if admin != caller {
-            panic!("Unauthorized");
-        }
-        env.storage().persistent().remove(&SNAPSHOT_KEY);
-        env.events()
-            .publish((symbol_short!("family"), symbol_short!("snap_dsc")), ());
-        true
-    }
-
     fn get_pause_admin(env: &Env) -> Option<Address> {
         env.storage().instance().get(&symbol_short!("PAUSE_ADM"))
     }
@@ -3582,7 +3323,7 @@ impl FamilyWallet {
 
     fn require_not_paused(env: &Env) {
         if Self::get_global_paused(env) {
-            panic!("Contract is paused");
+            panic_with_error!(env, Error::ContractPaused);
         }
     }
 
@@ -3610,12 +3351,6 @@ impl FamilyWallet {
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    fn extend_archive_ttl(env: &Env) {
-        env.storage()
-            .instance()
-            .extend_ttl(ARCHIVE_LIFETIME_THRESHOLD, ARCHIVE_BUMP_AMOUNT);
-    }
-
     /// Clear all per-member state maps for a removed member.
     ///
     /// Removes entries from ROLE_EXP, PREC_LIM, and SPND_TRK to prevent:
@@ -3705,6 +3440,2300 @@ impl FamilyWallet {
     }
 }
 
+// -----------------------------------------------------------------------
+// Emergency transfers (`emergency` feature, on by default)
+// -----------------------------------------------------------------------
+#[cfg(any(test, feature = "emergency"))]
+#[contractimpl]
+impl FamilyWallet {
+    /// Propose or execute an emergency transfer.
+    ///
+    /// In emergency mode the transfer executes immediately, subject to the
+    /// `EmergencyConfig` guardrails; otherwise it becomes a multisig proposal.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` if `amount` is not positive
+    /// - `EmergencyAmountExceeded`, `EmergencyCooldownActive`,
+    ///   `EmergencyDailyLimitExceeded`, `MinBalanceViolation` in emergency mode
+    /// - `DuplicateEmergencyProposal`, `EmergencyProposalLimitReached` otherwise
+    ///
+    /// Fails with `Error::ContractPaused` if the contract is paused.
+    pub fn propose_emergency_transfer(
+        env: Env,
+        proposer: Address,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<u64, Error> {
+        Self::require_not_paused(&env);
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let em_mode: bool = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("EM_MODE"))
+            .unwrap_or(false);
+
+        if em_mode {
+            return Self::execute_emergency_transfer_now(env, proposer, token, recipient, amount);
+        }
+
+        let pending_txs: Map<u64, PendingTransaction> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("PEND_TXS"))
+            .unwrap_or_else(|| Map::new(&env));
+
+        let mut active_proposals = 0;
+        for (_, tx) in pending_txs.iter() {
+            if tx.proposer == proposer && tx.tx_type == TransactionType::EmergencyTransfer {
+                if let TransactionData::EmergencyTransfer(t, r, a) = &tx.data {
+                    if t == &token && r == &recipient && *a == amount {
+                        return Err(Error::DuplicateEmergencyProposal);
+                    }
+                }
+                active_proposals += 1;
+            }
+        }
+
+        if active_proposals >= 1 {
+            return Err(Error::EmergencyProposalLimitReached);
+        }
+
+        let tx_id = Self::propose_transaction(
+            env.clone(),
+            proposer.clone(),
+            TransactionType::EmergencyTransfer,
+            TransactionData::EmergencyTransfer(token.clone(), recipient.clone(), amount),
+        );
+
+        Self::append_access_audit(
+            &env,
+            symbol_short!("em_prop"),
+            &proposer,
+            Some(recipient.clone()),
+            true,
+        );
+
+        Ok(tx_id)
+    }
+
+    /// Configure emergency transfer guardrails.
+    ///
+    /// Only `Owner` or `Admin` may update emergency settings.
+    /// Successful configuration is recorded in the access audit trail.
+    ///
+    /// # Errors
+    /// - `Unauthorized` if `caller` is not an unexpired Owner or Admin
+    /// - `InvalidEmergencyConfig` if `max_amount <= 0`, `min_balance < 0`
+    ///   or `daily_limit < 0`
+    pub fn configure_emergency(
+        env: Env,
+        caller: Address,
+        max_amount: i128,
+        cooldown: u64,
+        min_balance: i128,
+        daily_limit: i128,
+    ) -> Result<bool, Error> {
+        caller.require_auth();
+        Self::require_not_paused(&env);
+
+        Self::require_governance_ok(&env, &caller)?;
+        if max_amount <= 0 || min_balance < 0 || daily_limit < 0 {
+            return Err(Error::InvalidEmergencyConfig);
+        }
+
+        Self::extend_instance_ttl(&env);
+
+        env.storage().instance().set(
+            &symbol_short!("EM_CONF"),
+            &EmergencyConfig {
+                max_amount,
+                cooldown,
+                min_balance,
+                daily_limit,
+            },
+        );
+
+        Self::append_access_audit(&env, symbol_short!("em_conf"), &caller, None, true);
+
+        Ok(true)
+    }
+
+    /// Enable or disable emergency mode.
+    ///
+    /// This operation is restricted to `Owner` or `Admin` and is recorded in the access audit trail.
+    ///
+    /// # Errors
+    /// `Unauthorized` if `caller` is not an unexpired Owner or Admin.
+    pub fn set_emergency_mode(env: Env, caller: Address, enabled: bool) -> Result<bool, Error> {
+        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
+            return Ok(false);
+        }
+        caller.require_auth();
+        Self::require_not_paused(&env);
+
+        Self::require_governance_ok(&env, &caller)?;
+
+        Self::extend_instance_ttl(&env);
+
+        env.storage()
+            .instance()
+            .set(&symbol_short!("EM_MODE"), &enabled);
+
+        let event = if enabled {
+            EmergencyEvent::ModeOn
+        } else {
+            EmergencyEvent::ModeOff
+        };
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::System,
+            EventPriority::High,
+            symbol_short!("em_mode"),
+            event,
+        );
+
+        Self::append_access_audit(&env, symbol_short!("em_mode"), &caller, None, true);
+
+        Ok(true)
+    }
+
+    pub fn get_emergency_config(env: Env) -> Option<EmergencyConfig> {
+        env.storage().instance().get(&symbol_short!("EM_CONF"))
+    }
+
+    pub fn is_emergency_mode(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("EM_MODE"))
+            .unwrap_or(false)
+    }
+
+    pub fn get_last_emergency_at(env: Env) -> Option<u64> {
+        let ts: u64 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("EM_LAST"))
+            .unwrap_or(0u64);
+        if ts == 0 {
+            None
+        } else {
+            Some(ts)
+        }
+    }
+
+    /// Enforces the emergency transfer daily volume cap and persists the updated `EM_VOL`.
+    ///
+    /// # Day-boundary rollover
+    ///
+    /// The window is anchored to **UTC midnight** boundaries derived from `EM_LAST`
+    /// (the timestamp of the most-recently completed emergency transfer):
+    ///
+    /// ```text
+    /// is_new_day = (now / 86_400) > (EM_LAST / 86_400)
+    /// ```
+    ///
+    /// When `is_new_day` is true `EM_VOL` is reset to zero before adding `amount`.
+    /// This prevents the sliding-window attack where an attacker splits transfers
+    /// across an artificial 24-hour boundary to reset the counter early and
+    /// effectively drain up to `2 × daily_limit` across two adjacent calls.
+    ///
+    /// # Checked arithmetic
+    ///
+    /// `checked_add` is used instead of `saturating_add`.  An `i128` overflow would
+    /// silently wrap to a value that passes the cap comparison; `checked_add` panics
+    /// instead, treating overflow as a hard protocol error rather than masking it.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` — `current_vol + amount` overflows `i128`.
+    /// - `EmergencyDailyLimitExceeded` — accumulated volume would exceed `daily_limit`.
+    fn check_and_update_emergency_volume(
+        env: &Env,
+        now: u64,
+        amount: i128,
+        daily_limit: i128,
+    ) -> Result<(), Error> {
+        const DAY: u64 = 86_400;
+
+        // EM_LAST: timestamp of the last recorded emergency transfer.
+        // Initialized to 0 in `init`; 0 places the last transfer at the Unix epoch
+        // (day 0), so any transfer at timestamp >= 86_400 triggers a fresh window.
+        let last_ts: u64 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("EM_LAST"))
+            .unwrap_or(0u64);
+
+        // EM_VOL: accumulated volume for the current UTC day.
+        let stored_vol: i128 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("EM_VOL"))
+            .unwrap_or(0i128);
+
+        // Integer division truncates to the start of each UTC day.
+        // e.g. 86_399 / 86_400 = 0  (day 0)
+        //      86_400 / 86_400 = 1  (day 1) ← triggers reset
+        let current_vol = if (now / DAY) > (last_ts / DAY) {
+            0i128 // new UTC day — discard previous window's volume
+        } else {
+            stored_vol
+        };
+
+        // checked_add: overflow is a hard error, not a user-correctable condition.
+        let new_vol = current_vol
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+
+        if new_vol > daily_limit {
+            return Err(Error::EmergencyDailyLimitExceeded);
+        }
+
+        // Persist updated volume. EM_LAST is written by the caller *after* the
+        // token transfer succeeds, so on the next call this helper sees the correct
+        // "last transfer day" for rollover detection.
+        env.storage()
+            .instance()
+            .set(&symbol_short!("EM_VOL"), &new_vol);
+        Ok(())
+    }
+
+    fn execute_emergency_transfer_now(
+        env: Env,
+        proposer: Address,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<u64, Error> {
+        let config: EmergencyConfig = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("EM_CONF"))
+            .ok_or(Error::EmergencyConfigNotSet)?;
+
+        if amount > config.max_amount {
+            return Err(Error::EmergencyAmountExceeded);
+        }
+
+        let now = env.ledger().timestamp();
+        let last_ts: u64 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("EM_LAST"))
+            .unwrap_or(0u64);
+        if last_ts != 0 && now < last_ts.saturating_add(config.cooldown) {
+            return Err(Error::EmergencyCooldownActive);
+        }
+
+        // Enforce daily volume cap — correct day-boundary rollover + checked arithmetic.
+        Self::check_and_update_emergency_volume(&env, now, amount, config.daily_limit)?;
+
+        // --- Minimum balance floor -------------------------------------------------
+        //
+        // Invariant: an emergency transfer must never drain the source balance
+        // below `EmergencyConfig.min_balance`. For vault-routed tokens the
+        // source is the tracked pooled balance; otherwise it is the proposer's
+        // own token balance. This floor exists so a wallet stays
+        // solvent for recurring obligations (bills, premiums) even during an
+        // emergency drain; if it were unenforced it would be a purely decorative
+        // setting.
+        //
+        // `min_balance == 0` intentionally disables the floor (any non-negative
+        // post-transfer balance is allowed), matching `configure_emergency`'s
+        // validation that only rejects *negative* `min_balance` values.
+        //
+        // TOCTOU safety: this reads `current_balance` from the same `token_client`
+        // (same token address) that `execute_transaction_internal` uses to perform
+        // the actual transfer below, and no external/cross-contract call happens
+        // between this read and that transfer — so there is no window in which the
+        // balance could change between the check and the transfer.
+        //
+        // `checked_sub` (rather than plain `-`) mirrors the daily-volume cap's
+        // checked-arithmetic discipline: an overflow/underflow here must surface as
+        // a hard error rather than silently wrapping and bypassing the floor.
+        let current_balance = if Self::is_vault_token(&env, &token) {
+            Self::vault_balances(&env).get(token.clone()).unwrap_or(0)
+        } else {
+            TokenClient::new(&env, &token).balance(&proposer)
+        };
+        let post_transfer_balance = current_balance
+            .checked_sub(amount)
+            .ok_or(Error::MinBalanceViolation)?;
+        if post_transfer_balance < config.min_balance {
+            return Err(Error::MinBalanceViolation);
+        }
+
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Transaction,
+            EventPriority::High,
+            symbol_short!("em_init"),
+            (proposer.clone(), recipient.clone(), amount),
+        );
+
+        proposer.require_auth();
+        let _ = Self::execute_transaction_internal(
+            &env,
+            &proposer,
+            &TransactionType::EmergencyTransfer,
+            &TransactionData::EmergencyTransfer(token.clone(), recipient.clone(), amount),
+            ProposerAuth::Given,
+        );
+
+        // Avoid storing 0: `get_last_emergency_at` treats 0 as "none", and cooldown logic uses `last_ts != 0`.
+        let ts = env.ledger().timestamp();
+        let store_ts: u64 = if ts == 0 { 1u64 } else { ts };
+        env.storage()
+            .instance()
+            .set(&symbol_short!("EM_LAST"), &store_ts);
+
+        env.events().publish(
+            (symbol_short!("emerg"), EmergencyEvent::TransferExec),
+            (proposer.clone(), recipient.clone(), amount),
+        );
+
+        Self::append_access_audit(
+            &env,
+            symbol_short!("em_exec"),
+            &proposer,
+            Some(recipient.clone()),
+            true,
+        );
+
+        Ok(0)
+    }
+}
+
+// -----------------------------------------------------------------------
+// Transaction archive (`archive` feature, on by default)
+// -----------------------------------------------------------------------
+#[cfg(any(test, feature = "archive"))]
+#[contractimpl]
+impl FamilyWallet {
+    /// Moves **eligible** multisig-executed transactions from `EXEC_TXS` into `ARCH_TX`.
+    ///
+    /// # Semantics
+    /// - `before_timestamp` is a **retention cutoff** (ledger seconds): a row is archived iff
+    ///   `executed_at < before_timestamp` (strictly less-than — entries executed *at* the cutoff
+    ///   are **not** archived, preserving the most recent boundary entry in `EXEC_TXS`).
+    /// - The cutoff must satisfy `before_timestamp <= ledger timestamp`. A future cutoff would
+    ///   treat recent executions as "old" relative to an incorrect clock and could archive too much.
+    ///
+    /// # Bounded growth invariant
+    /// `ARCH_TX` is capped at `MAX_ARCHIVE_ENTRIES`. Before inserting each new entry, if the
+    /// archive is already at capacity the entry with the **lowest `tx_id`** (oldest) is evicted.
+    /// This keeps instance-storage rent bounded regardless of how many transactions are executed
+    /// over the contract's lifetime.
+    ///
+    /// # Authorization
+    /// Owner or Admin only (`caller.require_auth()`).
+    ///
+    /// # Data integrity
+    /// Archived rows copy **proposer**, **tx_type**, and **executed_at** from `ExecutedTxMeta`.
+    /// If `meta.tx_id != map_key`, the contract panics to avoid corrupting the archive.
+    ///
+    /// # Returns
+    /// The number of transactions moved from `EXEC_TXS` to `ARCH_TX` in this call.
+    pub fn archive_old_transactions(env: Env, caller: Address, before_timestamp: u64) -> u32 {
+        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
+            return 0;
+        }
+        caller.require_auth();
+        Self::require_not_paused(&env);
+
+        if !Self::is_owner_or_admin(&env, &caller) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+
+        Self::extend_instance_ttl(&env);
+
+        let now = env.ledger().timestamp();
+        if before_timestamp > now {
+            panic_with_error!(&env, AdminError::InvalidArchiveCutoff);
+        }
+
+        let mut executed_txs: Map<u64, ExecutedTxMeta> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("EXEC_TXS"))
+            .unwrap_or_else(|| Map::new(&env));
+
+        let mut archived: Map<u64, ArchivedTransaction> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("ARCH_TX"))
+            .unwrap_or_else(|| Map::new(&env));
+
+        let current_time = env.ledger().timestamp();
+        let mut archived_count = 0u32;
+        let mut to_remove: Vec<u64> = Vec::new(&env);
+
+        // Pre-compute archive length and oldest tx_id once, before the main loop.
+        // This avoids O(n²) nested iteration which exhausts the Soroban WASM budget.
+        let mut arch_len = 0u32;
+        let mut oldest_arch_id: Option<u64> = None;
+        for (aid, _) in archived.iter() {
+            arch_len += 1;
+            oldest_arch_id = Some(match oldest_arch_id {
+                None => aid,
+                Some(prev) => {
+                    if aid < prev {
+                        aid
+                    } else {
+                        prev
+                    }
+                }
+            });
+        }
+
+        for (tx_id, meta) in executed_txs.iter() {
+            if meta.tx_id != tx_id {
+                panic!("Inconsistent executed transaction metadata");
+            }
+            // Strictly less-than: entries executed AT before_timestamp are retained.
+            if meta.executed_at < before_timestamp {
+                // Enforce the archive size cap: evict the oldest entry (lowest tx_id)
+                // before inserting so ARCH_TX never exceeds MAX_ARCHIVE_ENTRIES.
+                if arch_len >= MAX_ARCHIVE_ENTRIES {
+                    if let Some(oid) = oldest_arch_id {
+                        archived.remove(oid);
+                        // After eviction, find the new oldest from the remaining entries.
+                        let mut new_oldest: Option<u64> = None;
+                        for (aid, _) in archived.iter() {
+                            new_oldest = Some(match new_oldest {
+                                None => aid,
+                                Some(prev) => {
+                                    if aid < prev {
+                                        aid
+                                    } else {
+                                        prev
+                                    }
+                                }
+                            });
+                        }
+                        oldest_arch_id = new_oldest;
+                        // arch_len stays the same: we removed one and will add one below.
+                    }
+                } else {
+                    arch_len += 1;
+                    // Update oldest_arch_id if this new entry is older (lower tx_id).
+                    oldest_arch_id = Some(match oldest_arch_id {
+                        None => tx_id,
+                        Some(prev) => {
+                            if tx_id < prev {
+                                tx_id
+                            } else {
+                                prev
+                            }
+                        }
+                    });
+                }
+
+                let archived_tx = ArchivedTransaction {
+                    tx_id: meta.tx_id,
+                    tx_type: meta.tx_type,
+                    proposer: meta.proposer.clone(),
+                    executed_at: meta.executed_at,
+                    archived_at: current_time,
+                };
+                archived.set(tx_id, archived_tx);
+                to_remove.push_back(tx_id);
+                archived_count += 1;
+            }
+        }
+
+        for i in 0..to_remove.len() {
+            if let Some(id) = to_remove.get(i) {
+                executed_txs.remove(id);
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&symbol_short!("EXEC_TXS"), &executed_txs);
+
+        env.storage()
+            .instance()
+            .set(&symbol_short!("ARCH_TX"), &archived);
+
+        Self::extend_archive_ttl(&env);
+        Self::update_storage_stats(&env);
+
+        env.events().publish(
+            (symbol_short!("archive"), ArchiveEvent::TransactionsArchived),
+            (archived_count, caller.clone()),
+        );
+
+        Self::append_access_audit(&env, symbol_short!("arch_tx"), &caller, None, true);
+
+        archived_count
+    }
+
+    /// Returns a page of archived transactions ordered by ascending `tx_id`.
+    ///
+    /// # Parameters
+    /// - `limit`: entries to return; `0` → `DEFAULT_ARCHIVE_PAGE_LIMIT`; clamped to
+    ///   `MAX_ARCHIVE_PAGE_LIMIT`. Ordering follows the map's natural key order (ascending `tx_id`).
+    ///
+    /// # Authorization
+    /// Only Owner or Admin. Requires `caller.require_auth()` to prevent unauthenticated reads
+    /// of historical transaction metadata (ownership / privacy leakage).
+    pub fn get_archived_transactions(
+        env: Env,
+        caller: Address,
+        limit: u32,
+    ) -> Vec<ArchivedTransaction> {
+        caller.require_auth();
+        if !Self::is_owner_or_admin(&env, &caller) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+
+        // Clamp limit: 0 → default, >max → max.
+        let effective_limit = if limit == 0 {
+            DEFAULT_ARCHIVE_PAGE_LIMIT
+        } else if limit > MAX_ARCHIVE_PAGE_LIMIT {
+            MAX_ARCHIVE_PAGE_LIMIT
+        } else {
+            limit
+        };
+
+        let archived: Map<u64, ArchivedTransaction> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("ARCH_TX"))
+            .unwrap_or_else(|| Map::new(&env));
+
+        let mut result = Vec::new(&env);
+        for (count, (_, tx)) in archived.iter().enumerate() {
+            if count as u32 >= effective_limit {
+                break;
+            }
+            result.push_back(tx);
+        }
+        result
+    }
+
+    fn extend_archive_ttl(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(ARCHIVE_LIFETIME_THRESHOLD, ARCHIVE_BUMP_AMOUNT);
+    }
+}
+
+// -----------------------------------------------------------------------
+// Batch membership changes (`batch_members` feature, on by default)
+// -----------------------------------------------------------------------
+#[cfg(any(test, feature = "batch_members"))]
+#[contractimpl]
+impl FamilyWallet {
+    /// Add a batch of family members atomically.
+    ///
+    /// Semantics:
+    /// - The whole batch succeeds or the whole batch fails.
+    /// - Empty batches are accepted and return `0`.
+    /// - Any duplicate address in the batch, pre-existing member, owner-role item,
+    ///   or batch that would exceed the family-member cap aborts the entire call.
+    /// - On success, the return value is the number of members added.
+    pub fn batch_add_family_members(
+        env: Env,
+        caller: Address,
+        members: Vec<BatchMemberItem>,
+    ) -> u32 {
+        caller.require_auth();
+        Self::require_role_at_least(&env, &caller, FamilyRole::Admin);
+        Self::require_not_paused(&env);
+        if members.len() > MAX_BATCH_MEMBERS {
+            panic_with_error!(&env, AdminError::BatchTooLarge);
+        }
+        Self::extend_instance_ttl(&env);
+
+        let mut members_map: Map<Address, FamilyMember> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("MEMBERS"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+
+        let mut current_member_count = 0u32;
+        for _ in members_map.iter() {
+            current_member_count += 1;
+        }
+
+        let mut seen_addrs: Map<Address, bool> = Map::new(&env);
+        let mut additions = 0u32;
+        for item in members.iter() {
+            if item.role == FamilyRole::Owner {
+                panic_with_error!(&env, Error::InvalidRole);
+            }
+            if seen_addrs.get(item.address.clone()).is_some() {
+                panic_with_error!(&env, AdminError::DuplicateMember);
+            }
+            seen_addrs.set(item.address.clone(), true);
+            if members_map.get(item.address.clone()).is_some() {
+                panic_with_error!(&env, Error::MemberAlreadyExists);
+            }
+            additions += 1;
+        }
+
+        if current_member_count + additions > MAX_FAMILY_MEMBERS {
+            panic_with_error!(&env, AdminError::MemberCapExceeded);
+        }
+
+        let timestamp = env.ledger().timestamp();
+        let mut count = 0u32;
+        for item in members.iter() {
+            members_map.set(
+                item.address.clone(),
+                FamilyMember {
+                    address: item.address.clone(),
+                    role: item.role,
+                    spending_limit: 0,
+                    precision_limit: PrecisionLimitOpt::None,
+                    added_at: timestamp,
+                },
+            );
+            RemitwiseEvents::emit(
+                &env,
+                EventCategory::Access,
+                EventPriority::High,
+                symbol_short!("role_grnt"),
+                RoleGrantedEvent {
+                    member: item.address.clone(),
+                    role: item.role,
+                    timestamp,
+                },
+            );
+            Self::append_access_audit(
+                &env,
+                symbol_short!("add_mem"),
+                &caller,
+                Some(item.address.clone()),
+                true,
+            );
+            count += 1;
+        }
+        env.storage()
+            .instance()
+            .set(&symbol_short!("MEMBERS"), &members_map);
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Access,
+            EventPriority::Medium,
+            symbol_short!("batch_mem"),
+            count,
+        );
+        Self::update_storage_stats(&env);
+        count
+    }
+
+    /// Remove a batch of family members atomically.
+    ///
+    /// Semantics:
+    /// - The whole batch succeeds or the whole batch fails.
+    /// - Empty batches are accepted and return `0`.
+    /// - Any duplicate address in the batch, missing member, or attempt to remove
+    ///   the owner aborts the entire call.
+    /// - On success, the return value is the number of members removed.
+    pub fn batch_remove_family_members(env: Env, caller: Address, addresses: Vec<Address>) -> u32 {
+        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
+            return 0;
+        }
+        caller.require_auth();
+        Self::require_role_at_least(&env, &caller, FamilyRole::Owner);
+        let owner: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("OWNER"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+        if caller != owner {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        Self::require_not_paused(&env);
+        if addresses.len() > MAX_BATCH_MEMBERS {
+            panic_with_error!(&env, AdminError::BatchTooLarge);
+        }
+        Self::extend_instance_ttl(&env);
+        let mut members_map: Map<Address, FamilyMember> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("MEMBERS"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+
+        // Validate the whole batch before touching state so a bad entry
+        // cannot leave a partial removal behind.
+        let mut seen_addrs: Map<Address, bool> = Map::new(&env);
+        for addr in addresses.iter() {
+            if addr.clone() == owner {
+                panic_with_error!(&env, Error::CannotRemoveOwner);
+            }
+            if seen_addrs.get(addr.clone()).is_some() {
+                panic_with_error!(&env, AdminError::DuplicateMember);
+            }
+            seen_addrs.set(addr.clone(), true);
+            if members_map.get(addr.clone()).is_none() {
+                panic_with_error!(&env, Error::MemberNotFound);
+            }
+        }
+
+        let mut count = 0u32;
+        for addr in addresses.iter() {
+            if let Some(removed_member) = members_map.get(addr.clone()) {
+                members_map.remove(addr.clone());
+                // Clear all per-member state to prevent storage bloat and stale
+                // state from affecting re-added members.
+                Self::clear_member_state(&env, &addr);
+                Self::clear_sub_accounts(&env, &addr);
+
+                RemitwiseEvents::emit(
+                    &env,
+                    EventCategory::Access,
+                    EventPriority::High,
+                    symbol_short!("role_revk"),
+                    RoleRevokedEvent {
+                        member: addr.clone(),
+                        role: removed_member.role,
+                        timestamp: env.ledger().timestamp(),
+                    },
+                );
+
+                Self::append_access_audit(
+                    &env,
+                    symbol_short!("rem_mem"),
+                    &caller,
+                    Some(addr.clone()),
+                    true,
+                );
+                count += 1;
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&symbol_short!("MEMBERS"), &members_map);
+        Self::update_storage_stats(&env);
+
+        // Re-validate in-flight proposals after batch removal: strip signatures
+        // from removed members and invalidate proposals that can no longer reach quorum.
+        Self::revalidate_proposals_after_membership_change(&env);
+
+        count
+    }
+}
+
+// -----------------------------------------------------------------------
+// Pre-upgrade snapshots (`upgrade_snapshots` feature, on by default)
+// -----------------------------------------------------------------------
+#[cfg(any(test, feature = "upgrade_snapshots"))]
+#[contractimpl]
+impl FamilyWallet {
+    /// Capture a pre-upgrade snapshot of critical instance storage.
+    ///
+    /// Call this before performing a contract upgrade. The snapshot is stored
+    /// under `SNAPSHOT_KEY` in persistent storage and can be restored via
+    /// `restore_from_snapshot` if the upgrade needs to be rolled back.
+    ///
+    /// # Authorization
+    /// Only the upgrade admin (or owner if no upgrade admin is set) may take
+    /// a snapshot.
+    ///
+    /// # Panics
+    /// - If the wallet is not initialized
+    /// - `Error::Unauthorized` if `caller` lacks authorization
+    pub fn pre_upgrade(env: Env, caller: Address) -> bool {
+        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
+            return false;
+        }
+        caller.require_auth();
+        let owner: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("OWNER"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+        let admin = Self::get_upgrade_admin(&env).unwrap_or(owner.clone());
+        if admin != caller {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        Self::extend_instance_ttl(&env);
+        let em_config: EmergencyConfig = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("EM_CONF"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+        let snapshot = PreUpgradeSnapshot {
+            schema_version: SNAPSHOT_VERSION,
+            owner: owner.clone(),
+            paused: Self::get_global_paused(&env),
+            pause_admin: Self::get_pause_admin(&env),
+            version: Self::get_version(env.clone()),
+            upgrade_admin: Self::get_upgrade_admin(&env),
+            emergency_mode: env
+                .storage()
+                .instance()
+                .get(&symbol_short!("EM_MODE"))
+                .unwrap_or(false),
+            emergency_config: em_config,
+            emergency_last: env
+                .storage()
+                .instance()
+                .get(&symbol_short!("EM_LAST"))
+                .unwrap_or(0),
+            next_tx: env
+                .storage()
+                .instance()
+                .get(&symbol_short!("NEXT_TX"))
+                .unwrap_or(1),
+            proposal_expiry: env
+                .storage()
+                .instance()
+                .get(&symbol_short!("PROP_EXP"))
+                .unwrap_or(DEFAULT_PROPOSAL_EXPIRY),
+        };
+        env.storage().persistent().set(&SNAPSHOT_KEY, &snapshot);
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("SNAP_TS"), &env.ledger().timestamp());
+        env.events().publish(
+            (symbol_short!("family"), symbol_short!("snap_pre")),
+            SNAPSHOT_VERSION,
+        );
+        true
+    }
+
+    /// Restore critical instance storage from a pre-upgrade snapshot.
+    ///
+    /// Reads the snapshot stored by `pre_upgrade` and writes the captured
+    /// owner, pause state, version, upgrade admin, emergency config, next
+    /// transaction ID, and proposal expiry back to instance storage.
+    /// The snapshot is consumed after a successful restore.
+    ///
+    /// # Authorization
+    /// Only the upgrade admin (or owner if no upgrade admin is set) may
+    /// restore from a snapshot.
+    ///
+    /// # Panics
+    /// - If no snapshot exists
+    /// - If the snapshot version is unsupported
+    /// - `Error::Unauthorized` if `caller` lacks authorization
+    ///
+    /// # Events
+    /// Emits `(symbol_short!("family"), symbol_short!("snap_rst"))`.
+    pub fn restore_from_snapshot(env: Env, caller: Address) -> bool {
+        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
+            return false;
+        }
+        caller.require_auth();
+        let owner: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("OWNER"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+        let admin = Self::get_upgrade_admin(&env).unwrap_or(owner.clone());
+        if admin != caller {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        let snapshot: PreUpgradeSnapshot = env
+            .storage()
+            .persistent()
+            .get(&SNAPSHOT_KEY)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
+        if snapshot.schema_version != SNAPSHOT_VERSION {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        let snapshot_taken_at: u64 = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("SNAP_TS"))
+            .unwrap_or(0);
+        if remitwise_common::require_recent_snapshot(&env, snapshot_taken_at).is_err() {
+            panic_with_error!(&env, Error::SnapshotTooOld);
+        }
+        if snapshot.owner != owner {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        Self::extend_instance_ttl(&env);
+
+        // Restore pause state
+        env.storage()
+            .instance()
+            .set(&symbol_short!("PAUSED"), &snapshot.paused);
+        match &snapshot.pause_admin {
+            Some(addr) => env
+                .storage()
+                .instance()
+                .set(&symbol_short!("PAUSE_ADM"), addr),
+            None => env.storage().instance().remove(&symbol_short!("PAUSE_ADM")),
+        }
+
+        // Restore version
+        env.storage()
+            .instance()
+            .set(&symbol_short!("VERSION"), &snapshot.version);
+
+        // Restore upgrade admin
+        match &snapshot.upgrade_admin {
+            Some(addr) => env
+                .storage()
+                .instance()
+                .set(&symbol_short!("UPG_ADM"), addr),
+            None => env.storage().instance().remove(&symbol_short!("UPG_ADM")),
+        }
+
+        // Restore emergency config
+        env.storage()
+            .instance()
+            .set(&symbol_short!("EM_CONF"), &snapshot.emergency_config);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("EM_MODE"), &snapshot.emergency_mode);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("EM_LAST"), &snapshot.emergency_last);
+
+        // Restore next TX and proposal expiry
+        env.storage()
+            .instance()
+            .set(&symbol_short!("NEXT_TX"), &snapshot.next_tx);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("PROP_EXP"), &snapshot.proposal_expiry);
+
+        // Consume the snapshot
+        env.storage().persistent().remove(&SNAPSHOT_KEY);
+
+        env.events().publish(
+            (symbol_short!("family"), symbol_short!("snap_rst")),
+            snapshot.version,
+        );
+        true
+    }
+
+    /// Discard a pre-upgrade snapshot without restoring it.
+    ///
+    /// Use after a successful upgrade to free persistent storage.
+    ///
+    /// # Authorization
+    /// Only the upgrade admin (or owner if no upgrade admin is set) may
+    /// discard a snapshot.
+    ///
+    /// # Panics
+    /// - `Error::Unauthorized` if `caller` lacks authorization
+    pub fn discard_snapshot(env: Env, caller: Address) -> bool {
+        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
+            return false;
+        }
+        caller.require_auth();
+        let owner: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("OWNER"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+        let admin = Self::get_upgrade_admin(&env).unwrap_or(owner);
+        if admin != caller {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        env.storage().persistent().remove(&SNAPSHOT_KEY);
+        env.events()
+            .publish((symbol_short!("family"), symbol_short!("snap_dsc")), ());
+        true
+    }
+}
+
+// -----------------------------------------------------------------------
+// Member invitations (`invitations` feature)
+// -----------------------------------------------------------------------
+#[cfg(any(test, feature = "invitations"))]
+#[contractimpl]
+impl FamilyWallet {
+    /// Invite `invitee` to join the wallet with `role` and `spending_limit`.
+    ///
+    /// Unlike `add_family_member`, nothing changes until the invitee calls
+    /// `accept_invitation` with their own signature, so a mistyped address
+    /// never becomes a member. The invitation lapses `expires_in` seconds
+    /// from now and can be withdrawn earlier with `revoke_invitation`.
+    /// Each call also prunes up to `MAX_INVITATION_PRUNE` lapsed invitations,
+    /// oldest first.
+    ///
+    /// # Returns
+    /// The invitation ID.
+    ///
+    /// # Errors
+    /// - `Unauthorized` if `caller` is not an Owner or Admin
+    /// - `InvalidRole` if `role` is `Owner`
+    /// - `InvalidSpendingLimit` if `spending_limit` is negative
+    /// - `InvalidExpiry` unless `0 < expires_in <= MAX_INVITATION_TTL`
+    /// - `MemberAlreadyExists` if `invitee` is already a member
+    /// - `AlreadyPending` if `invitee` already holds a live invitation
+    pub fn create_invitation(
+        env: Env,
+        caller: Address,
+        invitee: Address,
+        role: FamilyRole,
+        spending_limit: i128,
+        expires_in: u64,
+    ) -> Result<u64, InvitationError> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        caller.require_auth();
+        Self::require_not_paused(&env);
+        if !Self::is_owner_or_admin(&env, &caller) {
+            return Err(InvitationError::Unauthorized);
+        }
+        if role == FamilyRole::Owner {
+            return Err(InvitationError::InvalidRole);
+        }
+        if spending_limit < 0 {
+            return Err(InvitationError::InvalidSpendingLimit);
+        }
+        if expires_in == 0 || expires_in > MAX_INVITATION_TTL {
+            return Err(InvitationError::InvalidExpiry);
+        }
+        if Self::is_family_member(&env, &invitee) {
+            return Err(InvitationError::MemberAlreadyExists);
+        }
+
+        let now = env.ledger().timestamp();
+        let pending_key = (symbol_short!("INV_FOR"), invitee.clone());
+        let pending_id: Option<u64> = env.storage().persistent().get(&pending_key);
+        if let Some(existing) = pending_id.and_then(|id| Self::load_invitation(&env, id)) {
+            if existing.expires_at > now {
+                return Err(InvitationError::AlreadyPending);
+            }
+            Self::remove_invitation(&env, &existing);
+        }
+
+        Self::extend_instance_ttl(&env);
+
+        let id: u64 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("INV_NEXT"))
+            .unwrap_or(1u64);
+        Self::prune_invitations(&env, now, id);
+
+        let invitation = Invitation {
+            id,
+            invitee: invitee.clone(),
+            role,
+            spending_limit,
+            invited_by: caller.clone(),
+            created_at: now,
+            expires_at: now.saturating_add(expires_in),
+        };
+        // Keep both entries live at least until the invitation lapses.
+        let ttl = (expires_in / LEDGER_CLOSE_SECONDS + 1) as u32;
+        let storage = env.storage().persistent();
+        storage.set(&Self::invitation_key(id), &invitation);
+        storage.set(&pending_key, &id);
+        storage.extend_ttl(&Self::invitation_key(id), ttl, ttl);
+        storage.extend_ttl(&pending_key, ttl, ttl);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("INV_NEXT"), &(id + 1));
+
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Access,
+            EventPriority::Medium,
+            symbol_short!("inv_new"),
+            (id, invitee.clone(), role),
+        );
+        Self::append_access_audit(&env, symbol_short!("inv_new"), &caller, Some(invitee), true);
+
+        Ok(id)
+    }
+
+    /// Accept an invitation addressed to `invitee`, joining the wallet with
+    /// the invited role and spending limit.
+    ///
+    /// # Errors
+    /// - `NotFound` if the invitation does not exist or was revoked
+    /// - `Unauthorized` if the invitation is addressed to someone else
+    /// - `Expired` once `expires_at` has passed
+    /// - `InviterRevoked` if the inviter has since been removed, demoted or
+    ///   has an expired role
+    /// - `MemberAlreadyExists` if `invitee` joined by other means meanwhile
+    /// - `MemberCapReached` if the wallet already has `MAX_FAMILY_MEMBERS` members
+    pub fn accept_invitation(
+        env: Env,
+        invitee: Address,
+        invitation_id: u64,
+    ) -> Result<bool, InvitationError> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        invitee.require_auth();
+        Self::require_not_paused(&env);
+
+        let invitation =
+            Self::load_invitation(&env, invitation_id).ok_or(InvitationError::NotFound)?;
+        if invitation.invitee != invitee {
+            return Err(InvitationError::Unauthorized);
+        }
+        let now = env.ledger().timestamp();
+        if now >= invitation.expires_at {
+            return Err(InvitationError::Expired);
+        }
+
+        let mut members: Map<Address, FamilyMember> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("MEMBERS"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+        if !Self::is_owner_or_admin_in_members(&env, &members, &invitation.invited_by) {
+            return Err(InvitationError::InviterRevoked);
+        }
+        if members.contains_key(invitee.clone()) {
+            return Err(InvitationError::MemberAlreadyExists);
+        }
+        if members.len() >= MAX_FAMILY_MEMBERS {
+            return Err(InvitationError::MemberCapReached);
+        }
+
+        Self::extend_instance_ttl(&env);
+
+        members.set(
+            invitee.clone(),
+            FamilyMember {
+                address: invitee.clone(),
+                role: invitation.role,
+                spending_limit: invitation.spending_limit,
+                precision_limit: PrecisionLimitOpt::None,
+                added_at: now,
+            },
+        );
+        env.storage()
+            .instance()
+            .set(&symbol_short!("MEMBERS"), &members);
+
+        Self::remove_invitation(&env, &invitation);
+
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Access,
+            EventPriority::High,
+            symbol_short!("role_grnt"),
+            RoleGrantedEvent {
+                member: invitee.clone(),
+                role: invitation.role,
+                timestamp: now,
+            },
+        );
+        Self::append_access_audit(
+            &env,
+            symbol_short!("inv_acc"),
+            &invitee,
+            Some(invitation.invited_by),
+            true,
+        );
+
+        Ok(true)
+    }
+
+    /// Withdraw a pending or expired invitation.
+    ///
+    /// # Errors
+    /// - `Unauthorized` if `caller` is not an Owner or Admin
+    /// - `NotFound` if the invitation does not exist
+    pub fn revoke_invitation(
+        env: Env,
+        caller: Address,
+        invitation_id: u64,
+    ) -> Result<bool, InvitationError> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        caller.require_auth();
+        Self::require_not_paused(&env);
+        if !Self::is_owner_or_admin(&env, &caller) {
+            return Err(InvitationError::Unauthorized);
+        }
+
+        let invitation =
+            Self::load_invitation(&env, invitation_id).ok_or(InvitationError::NotFound)?;
+        Self::remove_invitation(&env, &invitation);
+
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Access,
+            EventPriority::Medium,
+            symbol_short!("inv_rev"),
+            (invitation_id, invitation.invitee.clone()),
+        );
+        Self::append_access_audit(
+            &env,
+            symbol_short!("inv_rev"),
+            &caller,
+            Some(invitation.invitee),
+            true,
+        );
+
+        Ok(true)
+    }
+
+    /// Look up one invitation.
+    ///
+    /// Visibility matches `get_invitations_page`: Owners and Admins see any
+    /// invitation, anyone else only those addressed to them.
+    pub fn get_invitation(env: Env, caller: Address, invitation_id: u64) -> Option<Invitation> {
+        caller.require_auth();
+        let invitation = Self::load_invitation(&env, invitation_id)?;
+        if invitation.invitee == caller || Self::is_owner_or_admin(&env, &caller) {
+            Some(invitation)
+        } else {
+            None
+        }
+    }
+
+    /// Page through stored invitations in ascending ID order.
+    ///
+    /// Owners and Admins see every invitation; anyone else sees only the
+    /// invitations addressed to them. Expired invitations stay listed (with
+    /// their `expires_at`) until revoked or pruned. Cursor semantics match
+    /// `get_pending_transactions_page`: pass the returned `next_cursor`, which
+    /// is `0` once the last page has been read. Each call examines at most
+    /// `MAX_INVITATION_SCAN` IDs, so a page may come back short (even empty)
+    /// with a non-zero cursor.
+    pub fn get_invitations_page(
+        env: Env,
+        caller: Address,
+        cursor: u64,
+        limit: u32,
+    ) -> InvitationPage {
+        caller.require_auth();
+
+        let capped_limit = if limit == 0 {
+            DEFAULT_MEMBER_PAGE_LIMIT
+        } else {
+            limit.min(MAX_MEMBER_PAGE_LIMIT)
+        };
+
+        let next_id: u64 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("INV_NEXT"))
+            .unwrap_or(1u64);
+        let is_admin = Self::is_owner_or_admin(&env, &caller);
+
+        let mut items: Vec<Invitation> = Vec::new(&env);
+        let mut id = cursor.saturating_add(1);
+        let scan_end = id.saturating_add(MAX_INVITATION_SCAN).min(next_id);
+        while id < scan_end && items.len() < capped_limit {
+            if let Some(invitation) = Self::load_invitation(&env, id) {
+                if is_admin || invitation.invitee == caller {
+                    items.push_back(invitation);
+                }
+            }
+            id = id.saturating_add(1);
+        }
+
+        // `id - 1` is the last ID examined; resume after it.
+        let next_cursor = if id < next_id { id - 1 } else { 0u64 };
+        let count = items.len();
+
+        InvitationPage {
+            items,
+            next_cursor,
+            count,
+        }
+    }
+
+    fn invitation_key(id: u64) -> (Symbol, u64) {
+        (symbol_short!("INVITE"), id)
+    }
+
+    fn load_invitation(env: &Env, id: u64) -> Option<Invitation> {
+        env.storage().persistent().get(&Self::invitation_key(id))
+    }
+
+    /// Delete an invitation and, if it still points here, its invitee index.
+    fn remove_invitation(env: &Env, invitation: &Invitation) {
+        let storage = env.storage().persistent();
+        storage.remove(&Self::invitation_key(invitation.id));
+        let pending_key = (symbol_short!("INV_FOR"), invitation.invitee.clone());
+        if storage.get::<_, u64>(&pending_key) == Some(invitation.id) {
+            storage.remove(&pending_key);
+        }
+    }
+
+    /// Drop lapsed invitations starting at the `INV_PRUNE` cursor, examining
+    /// at most `MAX_INVITATION_PRUNE` IDs below `next_id`. The cursor only
+    /// advances past IDs that are gone, so a live invitation holds it until
+    /// it is accepted, revoked or lapses.
+    fn prune_invitations(env: &Env, now: u64, next_id: u64) {
+        let start: u64 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("INV_PRUNE"))
+            .unwrap_or(1u64);
+        let end = start.saturating_add(MAX_INVITATION_PRUNE).min(next_id);
+        let mut cursor = start;
+        for id in start..end {
+            let gone = match Self::load_invitation(env, id) {
+                Some(invitation) if invitation.expires_at > now => false,
+                Some(invitation) => {
+                    Self::remove_invitation(env, &invitation);
+                    true
+                }
+                None => true,
+            };
+            if gone && cursor == id {
+                cursor = id + 1;
+            }
+        }
+        if cursor != start {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("INV_PRUNE"), &cursor);
+        }
+    }
+}
+
+// -----------------------------------------------------------------------
+// Execution timelock (`timelock` feature, on by default)
+// -----------------------------------------------------------------------
+#[cfg(any(test, feature = "timelock"))]
+#[contractimpl]
+impl FamilyWallet {
+    /// Set the execution delay (timelock) in seconds for a transaction type.
+    ///
+    /// Once a proposal of this type reaches its signature threshold it is
+    /// queued instead of executed. During the delay any Owner may
+    /// `veto_transaction`; afterwards anyone may `execute_queued_transaction`
+    /// within `EXECUTION_GRACE_PERIOD`.
+    ///
+    /// A value of `0` disables the timelock (approved proposals execute
+    /// immediately).
+    ///
+    /// # Errors
+    /// - `Unauthorized` if `caller` is not the Owner
+    /// - `RoleExpired` if the Owner's role has expired
+    /// - `InvalidExecutionDelay` if `delay` exceeds `MAX_EXECUTION_DELAY`
+    ///
+    /// Fails with `Error::ContractPaused` if the contract is paused.
+    pub fn set_execution_delay(
+        env: Env,
+        caller: Address,
+        tx_type: TransactionType,
+        delay: u64,
+    ) -> Result<(), Error> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        caller.require_auth();
+        Self::require_not_paused(&env);
+        let owner: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("OWNER"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+
+        if caller != owner {
+            return Err(Error::Unauthorized);
+        }
+        if Self::role_has_expired(&env, &caller) {
+            return Err(Error::RoleExpired);
+        }
+        if delay > MAX_EXECUTION_DELAY {
+            return Err(Error::InvalidExecutionDelay);
+        }
+
+        let mut delays: Map<TransactionType, u64> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("TX_DELAY"))
+            .unwrap_or_else(|| Map::new(&env));
+        if delay == 0 {
+            delays.remove(tx_type);
+        } else {
+            delays.set(tx_type, delay);
+        }
+        env.storage()
+            .instance()
+            .set(&symbol_short!("TX_DELAY"), &delays);
+        Self::append_access_audit(&env, symbol_short!("tx_delay"), &caller, None, true);
+        Ok(())
+    }
+
+    /// Return the configured execution delay for `tx_type` (`0` when unset).
+    pub fn get_execution_delay(env: Env, tx_type: TransactionType) -> u64 {
+        Self::execution_delay(&env, tx_type)
+    }
+
+    /// Veto a queued transaction during its execution delay.
+    ///
+    /// The allowance the proposer granted this contract when proposing is
+    /// spent back to the proposer, so the vetoed amount is no longer approved.
+    ///
+    /// # Authorization
+    /// Any member holding the `Owner` role whose role has not expired.
+    ///
+    /// # Errors
+    /// - `Unauthorized` if `caller` is not an active Owner
+    /// - `TransactionNotQueued` if `tx_id` is not waiting in the queue
+    pub fn veto_transaction(env: Env, caller: Address, tx_id: u64) -> Result<bool, Error> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        caller.require_auth();
+
+        let members: Map<Address, FamilyMember> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("MEMBERS"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+        let is_owner = members
+            .get(caller.clone())
+            .map(|m| m.role == FamilyRole::Owner)
+            .unwrap_or(false);
+        if !is_owner || Self::role_has_expired(&env, &caller) {
+            return Err(Error::Unauthorized);
+        }
+        if Self::get_transaction_eta(env.clone(), tx_id).is_none() {
+            return Err(Error::TransactionNotQueued);
+        }
+
+        Self::extend_instance_ttl(&env);
+
+        let mut pending_txs: Map<u64, PendingTransaction> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("PEND_TXS"))
+            .unwrap_or_else(|| Map::new(&env));
+        if let Some(pending_tx) = pending_txs.get(tx_id) {
+            Self::release_queued_spend(&env, &pending_tx);
+        }
+        pending_txs.remove(tx_id);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("PEND_TXS"), &pending_txs);
+        Self::dequeue_transaction(&env, tx_id);
+
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Access,
+            EventPriority::High,
+            symbol_short!("tx_veto"),
+            (tx_id, caller.clone()),
+        );
+        Self::append_access_audit(&env, symbol_short!("tx_veto"), &caller, None, true);
+
+        Ok(true)
+    }
+
+    /// Execute a queued transaction once its execution delay has elapsed.
+    ///
+    /// Permissionless: anyone may trigger execution after the ETA. Transfers
+    /// of the proposer's funds draw on the allowance the proposer granted this
+    /// contract when proposing; only if the delay was configured after the
+    /// proposal (so no allowance exists) must the proposer authorize this call.
+    ///
+    /// # Errors
+    /// - `TransactionNotFound` if `tx_id` is not pending
+    /// - `TransactionNotQueued` if it has not reached threshold under a timelock
+    /// - `TimelockNotElapsed` before the queued ETA
+    /// - `TransactionExpired` after the grace period or once invalidated
+    pub fn execute_queued_transaction(env: Env, tx_id: u64) -> Result<bool, Error> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        Self::require_not_paused(&env);
+
+        let mut pending_txs: Map<u64, PendingTransaction> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("PEND_TXS"))
+            .unwrap_or_else(|| Map::new(&env));
+        let pending_tx = pending_txs.get(tx_id).ok_or(Error::TransactionNotFound)?;
+        let eta =
+            Self::get_transaction_eta(env.clone(), tx_id).ok_or(Error::TransactionNotQueued)?;
+
+        let now = env.ledger().timestamp();
+        if now < eta {
+            return Err(Error::TimelockNotElapsed);
+        }
+        if now > pending_tx.expires_at {
+            return Err(Error::TransactionExpired);
+        }
+
+        Self::extend_instance_ttl(&env);
+        Self::dequeue_transaction(&env, tx_id);
+
+        let executed = Self::execute_transaction_internal(
+            &env,
+            &pending_tx.proposer,
+            &pending_tx.tx_type,
+            &pending_tx.data,
+            ProposerAuth::Allowance,
+        );
+        if executed == 0 {
+            Self::record_executed_transaction(&env, &mut pending_txs, &pending_tx);
+        }
+
+        Ok(true)
+    }
+}
+
+// -----------------------------------------------------------------------
+// Inheritance / dead-man switch (`inheritance` feature)
+// -----------------------------------------------------------------------
+#[cfg(any(test, feature = "inheritance"))]
+#[contractimpl]
+impl FamilyWallet {
+    /// Configure the opt-in owner-inactivity (dead-man switch) policy.
+    ///
+    /// Every authenticated action by the owner (any audited operation,
+    /// proposing or signing a transaction, or `heartbeat`) resets the
+    /// inactivity clock. Once `inactivity_period` seconds pass without one,
+    /// `successor` may call `claim_ownership` and each beneficiary may call
+    /// `claim_inheritance_share`.
+    ///
+    /// Reconfiguring resets the clock and any partially-completed
+    /// beneficiary distribution.
+    ///
+    /// When both a successor and beneficiaries are set, the two race once the
+    /// deadline passes: `claim_ownership` consumes the policy, so beneficiaries
+    /// lose any token none of them has claimed yet. Once one beneficiary has
+    /// claimed a token, `claim_ownership` is refused until every beneficiary
+    /// has claimed that token too.
+    ///
+    /// # Authorization
+    /// Owner only; the owner's role must not be expired.
+    ///
+    /// # Errors
+    /// - `Unauthorized` if `caller` is not the owner
+    /// - `InvalidInheritanceConfig` if `inactivity_period` is outside
+    ///   `MIN_INACTIVITY_PERIOD..=MAX_INACTIVITY_PERIOD`, `warning_window` is
+    ///   not shorter than `inactivity_period`, neither a successor nor
+    ///   beneficiaries are given, the successor is the owner or not a family
+    ///   member, or the beneficiary shares are duplicated, zero, more than
+    ///   `MAX_BENEFICIARIES`, or do not sum to `BASIS_POINTS`
+    pub fn configure_inheritance(
+        env: Env,
+        caller: Address,
+        inactivity_period: u64,
+        warning_window: u64,
+        successor: Option<Address>,
+        beneficiaries: Vec<InheritanceShare>,
+    ) -> Result<bool, Error> {
+        caller.require_auth();
+        Self::require_not_paused(&env);
+
+        let owner = Self::get_owner(env.clone());
+        if caller != owner || Self::role_has_expired(&env, &caller) {
+            return Err(Error::Unauthorized);
+        }
+
+        if !(MIN_INACTIVITY_PERIOD..=MAX_INACTIVITY_PERIOD).contains(&inactivity_period)
+            || warning_window >= inactivity_period
+        {
+            return Err(Error::InvalidInheritanceConfig);
+        }
+        if successor.is_none() && beneficiaries.is_empty() {
+            return Err(Error::InvalidInheritanceConfig);
+        }
+        if let Some(s) = &successor {
+            if *s == owner || !Self::is_family_member(&env, s) {
+                return Err(Error::InvalidInheritanceConfig);
+            }
+        }
+        if beneficiaries.len() > MAX_BENEFICIARIES {
+            return Err(Error::InvalidInheritanceConfig);
+        }
+
+        let mut seen: Map<Address, bool> = Map::new(&env);
+        let mut total_bps: u32 = 0;
+        for share in beneficiaries.iter() {
+            if share.share_bps == 0
+                || share.beneficiary == owner
+                || seen.contains_key(share.beneficiary.clone())
+            {
+                return Err(Error::InvalidInheritanceConfig);
+            }
+            seen.set(share.beneficiary.clone(), true);
+            total_bps = total_bps
+                .checked_add(share.share_bps)
+                .ok_or(Error::InvalidInheritanceConfig)?;
+        }
+        if !beneficiaries.is_empty() && total_bps != BASIS_POINTS {
+            return Err(Error::InvalidInheritanceConfig);
+        }
+
+        Self::extend_instance_ttl(&env);
+
+        env.storage().instance().set(
+            &symbol_short!("INH_CONF"),
+            &InheritanceConfig {
+                inactivity_period,
+                warning_window,
+                successor,
+                beneficiaries,
+            },
+        );
+        env.storage()
+            .instance()
+            .set(&symbol_short!("OWN_ACT"), &env.ledger().timestamp());
+        env.storage().instance().remove(&symbol_short!("INH_SNAP"));
+        env.storage().instance().remove(&symbol_short!("INH_PAID"));
+
+        Self::append_access_audit(&env, symbol_short!("inh_conf"), &caller, None, true);
+
+        Ok(true)
+    }
+
+    /// Remove the inactivity policy. Owner only.
+    pub fn clear_inheritance(env: Env, caller: Address) -> Result<bool, Error> {
+        caller.require_auth();
+
+        let owner = Self::get_owner(env.clone());
+        if caller != owner || Self::role_has_expired(&env, &caller) {
+            return Err(Error::Unauthorized);
+        }
+        if !env.storage().instance().has(&symbol_short!("INH_CONF")) {
+            return Err(Error::InheritanceNotConfigured);
+        }
+
+        Self::extend_instance_ttl(&env);
+        Self::clear_inheritance_trigger(&env);
+
+        Self::append_access_audit(&env, symbol_short!("inh_clr"), &caller, None, true);
+
+        Ok(true)
+    }
+
+    /// Owner liveness proof: resets the inactivity clock without any other
+    /// side effect. Allowed while paused so an owner can always keep the
+    /// dead-man switch from firing.
+    ///
+    /// # Returns
+    /// The new inactivity deadline.
+    pub fn heartbeat(env: Env, caller: Address) -> Result<u64, Error> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        caller.require_auth();
+
+        let owner = Self::get_owner(env.clone());
+        if caller != owner {
+            return Err(Error::Unauthorized);
+        }
+        let config: InheritanceConfig = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("INH_CONF"))
+            .ok_or(Error::InheritanceNotConfigured)?;
+
+        Self::extend_instance_ttl(&env);
+        Self::record_owner_activity(&env, &caller);
+
+        let now = env.ledger().timestamp();
+        let deadline = now.saturating_add(config.inactivity_period);
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::State,
+            EventPriority::Low,
+            symbol_short!("heartbeat"),
+            (caller, deadline),
+        );
+
+        Ok(deadline)
+    }
+
+    pub fn get_inheritance_config(env: Env) -> Option<InheritanceConfig> {
+        env.storage().instance().get(&symbol_short!("INH_CONF"))
+    }
+
+    /// Amount already paid to `beneficiary` in `token`, or `None` if that
+    /// share has not been claimed. Survives `claim_ownership` and
+    /// `clear_inheritance`; a fresh `configure_inheritance` starts a new
+    /// payout round.
+    pub fn get_inheritance_payout(env: Env, token: Address, beneficiary: Address) -> Option<i128> {
+        let paid: Map<(Address, Address), i128> =
+            env.storage().instance().get(&symbol_short!("INH_PAID"))?;
+        paid.get((token, beneficiary))
+    }
+
+    /// Read-only view of the inactivity clock, or `None` if no policy is set.
+    pub fn get_inheritance_status(env: Env) -> Option<InheritanceStatus> {
+        let config: InheritanceConfig = env.storage().instance().get(&symbol_short!("INH_CONF"))?;
+        Some(Self::inheritance_status(&env, &config))
+    }
+
+    /// Permissionless keeper poke for the inactivity policy.
+    ///
+    /// Emits `inh_warn` while the ledger is inside the warning window and
+    /// `inh_due` once the deadline has passed, so off-chain monitors can
+    /// alert the owner and inheritors. Returns the same status as
+    /// `get_inheritance_status`.
+    pub fn check_inheritance(env: Env) -> Option<InheritanceStatus> {
+        let config: InheritanceConfig = env.storage().instance().get(&symbol_short!("INH_CONF"))?;
+        let status = Self::inheritance_status(&env, &config);
+        let now = env.ledger().timestamp();
+
+        if status.claimable {
+            RemitwiseEvents::emit(
+                &env,
+                EventCategory::Alert,
+                EventPriority::Critical,
+                symbol_short!("inh_due"),
+                InheritanceWarningEvent {
+                    deadline: status.deadline,
+                    seconds_remaining: 0,
+                    timestamp: now,
+                },
+            );
+        } else if status.in_warning_window {
+            RemitwiseEvents::emit(
+                &env,
+                EventCategory::Alert,
+                EventPriority::High,
+                symbol_short!("inh_warn"),
+                InheritanceWarningEvent {
+                    deadline: status.deadline,
+                    seconds_remaining: Timestamp::seconds_until(now, status.deadline),
+                    timestamp: now,
+                },
+            );
+        }
+
+        Some(status)
+    }
+
+    /// Claim the Owner role after the owner's inactivity deadline has passed.
+    ///
+    /// The successor is promoted to `Owner` (any role expiry is cleared) and
+    /// the previous owner is demoted to `Member`. Pause and upgrade admin
+    /// slots held by the previous owner are cleared so they fall back to the
+    /// new owner. The inactivity policy is consumed; the new owner must
+    /// configure a fresh one. Beneficiary payout records are kept.
+    ///
+    /// Allowed while paused: the previous owner may have paused the wallet
+    /// and no longer be able to unpause it.
+    ///
+    /// # Errors
+    /// - `InheritanceNotConfigured` if no policy is set
+    /// - `NotInheritor` if `successor` is not the designated successor
+    /// - `InheritanceNotDue` if the deadline has not passed
+    /// - `PendingOperationsExist` if a token's beneficiary shares are only
+    ///   partly withdrawn
+    /// - `Unauthorized` if the successor is no longer a member or its role expired
+    pub fn claim_ownership(env: Env, successor: Address) -> Result<bool, Error> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        successor.require_auth();
+
+        let config: InheritanceConfig = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("INH_CONF"))
+            .ok_or(Error::InheritanceNotConfigured)?;
+        if config.successor.as_ref() != Some(&successor) {
+            return Err(Error::NotInheritor);
+        }
+        if !Self::inheritance_status(&env, &config).claimable {
+            return Err(Error::InheritanceNotDue);
+        }
+        if Self::distribution_pending(&env, &config) {
+            return Err(Error::PendingOperationsExist);
+        }
+        if Self::role_has_expired(&env, &successor) {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut members: Map<Address, FamilyMember> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("MEMBERS"))
+            .unwrap_or_else(|| panic!("Wallet not initialized"));
+        let mut successor_record = members.get(successor.clone()).ok_or(Error::Unauthorized)?;
+
+        let previous_owner = Self::get_owner(env.clone());
+        let now = env.ledger().timestamp();
+
+        Self::extend_instance_ttl(&env);
+
+        if let Some(mut previous) = members.get(previous_owner.clone()) {
+            previous.role = FamilyRole::Member;
+            members.set(previous_owner.clone(), previous);
+        }
+        successor_record.role = FamilyRole::Owner;
+        members.set(successor.clone(), successor_record);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("MEMBERS"), &members);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("OWNER"), &successor);
+
+        let mut role_exp: Map<Address, u64> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("ROLE_EXP"))
+            .unwrap_or_else(|| Map::new(&env));
+        role_exp.remove(successor.clone());
+        env.storage()
+            .instance()
+            .set(&symbol_short!("ROLE_EXP"), &role_exp);
+
+        if Self::get_pause_admin(&env).as_ref() == Some(&previous_owner) {
+            env.storage().instance().remove(&symbol_short!("PAUSE_ADM"));
+        }
+        if Self::get_upgrade_admin(&env).as_ref() == Some(&previous_owner) {
+            env.storage().instance().remove(&symbol_short!("UPG_ADM"));
+        }
+
+        Self::clear_inheritance_trigger(&env);
+
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Access,
+            EventPriority::Critical,
+            symbol_short!("inh_own"),
+            OwnershipClaimedEvent {
+                previous_owner: previous_owner.clone(),
+                new_owner: successor.clone(),
+                timestamp: now,
+            },
+        );
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Access,
+            EventPriority::High,
+            symbol_short!("role_revk"),
+            RoleRevokedEvent {
+                member: previous_owner.clone(),
+                role: FamilyRole::Owner,
+                timestamp: now,
+            },
+        );
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Access,
+            EventPriority::High,
+            symbol_short!("role_grnt"),
+            RoleGrantedEvent {
+                member: successor.clone(),
+                role: FamilyRole::Owner,
+                timestamp: now,
+            },
+        );
+
+        Self::append_access_audit(
+            &env,
+            symbol_short!("inh_own"),
+            &successor,
+            Some(previous_owner),
+            true,
+        );
+
+        Ok(true)
+    }
+
+    /// Withdraw a beneficiary's share of the wallet contract's `token`
+    /// balance after the owner's inactivity deadline has passed.
+    ///
+    /// The first claim for a token snapshots the contract's balance of it,
+    /// less the pooled vault balance deposited by members; every
+    /// beneficiary's payout is `snapshot * share_bps / BASIS_POINTS`,
+    /// so payouts do not depend on claim order. Each beneficiary can claim
+    /// each token once.
+    ///
+    /// # Returns
+    /// The amount transferred to `beneficiary`.
+    ///
+    /// # Errors
+    /// - `InheritanceNotConfigured`, `NotInheritor`, `InheritanceNotDue` as for
+    ///   `claim_ownership`
+    /// - `InheritanceAlreadyClaimed` if this share was already withdrawn
+    /// - `InvalidAmount` on share arithmetic overflow
+    pub fn claim_inheritance_share(
+        env: Env,
+        beneficiary: Address,
+        token: Address,
+    ) -> Result<i128, Error> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        beneficiary.require_auth();
+        Self::require_not_paused(&env);
+
+        let config: InheritanceConfig = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("INH_CONF"))
+            .ok_or(Error::InheritanceNotConfigured)?;
+        let share_bps = config
+            .beneficiaries
+            .iter()
+            .find(|s| s.beneficiary == beneficiary)
+            .map(|s| s.share_bps)
+            .ok_or(Error::NotInheritor)?;
+        if !Self::inheritance_status(&env, &config).claimable {
+            return Err(Error::InheritanceNotDue);
+        }
+
+        let mut paid: Map<(Address, Address), i128> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("INH_PAID"))
+            .unwrap_or_else(|| Map::new(&env));
+        let paid_key = (token.clone(), beneficiary.clone());
+        if paid.contains_key(paid_key.clone()) {
+            return Err(Error::InheritanceAlreadyClaimed);
+        }
+
+        Self::extend_instance_ttl(&env);
+
+        let token_client = TokenClient::new(&env, &token);
+        let mut snapshots: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("INH_SNAP"))
+            .unwrap_or_else(|| Map::new(&env));
+        let snapshot = match snapshots.get(token.clone()) {
+            Some(balance) => balance,
+            None => {
+                // Pooled vault deposits belong to the members who made them;
+                // only the remainder is the owner's to bequeath.
+                let pooled = Self::vault_balances(&env).get(token.clone()).unwrap_or(0);
+                let balance =
+                    (token_client.balance(&env.current_contract_address()) - pooled).max(0);
+                snapshots.set(token.clone(), balance);
+                env.storage()
+                    .instance()
+                    .set(&symbol_short!("INH_SNAP"), &snapshots);
+                balance
+            }
+        };
+
+        let amount = snapshot
+            .checked_mul(share_bps as i128)
+            .ok_or(Error::InvalidAmount)?
+            / BASIS_POINTS as i128;
+
+        // Record the payout before the external transfer.
+        paid.set(paid_key, amount);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("INH_PAID"), &paid);
+
+        if amount > 0 {
+            token_client.transfer(&env.current_contract_address(), &beneficiary, &amount);
+        }
+
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Transaction,
+            EventPriority::High,
+            symbol_short!("inh_pay"),
+            InheritancePaidEvent {
+                beneficiary: beneficiary.clone(),
+                token,
+                amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Self::append_access_audit(&env, symbol_short!("inh_pay"), &beneficiary, None, true);
+
+        Ok(amount)
+    }
+
+    fn inheritance_status(env: &Env, config: &InheritanceConfig) -> InheritanceStatus {
+        let now = env.ledger().timestamp();
+        let last_activity: u64 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("OWN_ACT"))
+            .unwrap_or(now);
+        let deadline = last_activity.saturating_add(config.inactivity_period);
+        let warning_at = deadline.saturating_sub(config.warning_window);
+        InheritanceStatus {
+            last_activity,
+            deadline,
+            warning_at,
+            in_warning_window: config.warning_window > 0 && now >= warning_at && now < deadline,
+            claimable: now >= deadline,
+        }
+    }
+
+    /// Whether some token has a payout snapshot (so at least one beneficiary
+    /// withdrew it) but not every beneficiary has withdrawn it yet.
+    fn distribution_pending(env: &Env, config: &InheritanceConfig) -> bool {
+        let snapshots: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("INH_SNAP"))
+            .unwrap_or_else(|| Map::new(env));
+        let paid: Map<(Address, Address), i128> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("INH_PAID"))
+            .unwrap_or_else(|| Map::new(env));
+        snapshots.keys().iter().any(|token| {
+            config
+                .beneficiaries
+                .iter()
+                .any(|share| !paid.contains_key((token.clone(), share.beneficiary)))
+        })
+    }
+
+    /// Drop the owner-side trigger (policy and inactivity clock). Payout
+    /// snapshots and per-beneficiary payout records are kept so the history
+    /// of what was already paid survives an ownership claim.
+    fn clear_inheritance_trigger(env: &Env) {
+        env.storage().instance().remove(&symbol_short!("INH_CONF"));
+        env.storage().instance().remove(&symbol_short!("OWN_ACT"));
+    }
+}
+
+// -----------------------------------------------------------------------
+// Balance vault (`vault` feature)
+// -----------------------------------------------------------------------
+#[cfg(any(test, feature = "vault"))]
+#[contractimpl]
+impl FamilyWallet {
+    /// Deposit `amount` of `token` into the wallet's pooled vault.
+    ///
+    /// Funds move from `depositor` to the contract and are added to the
+    /// per-token pooled ledger returned by `get_balances`. Depositing does not
+    /// change where emergency transfers are paid from; see
+    /// `set_vault_routing`.
+    ///
+    /// # Returns
+    /// The pooled balance of `token` after the deposit.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` if `amount` is not positive or the pool would overflow
+    /// - `Unauthorized` if `depositor` is not a Member, Admin or Owner
+    /// - `RoleExpired` if the depositor's role has expired
+    pub fn deposit(
+        env: Env,
+        depositor: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        depositor.require_auth();
+        Self::require_not_paused(&env);
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::check_role_at_least(&env, &depositor, FamilyRole::Member)?;
+
+        Self::extend_instance_ttl(&env);
+
+        let mut balances = Self::vault_balances(&env);
+        let balance = balances
+            .get(token.clone())
+            .unwrap_or(0)
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        balances.set(token.clone(), balance);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("VAULT"), &balances);
+
+        TokenClient::new(&env, &token).transfer(
+            &depositor,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Transaction,
+            EventPriority::Medium,
+            symbol_short!("vault_dep"),
+            VaultDepositEvent {
+                depositor: depositor.clone(),
+                token,
+                amount,
+                balance,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Self::append_access_audit(&env, symbol_short!("vault_dep"), &depositor, None, true);
+
+        Ok(balance)
+    }
+
+    /// Opt `token` in or out of vault routing for emergency transfers.
+    ///
+    /// While enabled, executed emergency transfers of `token` are paid from
+    /// the pooled balance and `EmergencyConfig.min_balance` is enforced
+    /// against it; otherwise funds move straight from the proposer.
+    ///
+    /// # Errors
+    /// - `Unauthorized` if `caller` is not an Owner or Admin
+    /// - `RoleExpired` if the caller's role has expired
+    pub fn set_vault_routing(
+        env: Env,
+        caller: Address,
+        token: Address,
+        enabled: bool,
+    ) -> Result<bool, Error> {
+        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
+            return Ok(false);
+        }
+        caller.require_auth();
+        Self::require_not_paused(&env);
+        Self::require_governance_ok(&env, &caller)?;
+
+        Self::extend_instance_ttl(&env);
+
+        let mut routed = Self::vault_routed_tokens(&env);
+        if enabled {
+            routed.set(token, true);
+        } else {
+            routed.remove(token);
+        }
+        env.storage()
+            .instance()
+            .set(&symbol_short!("VAULT_RT"), &routed);
+
+        Self::append_access_audit(&env, symbol_short!("vault_rt"), &caller, None, true);
+        Ok(true)
+    }
+
+    /// Whether emergency transfers of `token` are paid from the vault.
+    pub fn is_vault_routed(env: Env, token: Address) -> bool {
+        Self::is_vault_token(&env, &token)
+    }
+
+    /// Set how much of `token` a member may draw from the pooled vault.
+    ///
+    /// The allowance is a remaining balance: each `withdraw_from_vault`
+    /// reduces it, and calling this again replaces it. Every role, Owners and
+    /// Admins included, draws against an allowance; an Admin's own allowance
+    /// must be set by an Owner or another Admin.
+    ///
+    /// # Errors
+    /// - `Unauthorized` if `caller` is not an Owner or Admin, or is an Admin
+    ///   setting its own allowance
+    /// - `MemberNotFound` if `member` is not a family member
+    /// - `InvalidAmount` if `limit` is negative
+    pub fn set_sub_account_limit(
+        env: Env,
+        caller: Address,
+        member: Address,
+        token: Address,
+        limit: i128,
+    ) -> Result<bool, Error> {
+        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
+            return Ok(false);
+        }
+        caller.require_auth();
+        Self::require_not_paused(&env);
+        Self::require_governance_ok(&env, &caller)?;
+        if limit < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if !Self::is_family_member(&env, &member) {
+            return Err(Error::MemberNotFound);
+        }
+        if caller == member {
+            Self::check_role_at_least(&env, &caller, FamilyRole::Owner)?;
+        }
+
+        Self::extend_instance_ttl(&env);
+
+        let mut accounts = Self::sub_accounts(&env);
+        accounts.set((member.clone(), token), limit);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("SUB_ACCT"), &accounts);
+
+        Self::append_access_audit(&env, symbol_short!("sub_lim"), &caller, Some(member), true);
+        Ok(true)
+    }
+
+    /// Draw `amount` of `token` from the pooled vault and send it to
+    /// `recipient`.
+    ///
+    /// Every caller spends against their sub-account allowance and their usual
+    /// spending and precision limits, so no single Owner or Admin can drain
+    /// what other members pooled.
+    ///
+    /// # Returns
+    /// The pooled balance of `token` after the withdrawal.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` if `amount` is not positive
+    /// - `Unauthorized` / `RoleExpired` if `member` may not act on the wallet
+    /// - `SpendingLimitExceeded` or a precision-limit error
+    /// - `SubAccountLimitExceeded` if `amount` exceeds the member's allowance
+    /// - `InsufficientVaultBalance` if the pool holds less than `amount`
+    pub fn withdraw_from_vault(
+        env: Env,
+        member: Address,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
+        member.require_auth();
+        Self::require_not_paused(&env);
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::check_role_at_least(&env, &member, FamilyRole::Member)?;
+
+        if !Self::check_spending_limit(env.clone(), member.clone(), amount) {
+            return Err(Error::SpendingLimitExceeded);
+        }
+        Self::validate_precision_spending_internal(env.clone(), member.clone(), amount)?;
+        let mut accounts = Self::sub_accounts(&env);
+        let account_key = (member.clone(), token.clone());
+        let allowance = accounts.get(account_key.clone()).unwrap_or(0);
+        if amount > allowance {
+            return Err(Error::SubAccountLimitExceeded);
+        }
+        accounts.set(account_key, allowance - amount);
+
+        Self::extend_instance_ttl(&env);
+
+        let balance = Self::debit_vault(&env, &token, amount, 0)?;
+        env.storage()
+            .instance()
+            .set(&symbol_short!("SUB_ACCT"), &accounts);
+        Self::record_precision_spending(&env, &member, amount);
+
+        TokenClient::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &amount,
+        );
+
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Transaction,
+            EventPriority::Medium,
+            symbol_short!("vault_wd"),
+            VaultWithdrawalEvent {
+                member: member.clone(),
+                token,
+                amount,
+                balance,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Self::append_access_audit(
+            &env,
+            symbol_short!("vault_wd"),
+            &member,
+            Some(recipient),
+            true,
+        );
+
+        Ok(balance)
+    }
+
+    /// Pooled vault balance per token. Tokens never deposited are absent.
+    pub fn get_balances(env: Env) -> Map<Address, i128> {
+        Self::vault_balances(&env)
+    }
+
+    /// Remaining vault allowance of `token` for `member` (0 if none is set).
+    pub fn get_sub_account(env: Env, member: Address, token: Address) -> i128 {
+        Self::sub_accounts(&env).get((member, token)).unwrap_or(0)
+    }
+}
+
 #[cfg(test)]
 mod events_schema_test;
 #[cfg(test)]
@@ -1,18 +1,65 @@
 #![no_std]
 use remitwise_common::{
-    clamp_limit, CoverageType, EventCategory, EventPriority, RemitwiseEvents, DEFAULT_PAGE_LIMIT,
-    INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD, MAX_PAGE_LIMIT, PERSISTENT_BUMP_AMOUNT,
-    PERSISTENT_LIFETIME_THRESHOLD, SNAPSHOT_KEY, SNAPSHOT_VERSION,
+    clamp_limit, CoverageType, EventCategory, EventPriority, RemitwiseError, RemitwiseEvents,
+    DEFAULT_PAGE_LIMIT, INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD, MAX_PAGE_LIMIT,
+    PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD, SNAPSHOT_KEY, SNAPSHOT_VERSION,
 };
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, String, Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, Address,
+    Env, String, Vec,
 };
 
 mod fee_math;
 
-// Storage TTL constants
-const INSTANCE_LIFETIME_THRESHOLD: u32 = 17280; // ~1 day
-const INSTANCE_BUMP_AMOUNT: u32 = 518400; // ~30 days
+/// Seconds in one premium period.
+const THIRTY_DAYS_SECS: u64 = 30 * 24 * 3_600;
+/// Maximum policy name length in bytes.
+const MAX_NAME_LEN: u32 = 64;
+/// Maximum external reference length in bytes.
+const MAX_EXT_REF_LEN: u32 = 128;
+/// Maximum number of policies in the active index across all owners.
+pub const MAX_POLICIES: u32 = 1_000;
+/// Maximum number of active policies a single owner may hold.
+pub const MAX_POLICIES_PER_OWNER: u32 = 50;
+/// Largest monthly premium accepted for a `Health` policy.
+pub const MAX_MONTHLY_PREMIUM: i128 = 500_000_000_000;
+/// Largest coverage amount accepted for a `Health` policy.
+pub const MAX_COVERAGE_AMOUNT: i128 = 100_000_000_000_000;
+/// How long a deactivated policy must wait before it can be reactivated.
+pub const MAX_TENURE_SECS: u64 = THIRTY_DAYS_SECS;
+/// Maximum number of premium schedules per owner.
+const MAX_SCHEDULES_PER_OWNER: u32 = 50;
+/// Minimum recurrence interval for repeating premium schedules (1 hour).
+/// One-off schedules (interval == 0) are exempt from this check.
+const MIN_SCHEDULE_INTERVAL: u64 = 3_600;
+/// Furthest in the future a premium schedule's first run may be placed.
+const MAX_SCHEDULE_LEAD_TIME: u64 = 365 * 24 * 3_600;
+
+/// Premium and coverage bounds for one [`CoverageType`].
+struct TypeConstraints {
+    min_premium: i128,
+    max_premium: i128,
+    min_coverage: i128,
+    max_coverage: i128,
+}
+
+impl TypeConstraints {
+    fn for_type(coverage_type: &CoverageType) -> Self {
+        let (max_premium, max_coverage) = match coverage_type {
+            CoverageType::Health => (MAX_MONTHLY_PREMIUM, MAX_COVERAGE_AMOUNT),
+            CoverageType::Life => (1_000_000_000_000, 500_000_000_000_000),
+            CoverageType::Property => (2_000_000_000_000, 1_000_000_000_000_000),
+            CoverageType::Auto => (750_000_000_000, 200_000_000_000_000),
+            CoverageType::Liability => (400_000_000_000, 50_000_000_000_000),
+        };
+        Self {
+            min_premium: 1,
+            max_premium,
+            min_coverage: 1,
+            max_coverage,
+        }
+    }
+}
 
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -41,12 +88,13 @@ pub struct PolicyPage {
 
 #[contracttype]
 #[derive(Clone)]
-pub enum InsuranceEvent {
-    PolicyCreated,
-    PremiumPaid,
-    PolicyDeactivated,
-    EmergencyShutdown,
-    Resumed,
+pub struct PolicyCreatedEvent {
+    pub policy_id: u32,
+    pub name: String,
+    pub coverage_type: CoverageType,
+    pub monthly_premium: i128,
+    pub coverage_amount: i128,
+    pub timestamp: u64,
 }
 
 #[contracttype]
@@ -135,6 +183,10 @@ pub enum InsuranceEvent {
     ScheduleCancelled = 7,
     /// Recurring schedule was modified.
     ScheduleModified = 8,
+    /// Contract entered emergency shutdown (`emergency_shutdown`).
+    EmergencyShutdown = 9,
+    /// Emergency shutdown was lifted (`resume`).
+    Resumed = 10,
 }
 
 /// Event payload emitted when an external reference is set or cleared on a policy.
@@ -151,6 +203,53 @@ pub struct ExternalRefUpdatedEvent {
     pub timestamp: u64,
 }
 
+/// Typed failures for the insurance entrypoints, matching the
+/// `Result`-returning style of `bill_payments` and `remittance_split` so
+/// `try_*` clients can tell the cases apart.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum InsuranceError {
+    Unauthorized = 1,
+    AlreadyInitialized = 2,
+    NotInitialized = 3,
+    PolicyNotFound = 4,
+    PolicyInactive = 5,
+    InvalidName = 6,
+    InvalidPremium = 7,
+    InvalidCoverageAmount = 8,
+    UnsupportedCombination = 9,
+    InvalidExternalRef = 10,
+    MaxPoliciesReached = 11,
+    MonthlyPremiumTooLow = 12,
+    MonthlyPremiumTooHigh = 13,
+    CoverageAmountTooLow = 14,
+    CoverageAmountTooHigh = 15,
+    PolicyLimitExceeded = 16,
+    PolicyAlreadyActive = 17,
+    PolicyDeactivationTooSoon = 18,
+    ScheduleNotFound = 19,
+    ScheduleIntervalTooShort = 20,
+    ScheduleLeadTimeTooLong = 21,
+    SnapshotNotFound = 22,
+    SnapshotTooOld = 23,
+    ContractPaused = 24,
+}
+
+impl InsuranceError {
+    /// Maps the variants that have a cross-contract equivalent onto
+    /// [`RemitwiseError`]; contract-specific failures return `None`.
+    pub fn to_remitwise_error(self) -> Option<RemitwiseError> {
+        match self {
+            InsuranceError::Unauthorized => Some(RemitwiseError::Unauthorized),
+            InsuranceError::InvalidPremium | InsuranceError::InvalidCoverageAmount => {
+                Some(RemitwiseError::InvalidAmount)
+            }
+            _ => None,
+        }
+    }
+}
+
 #[contracttype]
 pub enum DataKey {
     Owner,
@@ -208,18 +307,19 @@ impl Insurance {
     /// One-time setup for the address allowed to trigger emergency
     /// shutdown.
     ///
-    /// # Panics
-    /// - If the pause admin has already been set
-    pub fn init_pause_admin(env: Env, admin: Address) {
+    /// # Errors
+    /// - `AlreadyInitialized` if the pause admin has already been set
+    pub fn init_pause_admin(env: Env, admin: Address) -> Result<(), InsuranceError> {
         admin.require_auth();
 
         if env.storage().instance().has(&symbol_short!("PADMIN")) {
-            panic!("Pause admin already initialized");
+            return Err(InsuranceError::AlreadyInitialized);
         }
 
         env.storage()
             .instance()
             .set(&symbol_short!("PADMIN"), &admin);
+        Ok(())
     }
 
     /// ## The emergency-shutdown flow
@@ -238,12 +338,12 @@ impl Insurance {
     /// emergency shutdown is to take effect immediately, in response to
     /// something already going wrong.
     ///
-    /// # Panics
-    /// - If the pause admin hasn't been initialized
-    /// - If caller is not the pause admin
-    pub fn emergency_shutdown(env: Env, caller: Address) {
+    /// # Errors
+    /// - `NotInitialized` if the pause admin hasn't been initialized
+    /// - `Unauthorized` if caller is not the pause admin
+    pub fn emergency_shutdown(env: Env, caller: Address) -> Result<(), InsuranceError> {
         caller.require_auth();
-        Self::require_pause_admin(&env, &caller);
+        Self::require_pause_admin(&env, &caller)?;
 
         env.storage()
             .instance()
@@ -252,22 +352,24 @@ impl Insurance {
             (symbol_short!("admin"), InsuranceEvent::EmergencyShutdown),
             caller,
         );
+        Ok(())
     }
 
     /// Lift a shutdown triggered by `emergency_shutdown`.
     ///
-    /// # Panics
-    /// - If the pause admin hasn't been initialized
-    /// - If caller is not the pause admin
-    pub fn resume(env: Env, caller: Address) {
+    /// # Errors
+    /// - `NotInitialized` if the pause admin hasn't been initialized
+    /// - `Unauthorized` if caller is not the pause admin
+    pub fn resume(env: Env, caller: Address) -> Result<(), InsuranceError> {
         caller.require_auth();
-        Self::require_pause_admin(&env, &caller);
+        Self::require_pause_admin(&env, &caller)?;
 
         env.storage()
             .instance()
             .set(&symbol_short!("PAUSED"), &false);
         env.events()
             .publish((symbol_short!("admin"), InsuranceEvent::Resumed), caller);
+        Ok(())
     }
 
     /// Whether the contract is currently in an emergency shutdown.
@@ -278,53 +380,25 @@ impl Insurance {
             .unwrap_or(false)
     }
 
-    fn require_pause_admin(env: &Env, caller: &Address) {
+    fn require_pause_admin(env: &Env, caller: &Address) -> Result<(), InsuranceError> {
         let admin: Address = env
             .storage()
             .instance()
             .get(&symbol_short!("PADMIN"))
-            .expect("Pause admin not initialized");
+            .ok_or(InsuranceError::NotInitialized)?;
 
         if &admin != caller {
-            panic!("Only the pause admin can do this");
+            return Err(InsuranceError::Unauthorized);
         }
+        Ok(())
     }
 
     fn require_not_paused(env: &Env) {
         if Self::is_paused(env.clone()) {
-            panic!("Contract is in emergency shutdown");
+            panic_with_error!(env, InsuranceError::ContractPaused);
         }
     }
 
-    /// Create a new insurance policy
-    ///
-    /// # Arguments
-    /// * `owner` - Address of the policy owner (must authorize)
-    /// * `name` - Name of the policy
-    /// * `coverage_type` - Type of coverage (e.g., "health", "emergency")
-    /// * `monthly_premium` - Monthly premium amount (must be positive)
-    /// * `coverage_amount` - Total coverage amount (must be positive)
-    ///
-    /// # Returns
-    /// The ID of the created policy
-    ///
-    /// # Panics
-    /// - If owner doesn't authorize the transaction
-    /// - If monthly_premium is not positive
-    /// - If coverage_amount is not positive
-    pub fn create_policy(
-        env: Env,
-        owner: Address,
-        name: String,
-        coverage_type: String,
-        monthly_premium: i128,
-        coverage_amount: i128,
-    ) -> u32 {
-        Self::require_not_paused(&env);
-
-        // Access control: require owner authorization
-        owner.require_auth();
-
     /// Initialize the insurance contract with the given owner.
     ///
     /// Requires `owner`'s signature — without it, anyone could front-run
@@ -349,25 +423,6 @@ impl Insurance {
         Ok(())
     }
 
-    /// Pay monthly premium for a policy
-    ///
-    /// # Arguments
-    /// * `caller` - Address of the caller (must be the policy owner)
-    /// * `policy_id` - ID of the policy
-    ///
-    /// # Returns
-    /// True if payment was successful
-    ///
-    /// # Panics
-    /// - If caller is not the policy owner
-    /// - If policy is not found
-    /// - If policy is not active
-    pub fn pay_premium(env: Env, caller: Address, policy_id: u32) -> bool {
-        Self::require_not_paused(&env);
-
-        // Access control: require caller authorization
-        caller.require_auth();
-
     fn require_initialized(env: &Env) -> Result<(), InsuranceError> {
         if !env.storage().instance().has(&DataKey::Initialized) {
             Err(InsuranceError::NotInitialized)
@@ -376,70 +431,6 @@ impl Insurance {
         }
     }
 
-    /// Get a policy by ID
-    ///
-    /// # Arguments
-    /// * `policy_id` - ID of the policy
-    ///
-    /// # Returns
-    /// InsurancePolicy struct or None if not found
-    pub fn get_policy(env: Env, policy_id: u32) -> Option<InsurancePolicy> {
-        let policies: Map<u32, InsurancePolicy> = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("POLICIES"))
-            .unwrap_or_else(|| Map::new(&env));
-
-        policies.get(policy_id)
-    }
-
-    /// Get all active policies for a specific owner
-    ///
-    /// # Arguments
-    /// * `owner` - Address of the policy owner
-    ///
-    /// # Returns
-    /// Vec of active InsurancePolicy structs belonging to the owner
-    pub fn get_active_policies(env: Env, owner: Address) -> Vec<InsurancePolicy> {
-        let policies: Map<u32, InsurancePolicy> = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("POLICIES"))
-            .unwrap_or_else(|| Map::new(&env));
-
-        let mut result = Vec::new(&env);
-        let max_id = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("NEXT_ID"))
-            .unwrap_or(0u32);
-
-        for i in 1..=max_id {
-            if let Some(policy) = policies.get(i) {
-                if policy.active && policy.owner == owner {
-                    result.push_back(policy);
-                }
-            }
-        }
-        result
-    }
-
-    /// Get total monthly premium for all active policies of an owner
-    ///
-    /// # Arguments
-    /// * `owner` - Address of the policy owner
-    ///
-    /// # Returns
-    /// Total monthly premium amount for the owner's active policies
-    pub fn get_total_monthly_premium(env: Env, owner: Address) -> i128 {
-        let active = Self::get_active_policies(env, owner);
-        let mut total = 0i128;
-        for policy in active.iter() {
-            total += policy.monthly_premium;
-        }
-        total
-    }
-
     /// Preview a policy's monthly premium after a loyalty/volume discount
     /// and cap are applied to it. Does not change any stored state -- the
     /// policy's own `monthly_premium` is untouched; this is a read-only
@@ -450,63 +441,20 @@ impl Insurance {
     /// * `discount_bps` - Discount in basis points (e.g. `500` = 5%)
     /// * `fee_cap` - Maximum fee after the discount is applied
     ///
-    /// # Panics
-    /// - If policy is not found
+    /// # Errors
+    /// - `PolicyNotFound` if no policy exists with `policy_id`
     pub fn calculate_discounted_premium(
         env: Env,
         policy_id: u32,
         discount_bps: u32,
         fee_cap: i128,
-    ) -> i128 {
-        let policy = Self::get_policy(env, policy_id).expect("Policy not found");
-        fee_math::apply_discount_then_cap(policy.monthly_premium, discount_bps, fee_cap)
-    }
-
-    /// Deactivate a policy
-    ///
-    /// # Arguments
-    /// * `caller` - Address of the caller (must be the policy owner)
-    /// * `policy_id` - ID of the policy
-    ///
-    /// # Returns
-    /// True if deactivation was successful
-    ///
-    /// # Panics
-    /// - If caller is not the policy owner
-    /// - If policy is not found
-    pub fn deactivate_policy(env: Env, caller: Address, policy_id: u32) -> bool {
-        // Access control: require caller authorization
-        caller.require_auth();
-
-        // Extend storage TTL
-        Self::extend_instance_ttl(&env);
-
-        let mut policies: Map<u32, InsurancePolicy> = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("POLICIES"))
-            .unwrap_or_else(|| Map::new(&env));
-
-        let mut policy = policies.get(policy_id).expect("Policy not found");
-
-        // Access control: verify caller is the owner
-        if policy.owner != caller {
-            panic!("Only the policy owner can deactivate this policy");
-        }
-
-        policy.active = false;
-        policies.set(policy_id, policy);
-        env.storage()
-            .instance()
-            .set(&symbol_short!("POLICIES"), &policies);
-
-        // Emit event for audit trail
-        env.events().publish(
-            (symbol_short!("insure"), InsuranceEvent::PolicyDeactivated),
-            (policy_id, caller),
-        );
-
-        true
+    ) -> Result<i128, InsuranceError> {
+        let policy = Self::load_policy(&env, policy_id)?;
+        Ok(fee_math::apply_discount_then_cap(
+            policy.monthly_premium,
+            discount_bps,
+            fee_cap,
+        ))
     }
 
     /// Extend the TTL of instance storage
@@ -586,6 +534,29 @@ impl Insurance {
             .ok_or(InsuranceError::PolicyNotFound)
     }
 
+    /// Mark one premium of `policy` paid now and emit `PremiumPaid`.
+    fn record_premium_payment(env: &Env, policy_id: u32, mut policy: Policy) {
+        let now = env.ledger().timestamp();
+        policy.last_payment_at = now;
+        policy.next_payment_date = Self::advance_next_payment_date(policy.next_payment_date, now);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Policy(policy_id), &policy);
+        Self::extend_instance_ttl(env);
+
+        env.events().publish(
+            (symbol_short!("insurance"), InsuranceEvent::PremiumPaid),
+            PremiumPaidEvent {
+                policy_id,
+                name: policy.name,
+                amount: policy.monthly_premium,
+                next_payment_date: policy.next_payment_date,
+                timestamp: now,
+            },
+        );
+    }
+
     fn validate_ext_ref(ext_ref: &core::option::Option<String>) -> Result<(), InsuranceError> {
         if let Some(r) = ext_ref {
             if r.is_empty() || r.len() > MAX_EXT_REF_LEN {
@@ -616,6 +587,9 @@ impl Insurance {
         ext_ref: Option<String>,
     ) -> Result<u32, InsuranceError> {
         Self::require_initialized(&env)?;
+        if Self::is_paused(env.clone()) {
+            return Err(InsuranceError::ContractPaused);
+        }
         caller.require_auth();
 
         if name.is_empty() {
@@ -751,9 +725,10 @@ impl Insurance {
         if Self::require_initialized(&env).is_err() {
             return false;
         }
+        Self::require_not_paused(&env);
         caller.require_auth();
 
-        let mut policy = match Self::load_policy(&env, policy_id) {
+        let policy = match Self::load_policy(&env, policy_id) {
             Ok(p) => p,
             Err(_) => return false,
         };
@@ -764,27 +739,70 @@ impl Insurance {
             return false;
         }
 
-        let now = env.ledger().timestamp();
-        policy.last_payment_at = now;
-        policy.next_payment_date = Self::advance_next_payment_date(policy.next_payment_date, now);
+        Self::record_premium_payment(&env, policy_id, policy);
+        true
+    }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::Policy(policy_id), &policy);
-        Self::extend_instance_ttl(&env);
+    /// Pay the premium of `owner`'s policy on their behalf.
+    ///
+    /// `executor` must be registered by `owner` through
+    /// [`Self::set_authorized_executor`]; the orchestrator uses this to run
+    /// scheduled flows without the owner signing each run. `amount` is what
+    /// the executor routed to the policy and must cover `monthly_premium`.
+    ///
+    /// # Errors
+    /// - [`InsuranceError::NotInitialized`] if the contract has not been initialized
+    /// - [`InsuranceError::Unauthorized`] if `owner` has not registered
+    ///   `executor`, or the policy is not `owner`'s
+    /// - [`InsuranceError::PolicyNotFound`] if no policy exists with `policy_id`
+    /// - [`InsuranceError::PolicyInactive`] if the policy is deactivated
+    /// - [`InsuranceError::InvalidPremium`] if `amount < monthly_premium`
+    pub fn pay_premium_for(
+        env: Env,
+        executor: Address,
+        owner: Address,
+        policy_id: u32,
+        amount: i128,
+    ) -> Result<(), InsuranceError> {
+        Self::require_initialized(&env)?;
+        Self::require_not_paused(&env);
+        executor.require_auth();
+        remitwise_common::require_authorized_executor(&env, &owner, &executor)
+            .map_err(|_| InsuranceError::Unauthorized)?;
 
-        env.events().publish(
-            (symbol_short!("insurance"), InsuranceEvent::PremiumPaid),
-            PremiumPaidEvent {
-                policy_id,
-                name: policy.name,
-                amount: policy.monthly_premium,
-                next_payment_date: policy.next_payment_date,
-                timestamp: now,
-            },
-        );
+        let policy = Self::load_policy(&env, policy_id)?;
+        if !policy.active {
+            return Err(InsuranceError::PolicyInactive);
+        }
+        if owner != policy.owner {
+            return Err(InsuranceError::Unauthorized);
+        }
+        if amount < policy.monthly_premium {
+            return Err(InsuranceError::InvalidPremium);
+        }
 
-        true
+        Self::record_premium_payment(&env, policy_id, policy);
+        Ok(())
+    }
+
+    /// Let `executor` (typically the orchestrator) pay `owner`'s premiums
+    /// through [`Self::pay_premium_for`], or revoke it.
+    pub fn set_authorized_executor(
+        env: Env,
+        owner: Address,
+        executor: Address,
+        authorized: bool,
+    ) -> Result<(), InsuranceError> {
+        Self::require_initialized(&env)?;
+        owner.require_auth();
+        remitwise_common::set_authorized_executor(&env, &owner, &executor, authorized);
+        Self::extend_instance_ttl(&env);
+        Ok(())
+    }
+
+    /// Whether `owner` has registered `executor` for [`Self::pay_premium_for`].
+    pub fn is_authorized_executor(env: Env, owner: Address, executor: Address) -> bool {
+        remitwise_common::is_authorized_executor(&env, &owner, &executor)
     }
 
     /// Pay premiums for multiple policies in a single transaction.
@@ -1133,7 +1151,8 @@ impl Insurance {
                         if items.len() < lim {
                             items.push_back(p);
                         } else {
-                            next_cursor = id;
+                            // More remain: resume after the last returned id.
+                            next_cursor = items.last().map(|last| last.id).unwrap_or(0);
                             break;
                         }
                     }
@@ -1193,7 +1212,8 @@ impl Insurance {
                         if items.len() < lim {
                             items.push_back(p);
                         } else {
-                            next_cursor = id;
+                            // More remain: resume after the last returned id.
+                            next_cursor = items.last().map(|last| last.id).unwrap_or(0);
                             break;
                         }
                     }
@@ -1863,20 +1883,38 @@ mod tests {
     use super::*;
     use soroban_sdk::testutils::Address as _;
 
+    fn setup(env: &Env) -> InsuranceClient<'_> {
+        let contract_id = env.register_contract(None, Insurance);
+        let client = InsuranceClient::new(env, &contract_id);
+        client.init(&Address::generate(env));
+        client
+    }
+
+    fn open_policy(env: &Env, client: &InsuranceClient, owner: &Address) -> u32 {
+        client.create_policy(
+            owner,
+            &String::from_str(env, "Health"),
+            &CoverageType::Health,
+            &100,
+            &10_000,
+            &None,
+        )
+    }
+
     #[test]
     fn calculate_discounted_premium_discounts_before_capping() {
         let env = Env::default();
         env.mock_all_auths();
-        let contract_id = env.register_contract(None, Insurance);
-        let client = InsuranceClient::new(&env, &contract_id);
+        let client = setup(&env);
 
         let owner = Address::generate(&env);
         let policy_id = client.create_policy(
             &owner,
             &String::from_str(&env, "Health"),
-            &String::from_str(&env, "health"),
+            &CoverageType::Health,
             &1000,
             &10_000,
+            &None,
         );
 
         // 10% off a 1000 premium is 900, under the 920 cap -- the cap
@@ -1886,30 +1924,23 @@ mod tests {
 
         assert_eq!(discounted, 900);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
+    #[test]
+    fn calculate_discounted_premium_unknown_policy_returns_error() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, Insurance);
+        let client = InsuranceClient::new(&env, &contract_id);
 
-    fn open_policy(env: &Env, client: &InsuranceClient, owner: &Address) -> u32 {
-        client.create_policy(
-            owner,
-            &String::from_str(env, "Health"),
-            &String::from_str(env, "health"),
-            &100,
-            &10_000,
-        )
+        let result = client.try_calculate_discounted_premium(&999, &1_000, &920);
+
+        assert_eq!(result, Err(Ok(InsuranceError::PolicyNotFound)));
     }
 
     #[test]
-    #[should_panic(expected = "Contract is in emergency shutdown")]
     fn emergency_shutdown_blocks_new_policies() {
         let env = Env::default();
         env.mock_all_auths();
-        let contract_id = env.register_contract(None, Insurance);
-        let client = InsuranceClient::new(&env, &contract_id);
+        let client = setup(&env);
 
         let pause_admin = Address::generate(&env);
         let owner = Address::generate(&env);
@@ -1918,16 +1949,22 @@ mod tests {
         client.emergency_shutdown(&pause_admin);
         assert!(client.is_paused());
 
-        open_policy(&env, &client, &owner);
+        let result = client.try_create_policy(
+            &owner,
+            &String::from_str(&env, "Health"),
+            &CoverageType::Health,
+            &100,
+            &10_000,
+            &None,
+        );
+        assert_eq!(result, Err(Ok(InsuranceError::ContractPaused)));
     }
 
     #[test]
-    #[should_panic(expected = "Contract is in emergency shutdown")]
     fn emergency_shutdown_blocks_premium_payments() {
         let env = Env::default();
         env.mock_all_auths();
-        let contract_id = env.register_contract(None, Insurance);
-        let client = InsuranceClient::new(&env, &contract_id);
+        let client = setup(&env);
 
         let pause_admin = Address::generate(&env);
         let owner = Address::generate(&env);
@@ -1935,15 +1972,15 @@ mod tests {
         let policy_id = open_policy(&env, &client, &owner);
 
         client.emergency_shutdown(&pause_admin);
-        client.pay_premium(&owner, &policy_id);
+        let result = client.try_pay_premium(&owner, &policy_id);
+        assert_eq!(result, Err(Ok(InsuranceError::ContractPaused.into())));
     }
 
     #[test]
     fn resume_allows_state_changes_again() {
         let env = Env::default();
         env.mock_all_auths();
-        let contract_id = env.register_contract(None, Insurance);
-        let client = InsuranceClient::new(&env, &contract_id);
+        let client = setup(&env);
 
         let pause_admin = Address::generate(&env);
         let owner = Address::generate(&env);
@@ -1957,26 +1994,40 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Only the pause admin can do this")]
     fn only_the_pause_admin_can_shut_down() {
         let env = Env::default();
         env.mock_all_auths();
-        let contract_id = env.register_contract(None, Insurance);
-        let client = InsuranceClient::new(&env, &contract_id);
+        let client = setup(&env);
 
         let pause_admin = Address::generate(&env);
         let stranger = Address::generate(&env);
         client.init_pause_admin(&pause_admin);
 
-        client.emergency_shutdown(&stranger);
+        let result = client.try_emergency_shutdown(&stranger);
+        assert_eq!(result, Err(Ok(InsuranceError::Unauthorized)));
+        assert!(!client.is_paused());
+    }
+
+    #[test]
+    fn deactivate_policy_rejects_unknown_policy_and_non_owner() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = setup(&env);
+
+        let owner = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        let policy_id = open_policy(&env, &client, &owner);
+
+        assert!(!client.deactivate_policy(&owner, &(policy_id + 1)));
+        assert!(!client.deactivate_policy(&stranger, &policy_id));
+        assert!(client.get_policy(&policy_id).unwrap().active);
     }
 
     #[test]
     fn deactivate_policy_is_not_blocked_by_a_shutdown() {
         let env = Env::default();
         env.mock_all_auths();
-        let contract_id = env.register_contract(None, Insurance);
-        let client = InsuranceClient::new(&env, &contract_id);
+        let client = setup(&env);
 
         let pause_admin = Address::generate(&env);
         let owner = Address::generate(&env);
@@ -1984,8 +2035,45 @@ mod tests {
         let policy_id = open_policy(&env, &client, &owner);
 
         client.emergency_shutdown(&pause_admin);
-        client.deactivate_policy(&owner, &policy_id); // does not panic
+        assert!(client.deactivate_policy(&owner, &policy_id));
 
         assert!(!client.get_policy(&policy_id).unwrap().active);
     }
+
+    #[test]
+    fn pay_premium_for_requires_registered_executor_and_owned_policy() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = setup(&env);
+
+        let owner = Address::generate(&env);
+        let other = Address::generate(&env);
+        let executor = Address::generate(&env);
+        let policy_id = open_policy(&env, &client, &owner);
+        let due = client.get_policy(&policy_id).unwrap().next_payment_date;
+
+        let result = client.try_pay_premium_for(&executor, &owner, &policy_id, &100);
+        assert_eq!(result, Err(Ok(InsuranceError::Unauthorized)));
+
+        // Registered by another owner: cannot pay this owner's policy.
+        client.set_authorized_executor(&other, &executor, &true);
+        let result = client.try_pay_premium_for(&executor, &other, &policy_id, &100);
+        assert_eq!(result, Err(Ok(InsuranceError::Unauthorized)));
+
+        client.set_authorized_executor(&owner, &executor, &true);
+        assert!(client.is_authorized_executor(&owner, &executor));
+        let result = client.try_pay_premium_for(&executor, &owner, &policy_id, &99);
+        assert_eq!(result, Err(Ok(InsuranceError::InvalidPremium)));
+        assert_eq!(
+            client.get_policy(&policy_id).unwrap().next_payment_date,
+            due
+        );
+
+        client.pay_premium_for(&executor, &owner, &policy_id, &100);
+        assert!(client.get_policy(&policy_id).unwrap().next_payment_date > due);
+
+        client.set_authorized_executor(&owner, &executor, &false);
+        let result = client.try_pay_premium_for(&executor, &owner, &policy_id, &100);
+        assert_eq!(result, Err(Ok(InsuranceError::Unauthorized)));
+    }
 }
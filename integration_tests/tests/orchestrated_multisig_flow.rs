@@ -1,7 +1,9 @@
 use bill_payments::{BillPayments, BillPaymentsClient};
 use family_wallet::{FamilyWallet, FamilyWalletClient, TransactionType};
 use insurance::{Insurance, InsuranceClient};
-use orchestrator::{Orchestrator, OrchestratorClient, OrchestratorError, RemittanceFlowParams};
+use orchestrator::{
+    Orchestrator, OrchestratorClient, OrchestratorError, RemittanceFlowParams, TargetList,
+};
 use remittance_split::{RemittanceSplit, RemittanceSplitClient};
 use remitwise_common::{CoverageType, FamilyRole};
 use reporting::{ReportingContract, ReportingContractClient};
@@ -135,9 +137,9 @@ fn test_orchestrated_multisig_flow() {
         savings: savings_id.clone(),
         bills: bills_id.clone(),
         insurance: insurance_id.clone(),
-        goal_id,
-        bill_id,
-        policy_id,
+        goal_targets: TargetList::single(&env, goal_id),
+        bill_targets: TargetList::single(&env, bill_id),
+        policy_targets: TargetList::single(&env, policy_id),
     });
 
     match result {
@@ -168,9 +170,9 @@ fn test_orchestrated_multisig_flow() {
             savings: savings_id.clone(),
             bills: bills_id.clone(),
             insurance: insurance_id.clone(),
-            goal_id,
-            bill_id,
-            policy_id,
+            goal_targets: TargetList::single(&env, goal_id),
+            bill_targets: TargetList::single(&env, bill_id),
+            policy_targets: TargetList::single(&env, policy_id),
         });
     assert!(result_still_fails.is_err());
 
@@ -187,9 +189,9 @@ fn test_orchestrated_multisig_flow() {
         savings: savings_id.clone(),
         bills: bills_id.clone(),
         insurance: insurance_id.clone(),
-        goal_id,
-        bill_id,
-        policy_id,
+        goal_targets: TargetList::single(&env, goal_id),
+        bill_targets: TargetList::single(&env, bill_id),
+        policy_targets: TargetList::single(&env, policy_id),
     });
 
     // 5. Scenario: Paused Orchestrator (Downstream contract paused)
@@ -206,9 +208,9 @@ fn test_orchestrated_multisig_flow() {
         savings: savings_id.clone(),
         bills: bills_id.clone(),
         insurance: insurance_id.clone(),
-        goal_id,
-        bill_id,
-        policy_id,
+        goal_targets: TargetList::single(&env, goal_id),
+        bill_targets: TargetList::single(&env, bill_id),
+        policy_targets: TargetList::single(&env, policy_id),
     });
     assert!(
         result_paused.is_err(),
@@ -235,9 +237,9 @@ fn test_orchestrated_multisig_flow() {
         savings: savings_id.clone(),
         bills: bills_id.clone(),
         insurance: insurance_id.clone(),
-        goal_id,
-        bill_id,
-        policy_id,
+        goal_targets: TargetList::single(&env, goal_id),
+        bill_targets: TargetList::single(&env, bill_id),
+        policy_targets: TargetList::single(&env, policy_id),
     });
 
     match result_locked {
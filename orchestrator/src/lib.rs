@@ -3,8 +3,8 @@
 #![allow(clippy::too_many_arguments)]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Map, Symbol,
-    Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, xdr::ToXdr, Address, Env,
+    Map, Symbol, Vec,
 };
 
 #[allow(dead_code)]
@@ -22,6 +22,28 @@ mod interface {
         fn get_split(env: Env) -> Vec<u32>;
     }
 
+    /// Permissionless schedule executors of the downstream contracts, each
+    /// returning the ids of the schedules it executed. Called by `run_keeper`.
+    #[contractclient(name = "RemittanceScheduleClient")]
+    pub trait RemittanceScheduleInterface {
+        fn execute_due_remittance_schedules(env: Env) -> Vec<u32>;
+    }
+
+    #[contractclient(name = "SavingsScheduleClient")]
+    pub trait SavingsScheduleInterface {
+        fn execute_due_savings_schedules(env: Env) -> Vec<u32>;
+    }
+
+    #[contractclient(name = "BillScheduleClient")]
+    pub trait BillScheduleInterface {
+        fn execute_due_bill_schedules(env: Env) -> Vec<u32>;
+    }
+
+    #[contractclient(name = "PremiumScheduleClient")]
+    pub trait PremiumScheduleInterface {
+        fn execute_due_premium_schedules(env: Env) -> Vec<u32>;
+    }
+
     #[contractclient(name = "SavingsGoalsClient")]
     pub trait SavingsGoalsInterface {
         fn add_to_goal(env: Env, caller: Address, goal_id: u32, amount: i128);
@@ -37,6 +59,36 @@ mod interface {
         fn pay_premium(env: Env, caller: Address, policy_id: u32, amount: i128);
     }
 
+    /// Delegated writes used by scheduled flows. This contract calls them as
+    /// `executor`; each callee checks that `owner` registered it through
+    /// `set_authorized_executor` and that the goal, bill or policy is `owner`'s.
+    #[contractclient(name = "SavingsGoalsExecutorClient")]
+    pub trait SavingsGoalsExecutorInterface {
+        fn add_to_goal_for(
+            env: Env,
+            executor: Address,
+            owner: Address,
+            goal_id: u32,
+            amount: i128,
+        ) -> i128;
+    }
+
+    #[contractclient(name = "BillPaymentsExecutorClient")]
+    pub trait BillPaymentsExecutorInterface {
+        fn pay_bill_for(env: Env, executor: Address, owner: Address, bill_id: u32, amount: i128);
+    }
+
+    #[contractclient(name = "InsuranceExecutorClient")]
+    pub trait InsuranceExecutorInterface {
+        fn pay_premium_for(
+            env: Env,
+            executor: Address,
+            owner: Address,
+            policy_id: u32,
+            amount: i128,
+        );
+    }
+
     /// Compensation / reverse interfaces for rollback support.
     /// These are expected to be implemented by the respective downstream contracts.
     /// If a contract does not implement compensation, the orchestrator records
@@ -57,6 +109,58 @@ mod interface {
         fn reverse_premium(env: Env, user: Address, policy_id: u32, amount: i128);
     }
 
+    /// Delegated reversals for scheduled flows, under the same executor
+    /// registration as the delegated writes.
+    #[contractclient(name = "SavingsGoalsExecutorCompClient")]
+    pub trait SavingsGoalsExecutorCompInterface {
+        fn remove_from_goal_for(
+            env: Env,
+            executor: Address,
+            owner: Address,
+            goal_id: u32,
+            amount: i128,
+        );
+    }
+
+    #[contractclient(name = "BillPaymentsExecutorCompClient")]
+    pub trait BillPaymentsExecutorCompInterface {
+        fn reverse_payment_for(
+            env: Env,
+            executor: Address,
+            owner: Address,
+            bill_id: u32,
+            amount: i128,
+        );
+    }
+
+    #[contractclient(name = "InsuranceExecutorCompClient")]
+    pub trait InsuranceExecutorCompInterface {
+        fn reverse_premium_for(
+            env: Env,
+            executor: Address,
+            owner: Address,
+            policy_id: u32,
+            amount: i128,
+        );
+    }
+
+    /// Swap pool used by the optional conversion step of
+    /// `execute_converted_flow`. Any AMM or aggregator adapter exposing this
+    /// entrypoint can be plugged in per flow.
+    #[contractclient(name = "SwapPoolClient")]
+    pub trait SwapPoolInterface {
+        /// Swap exactly `amount_in` of `token_in` held by `trader` for at least
+        /// `min_out` of `token_out`, returning the amount received.
+        fn swap(
+            env: Env,
+            trader: Address,
+            token_in: Address,
+            token_out: Address,
+            amount_in: i128,
+            min_out: i128,
+        ) -> i128;
+    }
+
     /// External token contract interface used by `claim_rewards_summary_external`.
     ///
     /// Follows the standard Stellar Asset Contract / SEP-41 surface: only the
@@ -89,11 +193,12 @@ pub enum FlowStep {
     SavingsGoal = 3,
     BillPayment = 4,
     InsurancePremium = 5,
+    Conversion = 6,
 }
 
 use remitwise_common::{
-    EventCategory, EventPriority, RemitwiseEvents, CONTRACT_VERSION, PERSISTENT_BUMP_AMOUNT,
-    PERSISTENT_LIFETIME_THRESHOLD, SNAPSHOT_KEY, SNAPSHOT_VERSION,
+    EventCategory, EventPriority, PeriodKind, RemitwiseEvents, Timestamp, CONTRACT_VERSION,
+    PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD, SNAPSHOT_KEY, SNAPSHOT_VERSION,
 };
 
 // Storage TTL constants for active data
@@ -127,9 +232,56 @@ const FLOW_EXEC_AUDIT: Symbol = symbol_short!("flow_exec");
 /// Storage key for per-address pending reward balances.
 /// Value type: `Map<Address, i128>`.
 const PENDING_REWARDS: Symbol = symbol_short!("PNDG_RWD");
+/// Storage key for the keeper reward configuration.
+/// Value type: `KeeperConfig`.
+const KEEPER_CONFIG: Symbol = symbol_short!("KPR_CFG");
+/// Storage key for the unallocated keeper reward treasury balance.
+/// Value type: `i128`.
+const KEEPER_TREASURY: Symbol = symbol_short!("KPR_TRSY");
+/// Storage key for bounties paid in the current ledger.
+/// Value type: `KeeperLedgerUsage`.
+const KEEPER_USAGE: Symbol = symbol_short!("KPR_USE");
 /// Storage key for the current actor epoch.
 /// Value type: `u64`.
 const ACTOR_EPOCH: Symbol = symbol_short!("ACT_EPOCH");
+/// Persistent storage key prefix for per-user routing profiles.
+/// Key type: `(Symbol, Address)`; value type: `RoutingProfile`.
+const ROUTING_PROFILES: Symbol = symbol_short!("ROUTES");
+/// Persistent storage key prefix for resumable flow records.
+/// Key type: `(Symbol, u64)`; value type: `FlowRecord`.
+const FLOW_RECORD: Symbol = symbol_short!("FLOW_REC");
+/// Storage key for the last assigned flow id.
+/// Value type: `u64`.
+const FLOW_NEXT: Symbol = symbol_short!("FLOW_NEXT");
+/// Persistent storage key prefix for flow schedules.
+/// Key type: `(Symbol, u32)`; value type: `FlowSchedule`.
+const FLOW_SCHEDULE: Symbol = symbol_short!("FSCH");
+/// Persistent storage key prefix for an owner's flow schedule ids.
+/// Key type: `(Symbol, Address)`; value type: `Vec<u32>`.
+const OWNER_FLOW_SCHEDULES: Symbol = symbol_short!("FSCH_OWN");
+/// Storage key for the last assigned flow schedule id.
+/// Value type: `u32`.
+const FLOW_SCHEDULE_NEXT: Symbol = symbol_short!("FSCH_NEXT");
+/// Minimum interval between runs of a recurring flow schedule.
+const MIN_FLOW_SCHEDULE_INTERVAL: u64 = 3_600;
+/// Furthest in the future a flow schedule's first run may be placed.
+const MAX_FLOW_SCHEDULE_LEAD_TIME: u64 = 365 * 24 * 3_600;
+/// Maximum number of flow schedules a single owner may hold.
+const MAX_FLOW_SCHEDULES_PER_OWNER: u32 = 20;
+/// Maximum number of due schedules `execute_due_flows` runs per call, bounding
+/// its cross-contract fan-out.
+const MAX_DUE_FLOWS_PER_CALL: u32 = 10;
+/// Maximum number of targets a [`TargetList`] may hold.
+const MAX_FLOW_TARGETS: u32 = 10;
+/// Persistent storage key prefix for an owner's monthly remittance totals.
+/// Key type: `(Symbol, Address, u64)`; value type: `RemittancePeriod`.
+const REMITTANCE_HISTORY: Symbol = symbol_short!("RHIST");
+/// Persistent storage key prefix for the period keys an owner has history for.
+/// Key type: `(Symbol, Address)`; value type: `Vec<u64>`, ascending.
+const REMITTANCE_HISTORY_INDEX: Symbol = symbol_short!("RHIST_IDX");
+/// Maximum number of months of history kept per owner; the oldest month is
+/// dropped when a new one would exceed it.
+const MAX_REMITTANCE_HISTORY_PERIODS: u32 = 120;
 
 /// Pre-upgrade snapshot for upgrade rollback protection.
 ///
@@ -199,19 +351,144 @@ pub struct FanOutStepResult {
     pub amount: i128,
 }
 
+/// Outcome of a single downstream call (one goal, bill or policy) within a step.
+///
+/// A target whose allocated `amount` is zero is not called and reports success.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FanOutTargetResult {
+    pub step: FlowStep,
+    pub target_id: u32,
+    pub amount: i128,
+    pub succeeded: bool,
+}
+
 /// Aggregate result of execute_flow_fanout — all steps attempted, successes and
 /// failures reported independently. The caller can decide how to handle partial success.
+///
+/// `savings`, `bills` and `insurance` summarise their step (total amount, and
+/// `succeeded` only if every target in it succeeded); `targets` lists each
+/// downstream call in execution order.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct FanOutFlowResult {
     pub savings: FanOutStepResult,
     pub bills: FanOutStepResult,
     pub insurance: FanOutStepResult,
+    pub targets: Vec<FanOutTargetResult>,
     pub all_succeeded: bool,
 }
 
+/// Predicted outcome of one step of a previewed flow.
+///
+/// `error` is the `OrchestratorError` code the step is predicted to fail
+/// with, or 0 if it is expected to pass.
 #[contracttype]
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowStepPreview {
+    pub step: FlowStep,
+    pub amount: i128,
+    pub error: u32,
+}
+
+/// Dry-run result of `preview_remittance_flow` / `preview_flow_fanout`.
+///
+/// `steps` lists every step that was evaluated, in execution order; the
+/// preview keeps going past a failing step so all predicted failures are
+/// reported at once. `targets` holds the planned allocation of every goal,
+/// bill and policy whose step has a valid target list (`succeeded` is always
+/// `true`, as downstream writes are not attempted). `spending_amount` is the
+/// split's spending share, which stays with the user. `error` is the first
+/// predicted error code, or 0 when `would_succeed`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowPreview {
+    pub steps: Vec<FlowStepPreview>,
+    pub targets: Vec<FanOutTargetResult>,
+    pub spending_amount: i128,
+    pub error: u32,
+    pub would_succeed: bool,
+}
+
+/// Remittance distributions recorded for one owner in one calendar month.
+///
+/// `period_key` is the `YYYYMM` key of `Timestamp::to_period_key` (UTC).
+/// `received` is the total amount of every flow started in the month;
+/// `savings`, `bills` and `insurance` are the amounts actually paid into
+/// goals, bills and policies (net of any compensation by `abort_flow`), and
+/// `spending` is the part of `received` not routed to any of them.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemittancePeriod {
+    pub period_key: u64,
+    pub received: i128,
+    pub spending: i128,
+    pub savings: i128,
+    pub bills: i128,
+    pub insurance: i128,
+    pub flow_count: u32,
+}
+
+/// How a step's allocation is divided across the targets of a [`TargetList`].
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum AllocationRule {
+    /// Split in proportion to each target's `weight`. Rounding dust goes to
+    /// the first target.
+    ProRata = 1,
+    /// Fill targets in list order, each up to its `cap`.
+    Priority = 2,
+    /// Fill targets earliest `due_date` first, each up to its `cap`. Ties keep
+    /// list order.
+    DueDate = 3,
+}
+
+/// One goal, bill or policy that a remittance step funds.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowTarget {
+    pub id: u32,
+    /// Relative share under `ProRata`; ignored by the other rules.
+    pub weight: u32,
+    /// Maximum amount under `Priority` / `DueDate`; 0 means uncapped.
+    pub cap: i128,
+    /// Due timestamp ordering targets under `DueDate`.
+    pub due_date: u64,
+}
+
+/// The targets of one remittance step and the rule dividing its allocation.
+///
+/// Under `Priority` and `DueDate` the last target in fill order absorbs any
+/// remainder left once every cap is reached, so the whole step allocation is
+/// always routed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TargetList {
+    pub rule: AllocationRule,
+    pub targets: Vec<FlowTarget>,
+}
+
+impl TargetList {
+    /// A list routing the whole step allocation to `id`.
+    pub fn single(env: &Env, id: u32) -> Self {
+        Self {
+            rule: AllocationRule::Priority,
+            targets: soroban_sdk::vec![
+                env,
+                FlowTarget {
+                    id,
+                    weight: 1,
+                    cap: 0,
+                    due_date: 0,
+                }
+            ],
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
 pub struct RemittanceFlowParams {
     pub caller: Address,
     pub total_amount: i128,
@@ -220,9 +497,164 @@ pub struct RemittanceFlowParams {
     pub savings: Address,
     pub bills: Address,
     pub insurance: Address,
-    pub goal_id: u32,
-    pub bill_id: u32,
-    pub policy_id: u32,
+    pub goal_targets: TargetList,
+    pub bill_targets: TargetList,
+    pub policy_targets: TargetList,
+}
+
+/// Lifecycle of a resumable flow started by `start_flow`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum FlowStatus {
+    /// Every step completed.
+    Completed = 1,
+    /// At least one step has not completed; the flow can be resumed or aborted.
+    PartiallyCompleted = 2,
+    /// Closed by `abort_flow`; see the step statuses for what was reversed.
+    Aborted = 3,
+}
+
+/// Status of one persisted step of a resumable flow.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum StepStatus {
+    Pending = 0,
+    Completed = 1,
+    Failed = 2,
+    Compensated = 3,
+    /// `abort_flow` could not reverse this completed step: the target
+    /// contract rejected or does not expose the reverse call.
+    CompensationFailed = 4,
+}
+
+/// Persisted status of one [`FlowStep`] — or one target of a multi-target
+/// step — within a resumable flow.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowStepRecord {
+    pub step: FlowStep,
+    /// Goal, bill or policy id; 0 for `SpendingCheck` and `SplitCalculation`.
+    pub target_id: u32,
+    pub amount: i128,
+    pub status: StepStatus,
+    pub attempts: u32,
+}
+
+/// A resumable remittance flow and the status of each of its steps.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowRecord {
+    pub id: u64,
+    pub params: RemittanceFlowParams,
+    pub status: FlowStatus,
+    pub steps: Vec<FlowStepRecord>,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+/// Per-user downstream targets for flows that resolve routing from storage
+/// (`execute_flow_fanout`, `execute_remittance_flow_signed`).
+///
+/// Each step's allocation is divided across its list by the list's
+/// [`AllocationRule`], e.g. `DueDate` to settle the oldest bill first. Users
+/// without a profile fall back to the contract-wide `GOAL_ID` / `BILL_ID` /
+/// `POL_ID` defaults written at `init`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoutingProfile {
+    pub goal_targets: TargetList,
+    pub bill_targets: TargetList,
+    pub policy_targets: TargetList,
+    pub updated_at: u64,
+}
+
+/// A recurring or one-off remittance flow run by the permissionless
+/// `execute_due_flows` keeper entrypoint.
+//
+// `next_due` / `interval` / `missed_count` follow `RemittanceSchedule` in
+// `remittance_split`. Instead of authorizing every run, the owner authorizes
+// `spending_cap` once at creation; each successful run adds `amount` to
+// `spent`, and a run that would exceed the cap deactivates the schedule.
+// Runs write through the downstream `*_for` entrypoints with this contract
+// as executor, so the owner must also register the orchestrator with
+// `set_authorized_executor` on savings goals, bill payments and insurance.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowSchedule {
+    pub id: u32,
+    pub owner: Address,
+    pub amount: i128,
+    pub goal_targets: TargetList,
+    pub bill_targets: TargetList,
+    pub policy_targets: TargetList,
+    pub next_due: u64,
+    pub interval: u64,
+    pub recurring: bool,
+    pub active: bool,
+    pub created_at: u64,
+    pub last_executed: Option<u64>,
+    pub missed_count: u32,
+    pub spending_cap: i128,
+    pub spent: i128,
+}
+
+/// Keeper reward settings, set by the contract owner.
+//
+// Every schedule a keeper executes through `run_keeper` earns `bounty`
+// (in `reward_token` units), accrued to its pending rewards and paid out of
+// the keeper treasury. Since anyone can create cheap schedules and execute
+// them, bounties are limited to `max_rewards_per_ledger` across all keepers
+// and `max_rewards_per_keeper` per keeper in any one ledger, bounding how
+// fast the treasury can be drained.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeeperConfig {
+    pub reward_token: Address,
+    pub bounty: i128,
+    pub max_rewards_per_ledger: u32,
+    pub max_rewards_per_keeper: u32,
+}
+
+/// Bounties paid so far in ledger `sequence`; reset on the first
+/// `run_keeper` of each ledger.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeeperLedgerUsage {
+    pub sequence: u32,
+    pub rewarded: u32,
+    pub per_keeper: Map<Address, u32>,
+}
+
+/// Outcome of a `run_keeper` call: the number of schedules executed in each
+/// contract, and how many of them earned a bounty.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeeperRunResult {
+    pub flows: u32,
+    pub remittances: u32,
+    pub savings: u32,
+    pub bills: u32,
+    pub premiums: u32,
+    pub rewarded: u32,
+    pub reward: i128,
+}
+
+/// Optional conversion step of `execute_converted_flow`: the remittance is
+/// swapped from `token_in` (the corridor's source asset) to `token_out` (the
+/// asset the recipient's goals, bills and policies are denominated in) through
+/// `pool` before it is split.
+///
+/// `min_out` is the least the sender accepts for the whole remittance and is
+/// enforced both by the pool and again by the orchestrator.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConversionParams {
+    pub pool: Address,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub min_out: i128,
 }
 
 /// Resolved downstream targets for the remittance fan-out.
@@ -233,9 +665,12 @@ struct FlowRouting {
     savings: Address,
     bills: Address,
     insurance: Address,
-    goal_id: u32,
-    bill_id: u32,
-    policy_id: u32,
+    goal_targets: TargetList,
+    bill_targets: TargetList,
+    policy_targets: TargetList,
+    /// Set for scheduled runs: downstream writes and reversals go through the
+    /// `*_for` entrypoints with this executor acting for the flow's owner.
+    executor: Option<Address>,
 }
 
 impl FlowRouting {
@@ -246,9 +681,10 @@ impl FlowRouting {
             savings: params.savings.clone(),
             bills: params.bills.clone(),
             insurance: params.insurance.clone(),
-            goal_id: params.goal_id,
-            bill_id: params.bill_id,
-            policy_id: params.policy_id,
+            goal_targets: params.goal_targets.clone(),
+            bill_targets: params.bill_targets.clone(),
+            policy_targets: params.policy_targets.clone(),
+            executor: None,
         }
     }
 
@@ -279,23 +715,42 @@ impl FlowRouting {
                 .instance()
                 .get(&symbol_short!("INS_ADDR"))
                 .ok_or(OrchestratorError::InvalidDependency)?,
-            goal_id: env
-                .storage()
-                .instance()
-                .get(&symbol_short!("GOAL_ID"))
-                .unwrap_or(1),
-            bill_id: env
-                .storage()
-                .instance()
-                .get(&symbol_short!("BILL_ID"))
-                .unwrap_or(1),
-            policy_id: env
-                .storage()
-                .instance()
-                .get(&symbol_short!("POL_ID"))
-                .unwrap_or(1),
+            goal_targets: TargetList::single(
+                env,
+                env.storage()
+                    .instance()
+                    .get(&symbol_short!("GOAL_ID"))
+                    .unwrap_or(1),
+            ),
+            bill_targets: TargetList::single(
+                env,
+                env.storage()
+                    .instance()
+                    .get(&symbol_short!("BILL_ID"))
+                    .unwrap_or(1),
+            ),
+            policy_targets: TargetList::single(
+                env,
+                env.storage()
+                    .instance()
+                    .get(&symbol_short!("POL_ID"))
+                    .unwrap_or(1),
+            ),
+            executor: None,
         })
     }
+
+    /// Resolve routing from storage, replacing the contract-wide execution IDs
+    /// with `user`'s routing profile when one is set.
+    fn for_user(env: &Env, user: &Address) -> Result<Self, OrchestratorError> {
+        let mut routing = Self::from_storage(env)?;
+        if let Some(profile) = Orchestrator::load_routing_profile(env, user) {
+            routing.goal_targets = profile.goal_targets;
+            routing.bill_targets = profile.bill_targets;
+            routing.policy_targets = profile.policy_targets;
+        }
+        Ok(routing)
+    }
 }
 
 #[contracterror]
@@ -335,6 +790,33 @@ pub enum OrchestratorError {
     SnapshotTooOld = 14,
     /// The actor epoch does not match the contract's current epoch.
     EpochMismatch = 15,
+    /// A target list is empty, longer than `MAX_FLOW_TARGETS`, contains a
+    /// duplicate id or negative cap, or has zero total weight under `ProRata`.
+    InvalidTargetList = 16,
+    /// No resumable flow exists with the given id.
+    FlowNotFound = 17,
+    /// The flow is not `PartiallyCompleted`, so it cannot be resumed or aborted.
+    InvalidFlowState = 18,
+    /// No flow schedule exists with the given id for this owner.
+    ScheduleNotFound = 19,
+    /// `next_due` is not in the future or too far out, `interval` is below
+    /// `MIN_FLOW_SCHEDULE_INTERVAL`, or `spending_cap` does not cover one run.
+    InvalidSchedule = 20,
+    /// The owner already holds `MAX_FLOW_SCHEDULES_PER_OWNER` schedules.
+    ScheduleLimitReached = 21,
+    /// `bounty` is negative or the per-ledger reward limits are inconsistent.
+    InvalidKeeperConfig = 22,
+    /// The treasury cannot be funded before `set_keeper_config` is called.
+    KeeperNotConfigured = 23,
+    /// `min_out <= 0`, `token_in == token_out`, or the pool is this contract.
+    InvalidConversion = 24,
+    /// The swap pool returned less than the requested `min_out`.
+    SlippageExceeded = 25,
+    /// `reward_token` is not the token pending rewards are accrued in.
+    RewardTokenMismatch = 26,
+    /// `reward_token` cannot change while the keeper treasury or pending
+    /// rewards hold balances in the current token.
+    KeeperRewardsOutstanding = 27,
 }
 
 #[contract]
@@ -382,6 +864,79 @@ impl Orchestrator {
         Self::record_flow_outcome(&env, &params.caller, params.total_amount, result)
     }
 
+    /// Executes the remittance flow with a conversion step: `params.total_amount`
+    /// of `conversion.token_in` is swapped into `conversion.token_out`, and the
+    /// converted amount is what gets split and paid to goals, bills and
+    /// policies.
+    //
+    // The spending-limit check applies to the unconverted amount. The swap
+    // and all downstream calls happen in one transaction, so a failed flow
+    // (including a failed or under-delivering swap) leaves no partial state.
+    // Lifecycle events, stats and audit entries match
+    // [`Self::execute_remittance_flow`] and are recorded in source units.
+    //
+    // Returns the converted amount.
+    //
+    // # Errors
+    // - `InvalidAmount` if `params.total_amount <= 0`
+    // - `InvalidConversion` if the conversion parameters are malformed
+    // - `ExecutionLocked` if another flow is in progress
+    // - `CrossContractCallFailed` if the pool rejects the swap (for example
+    //   because its own `min_out` check failed)
+    // - `SlippageExceeded` if the pool returned less than `min_out`
+    // - any error of [`Self::execute_remittance_flow`]
+    //
+    // # Events
+    // Emits `convert` with `(token_in, token_out, amount_in, amount_out)`.
+    pub fn execute_converted_flow(
+        env: Env,
+        params: RemittanceFlowParams,
+        conversion: ConversionParams,
+    ) -> Result<i128, OrchestratorError> {
+        params.caller.require_auth();
+
+        if params.total_amount <= 0 {
+            Self::record_flow_validation_failure(&env, &params.caller);
+            return Err(OrchestratorError::InvalidAmount);
+        }
+        if conversion.min_out <= 0
+            || conversion.token_in == conversion.token_out
+            || conversion.pool == env.current_contract_address()
+        {
+            Self::record_flow_validation_failure(&env, &params.caller);
+            return Err(OrchestratorError::InvalidConversion);
+        }
+
+        let is_locked: bool = env.storage().instance().get(&EXEC_LOCK).unwrap_or(false);
+        if is_locked {
+            Self::record_flow_validation_failure(&env, &params.caller);
+            return Err(OrchestratorError::ExecutionLocked);
+        }
+
+        Self::emit_flow_started(&env, &params.caller, params.total_amount);
+
+        let mut converted = 0;
+        let result = {
+            Self::extend_instance_ttl(&env);
+            let _guard = Self::acquire_execution_lock(&env)?;
+
+            let routing = FlowRouting::from_params(&params);
+            Self::check_spending_limit(&env, &params.caller, params.total_amount, &routing)
+                .and_then(|()| {
+                    Self::convert(&env, &params.caller, params.total_amount, &conversion)
+                })
+                .and_then(|amount_out| {
+                    converted = amount_out;
+                    Self::split_and_fan_out(&env, &params.caller, amount_out, &routing, true)
+                })
+        };
+
+        // Report the converted amount, the units the flow distributed and its
+        // history records; `convert` relates it to the source amount.
+        Self::record_flow_outcome(&env, &params.caller, converted, result)?;
+        Ok(converted)
+    }
+
     fn perform_remittance_flow(
         env: &Env,
         params: &RemittanceFlowParams,
@@ -391,7 +946,7 @@ impl Orchestrator {
             &params.caller,
             params.total_amount,
             &FlowRouting::from_params(params),
-            false,
+            true,
         )
     }
 
@@ -425,10 +980,10 @@ impl Orchestrator {
     /// (see `require_nonce_hardened`) instead of relying on the
     /// `MAX_DEADLINE_WINDOW_SECS` default. Kept as a separate entrypoint so
     /// `init`'s signature and existing callers are unaffected.
-    ///
-    /// # Errors
-    /// - `InvalidAmount` if `deadline_window_secs == 0` -- a zero window
-    ///   would make every signed flow deadline immediately expired.
+    //
+    // # Errors
+    // - `InvalidAmount` if `deadline_window_secs == 0` -- a zero window
+    //   would make every signed flow deadline immediately expired.
     pub fn init_with_deadline_window(
         env: Env,
         caller: Address,
@@ -572,22 +1127,22 @@ impl Orchestrator {
     }
 
     /// Execute a remittance flow with replay protection.
-    ///
-    /// # Security
-    /// - Authorization-first pattern
-    /// - Execution lock to prevent cross-contract reentrancy
-    /// - Nonce replay protection with deadline window validation
-    /// - Request hash binding to prevent parameter-swap attacks
-    /// - Epoch validation to prevent stale actor token replay
-    ///
-    /// # Errors
-    /// - `Unauthorized` if executor doesn't authorize or contract not initialized
-    /// - `InvalidAmount` if amount <= 0
-    /// - `DeadlineExpired` if deadline is invalid or passed
-    /// - `InvalidNonce` if nonce or hash is invalid
-    /// - `NonceAlreadyUsed` if nonce was already used
-    /// - `ExecutionLocked` if reentrancy detected
-    /// - `EpochMismatch` if actor_epoch does not match current epoch
+    //
+    // # Security
+    // - Authorization-first pattern
+    // - Execution lock to prevent cross-contract reentrancy
+    // - Nonce replay protection with deadline window validation
+    // - Request hash binding to prevent parameter-swap attacks
+    // - Epoch validation to prevent stale actor token replay
+    //
+    // # Errors
+    // - `Unauthorized` if executor doesn't authorize or contract not initialized
+    // - `InvalidAmount` if amount <= 0
+    // - `DeadlineExpired` if deadline is invalid or passed
+    // - `InvalidNonce` if nonce or hash is invalid
+    // - `NonceAlreadyUsed` if nonce was already used
+    // - `ExecutionLocked` if reentrancy detected
+    // - `EpochMismatch` if actor_epoch does not match current epoch
     pub fn execute_remittance_flow_signed(
         env: Env,
         executor: Address,
@@ -624,18 +1179,19 @@ impl Orchestrator {
         Self::verify_matching_epoch(&env, actor_epoch)?;
 
         // 6. Hardened nonce validation with deadline + hash binding.
-        // Execution parameter IDs are read from instance storage (defaults set
-        // at init) and folded into the hash so relayers cannot redirect funds
-        // to a different goal/bill/policy after signing.
-        let routing = FlowRouting::from_storage(&env)?;
+        // Execution parameter IDs are resolved from the executor's routing
+        // profile (or the defaults set at init) and folded into the hash so
+        // relayers cannot redirect funds to a different goal/bill/policy
+        // after signing.
+        let routing = FlowRouting::for_user(&env, &executor)?;
         let expected_hash = Self::compute_request_hash(
             symbol_short!("flow"),
             nonce,
             amount,
             deadline,
-            routing.goal_id,
-            routing.bill_id,
-            routing.policy_id,
+            Self::target_binding(&env, &routing.goal_targets),
+            Self::target_binding(&env, &routing.bill_targets),
+            Self::target_binding(&env, &routing.policy_targets),
         );
         Self::require_nonce_hardened(
             &env,
@@ -668,21 +1224,21 @@ impl Orchestrator {
     /// Fan-out execute: attempt all three downstream cross-contract steps independently,
     /// reporting per-step success/failure. Unlike execute_remittance_flow, no compensation
     /// is applied — callers receive the full result and decide how to handle partial success.
-    ///
-    /// Useful for idempotent retries or best-effort distribution where partial progress
-    /// is preferable to full rollback.
-    ///
-    /// # Memoised fee lookup — fix for #1339
-    /// Dependency addresses and execution IDs are read from instance storage exactly
-    /// once via `FlowRouting::from_storage`, and the split allocation is computed via
-    /// a single `calculate_split` cross-contract call.  Previous versions called
-    /// 6 individual `instance().get(...)` lookups and used a hardcoded `amount / 3`
-    /// approximation rather than the configured split percentages.
-    ///
-    /// # Correct succeeded semantics — fix for #1345
-    /// `succeeded` is set to `true` when the downstream `try_*` call returns `Ok`,
-    /// and `false` when it returns `Err`.  The previous code used `.is_err()` for
-    /// all three assignments, which inverted both per-step flags and `all_succeeded`.
+    //
+    // Useful for idempotent retries or best-effort distribution where partial progress
+    // is preferable to full rollback.
+    //
+    // # Memoised fee lookup — fix for #1339
+    // Dependency addresses and execution IDs are read from instance storage exactly
+    // once via `FlowRouting::for_user`, and the split allocation is computed via
+    // a single `calculate_split` cross-contract call.  Previous versions called
+    // 6 individual `instance().get(...)` lookups and used a hardcoded `amount / 3`
+    // approximation rather than the configured split percentages.
+    //
+    // # Correct succeeded semantics — fix for #1345
+    // `succeeded` is set to `true` when the downstream `try_*` call returns `Ok`,
+    // and `false` when it returns `Err`.  The previous code used `.is_err()` for
+    // all three assignments, which inverted both per-step flags and `all_succeeded`.
     pub fn execute_flow_fanout(
         env: Env,
         executor: Address,
@@ -696,149 +1252,987 @@ impl Orchestrator {
         }
 
         // --- #1339: read all dependency addresses and IDs in one pass ----------
-        let routing = FlowRouting::from_storage(&env)?;
+        let routing = FlowRouting::for_user(&env, &executor)?;
 
         // --- #1339: single cross-contract call to fetch the configured split ---
-        // `calculate_split` returns [spending, savings, bills, insurance] amounts.
-        // We call it once and reuse the result for all three fan-out steps,
-        // so the fee schedule is fetched exactly once per batch invocation.
-        let rs_client = interface::RemittanceSplitClient::new(&env, &routing.remittance_split);
-        let allocations = rs_client.calculate_split(&amount);
-
-        if allocations.len() < 4 {
-            return Err(OrchestratorError::InvalidAmount);
-        }
-
-        let savings_amt = allocations
-            .get(1)
-            .ok_or(OrchestratorError::InvalidAmount)?;
-        let bills_amt = allocations
-            .get(2)
-            .ok_or(OrchestratorError::InvalidAmount)?;
-        let insurance_amt = allocations
-            .get(3)
-            .ok_or(OrchestratorError::InvalidAmount)?;
-
-        if savings_amt < 0 || bills_amt < 0 || insurance_amt < 0 {
-            return Err(OrchestratorError::InvalidAmount);
+        // `plan_fan_out` calls `calculate_split` once and expands each step's
+        // allocation over its target list, so the fee schedule is fetched
+        // exactly once per batch invocation.
+        let mut targets = Self::plan_fan_out(&env, amount, &routing)?;
+
+        // --- #1345: succeeded=true when the call succeeds --------------------
+        for i in 0..targets.len() {
+            if let Some(mut target) = targets.get(i) {
+                target.succeeded = target.amount == 0
+                    || Self::execute_target(
+                        &env,
+                        &executor,
+                        &routing,
+                        target.step,
+                        target.target_id,
+                        target.amount,
+                    )
+                    .is_ok();
+                targets.set(i, target);
+            }
         }
+        Self::record_distribution(
+            &env,
+            &executor,
+            amount,
+            amount - Self::planned_total(&targets),
+            &targets,
+        );
 
-        // --- #1345: use .is_ok() so succeeded=true when the call succeeds -----
-        let s_ok = interface::SavingsGoalsClient::new(&env, &routing.savings)
-            .try_add_to_goal(&executor, &routing.goal_id, &savings_amt)
-            .is_ok();
-        let b_ok = interface::BillPaymentsClient::new(&env, &routing.bills)
-            .try_pay_bill(&executor, &routing.bill_id, &bills_amt)
-            .is_ok();
-        let i_ok = interface::InsuranceClient::new(&env, &routing.insurance)
-            .try_pay_premium(&executor, &routing.policy_id, &insurance_amt)
-            .is_ok();
-
-        let savings = FanOutStepResult {
-            step: FlowStep::SavingsGoal,
-            succeeded: s_ok,
-            amount: savings_amt,
-        };
-        let bills = FanOutStepResult {
-            step: FlowStep::BillPayment,
-            succeeded: b_ok,
-            amount: bills_amt,
-        };
-        let insurance = FanOutStepResult {
-            step: FlowStep::InsurancePremium,
-            succeeded: i_ok,
-            amount: insurance_amt,
-        };
-        let all_succeeded = s_ok && b_ok && i_ok;
+        let savings = Self::summarize_step(&targets, FlowStep::SavingsGoal);
+        let bills = Self::summarize_step(&targets, FlowStep::BillPayment);
+        let insurance = Self::summarize_step(&targets, FlowStep::InsurancePremium);
+        let all_succeeded = savings.succeeded && bills.succeeded && insurance.succeeded;
 
         Ok(FanOutFlowResult {
             savings,
             bills,
             insurance,
+            targets,
             all_succeeded,
         })
     }
 
-    /// Get the current execution nonce for an address.
-    pub fn get_nonce(env: Env, address: Address) -> u64 {
-        Self::get_nonce_value(&env, &address)
-    }
-
-    /// Get current execution statistics, including evicted audit entry count.
-    pub fn get_execution_stats(env: Env) -> Option<ExecutionStats> {
-        Self::extend_instance_ttl(&env);
-        env.storage().instance().get(&symbol_short!("STATS"))
+    /// Dry-run [`Self::execute_remittance_flow`] for `params` without changing
+    /// state.
+    //
+    // Applies the same amount and lock validation, then makes read-only
+    // `check_spending_limit` and `calculate_split` calls and plans every
+    // target list exactly as the real flow would. Downstream writes are not
+    // attempted, so a goal, bill or policy the downstream contract itself
+    // would reject is only caught by simulating the transaction.
+    //
+    // No authorization is required and no events or audit entries are
+    // written. See [`FlowPreview`] for how failures are reported.
+    pub fn preview_remittance_flow(env: Env, params: RemittanceFlowParams) -> FlowPreview {
+        Self::preview_flow(
+            &env,
+            &params.caller,
+            params.total_amount,
+            Ok(FlowRouting::from_params(&params)),
+            true,
+        )
     }
 
-    /// Get the current fee schedule (split percentages) from the remittance split contract.
-    ///
-    /// Returns a tuple of (spending_percent, savings_percent, bills_percent, insurance_percent)
-    /// representing the percentage allocation for each category. The percentages are
-    /// in basis points (1% = 100 basis points).
+    /// Dry-run [`Self::execute_flow_fanout`] for `executor`, resolving routing
+    /// from storage and `executor`'s routing profile the same way.
     ///
-    /// This is a read-only view function that does not require authorization.
-    /// Returns the configured signed-flow deadline window in seconds -- the
-    /// maximum distance into the future a caller-supplied deadline may sit
-    /// (see `require_nonce_hardened`). Falls back to `MAX_DEADLINE_WINDOW_SECS`
-    /// if `init_with_deadline_window` was never called (i.e. plain `init` was
-    /// used). No authentication required — observable on-chain.
-    pub fn get_deadline_window(env: Env) -> u64 {
-        env.storage()
-            .instance()
-            .get(&symbol_short!("DL_WIN"))
-            .unwrap_or(MAX_DEADLINE_WINDOW_SECS)
+    /// Like the fan-out itself, no lock or spending-limit check is made; an
+    /// uninitialized orchestrator is reported as `InvalidDependency`. See
+    /// [`Self::preview_remittance_flow`].
+    pub fn preview_flow_fanout(env: Env, executor: Address, amount: i128) -> FlowPreview {
+        let routing = FlowRouting::for_user(&env, &executor);
+        Self::preview_flow(&env, &executor, amount, routing, false)
     }
 
-    pub fn get_fee_schedule(env: Env) -> Option<(u32, u32, u32, u32)> {
-        let rs_addr: Address = env.storage().instance().get(&symbol_short!("RS_ADDR"))?;
-
-        let rs_client = interface::RemittanceSplitClient::new(&env, &rs_addr);
-        let split = rs_client.get_split();
+    /// Start a resumable remittance flow and persist the status of each step.
+    //
+    // Performs the spending check and split of [`Self::execute_remittance_flow`],
+    // then attempts every downstream target independently. Instead of
+    // compensating on failure, the outcome is stored under a new flow id; a
+    // flow with failed steps is left `PartiallyCompleted` so the caller can
+    // [`Self::resume_flow`] it once the downstream contract recovers, or
+    // [`Self::abort_flow`] it.
+    //
+    // # Errors
+    // - `InvalidAmount` if `total_amount <= 0` or the split is malformed
+    // - `ExecutionLocked` if another flow is in progress
+    // - `Unauthorized` if the spending check rejects the amount
+    // - `InvalidTargetList` if a target list is malformed
+    //
+    // # Events
+    // Emits `flow`, then `flow_ok` if every step completed or `flow_part`
+    // with `(caller, flow_id)` otherwise.
+    pub fn start_flow(
+        env: Env,
+        params: RemittanceFlowParams,
+    ) -> Result<FlowRecord, OrchestratorError> {
+        params.caller.require_auth();
 
-        if split.len() != 4 {
-            return None;
+        if params.total_amount <= 0 {
+            Self::record_flow_validation_failure(&env, &params.caller);
+            return Err(OrchestratorError::InvalidAmount);
+        }
+        if Self::require_no_pending_ops(&env).is_err() {
+            Self::record_flow_validation_failure(&env, &params.caller);
+            return Err(OrchestratorError::ExecutionLocked);
         }
 
-        Some((split.get(0)?, split.get(1)?, split.get(2)?, split.get(3)?))
+        Self::emit_flow_started(&env, &params.caller, params.total_amount);
+
+        let result = {
+            Self::extend_instance_ttl(&env);
+            let _guard = Self::acquire_execution_lock(&env)?;
+            Self::begin_flow(&env, &params)
+        };
+
+        match result {
+            Ok(record) if record.status == FlowStatus::Completed => {
+                Self::record_flow_outcome(&env, &params.caller, params.total_amount, Ok(()))?;
+                Ok(record)
+            }
+            Ok(record) => {
+                Self::update_execution_stats(&env, false);
+                Self::append_audit(&env, FLOW_EXEC_AUDIT, &params.caller, false);
+                RemitwiseEvents::emit(
+                    &env,
+                    EventCategory::Transaction,
+                    EventPriority::High,
+                    symbol_short!("flow_part"),
+                    (params.caller.clone(), record.id),
+                );
+                Ok(record)
+            }
+            Err(e) => {
+                Self::record_flow_outcome(&env, &params.caller, params.total_amount, Err(e))?;
+                Err(e)
+            }
+        }
     }
 
-    /// Claim accrued rewards and transfer them from the reward-token contract
-    /// to the caller.
-    ///
-    /// # Threat mitigated — T-RE-02: re-entrant reward drain
-    ///
-    /// **Attack surface without this guard:**
-    /// 1. `caller` invokes `claim_rewards_summary_external`.
-    /// 2. Contract reads `pending` balance (e.g. 1 000 tokens) from storage.
-    /// 3. Contract calls `token.transfer(this, caller, 1_000)`.
-    /// 4. A malicious token contract re-enters `claim_rewards_summary_external`
-    ///    before the balance is written to zero.
-    /// 5. Step 4 reads `pending` again — still 1 000 — and triggers a second
-    ///    transfer, draining twice the entitlement.
-    ///
-    /// **Fix applied:**
-    /// * `EXEC_LOCK` is acquired (via RAII `LockGuard`) **before** any read of
-    ///   the pending balance.
-    /// * The pending balance is zeroed in storage (**effect written**) *before*
-    ///   the external `token.transfer` call (**interaction**), following the
-    ///   Checks-Effects-Interactions pattern.
-    /// * Any re-entrant call while the lock is held receives
-    ///   `OrchestratorError::ReentrancyDetected` immediately, without performing
-    ///   any state mutation or token transfer.
-    ///
-    /// # Authorization
-    /// `caller` must authorize the transaction.
-    ///
-    /// # Parameters
-    /// - `caller`       — address whose pending reward balance is claimed.
-    /// - `reward_token` — address of the SEP-41-compatible reward token contract.
-    ///
-    /// # Returns
-    /// The amount transferred (always > 0 on success).
+    /// Retry every step of a `PartiallyCompleted` flow that has not completed.
+    /// Completed steps are never re-executed.
+    //
+    // # Errors
+    // - `FlowNotFound` if `flow_id` is unknown
+    // - `Unauthorized` if `caller` did not start the flow
+    // - `InvalidFlowState` if the flow is already completed or aborted
+    // - `ExecutionLocked` if another flow is in progress
+    //
+    // # Events
+    // Emits `flow_rsm` with `(flow_id, status)`.
+    pub fn resume_flow(
+        env: Env,
+        caller: Address,
+        flow_id: u64,
+    ) -> Result<FlowRecord, OrchestratorError> {
+        caller.require_auth();
+        let mut record = Self::load_open_flow(&env, &caller, flow_id)?;
+
+        {
+            Self::extend_instance_ttl(&env);
+            let _guard = Self::acquire_execution_lock(&env)?;
+            let routing = FlowRouting::from_params(&record.params);
+            let completed = Self::run_outstanding_steps(&env, &mut record, &routing);
+            Self::record_distribution(&env, &caller, 0, 0, &completed);
+            Self::save_flow(&env, &record);
+        }
+
+        Self::append_audit(
+            &env,
+            symbol_short!("flow_rsm"),
+            &caller,
+            record.status == FlowStatus::Completed,
+        );
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Transaction,
+            EventPriority::Medium,
+            symbol_short!("flow_rsm"),
+            (flow_id, record.status),
+        );
+        Ok(record)
+    }
+
+    /// Abort a `PartiallyCompleted` flow, compensating its completed write
+    /// steps (newest first) through the reverse interfaces.
+    //
+    // The flow always becomes `Aborted`. Each reversed step becomes
+    // `Compensated`; a step whose target contract rejects or lacks the
+    // reverse call becomes `CompensationFailed` and must be settled with
+    // that contract directly.
+    //
+    // # Errors
+    // Same as [`Self::resume_flow`].
+    //
+    // # Events
+    // Emits `flow_abrt` with `(flow_id, status)`.
+    pub fn abort_flow(
+        env: Env,
+        caller: Address,
+        flow_id: u64,
+    ) -> Result<FlowRecord, OrchestratorError> {
+        caller.require_auth();
+        let mut record = Self::load_open_flow(&env, &caller, flow_id)?;
+
+        {
+            Self::extend_instance_ttl(&env);
+            let _guard = Self::acquire_execution_lock(&env)?;
+            let routing = FlowRouting::from_params(&record.params);
+            let mut reversed = Vec::new(&env);
+            for i in (0..record.steps.len()).rev() {
+                let Some(mut step) = record.steps.get(i) else {
+                    continue;
+                };
+                if step.status != StepStatus::Completed || !Self::is_write_step(step.step) {
+                    continue;
+                }
+                if step.amount == 0
+                    || Self::compensate_target(
+                        &env,
+                        &caller,
+                        &routing,
+                        step.step,
+                        step.target_id,
+                        step.amount,
+                    )
+                {
+                    reversed.push_back(FanOutTargetResult {
+                        step: step.step,
+                        target_id: step.target_id,
+                        amount: -step.amount,
+                        succeeded: true,
+                    });
+                    step.status = StepStatus::Compensated;
+                } else {
+                    step.status = StepStatus::CompensationFailed;
+                }
+                record.steps.set(i, step);
+            }
+            Self::record_distribution(&env, &caller, 0, 0, &reversed);
+            record.status = FlowStatus::Aborted;
+            record.updated_at = env.ledger().timestamp();
+            Self::save_flow(&env, &record);
+        }
+
+        let clean = !record
+            .steps
+            .iter()
+            .any(|s| s.status == StepStatus::CompensationFailed);
+        Self::append_audit(&env, symbol_short!("flow_abrt"), &caller, clean);
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Transaction,
+            EventPriority::High,
+            symbol_short!("flow_abrt"),
+            (flow_id, record.status),
+        );
+        Ok(record)
+    }
+
+    /// Return a resumable flow record by id.
+    pub fn get_flow(env: Env, flow_id: u64) -> Option<FlowRecord> {
+        env.storage().persistent().get(&(FLOW_RECORD, flow_id))
+    }
+
+    /// Return `owner`'s recorded remittance totals for every month whose
+    /// `YYYYMM` period key lies in `from_period..=to_period`, oldest first.
+    //
+    // Every flow entrypoint records what it actually distributed: completed
+    // atomic flows (`execute_remittance_flow`, `execute_remittance_flow_signed`,
+    // `execute_converted_flow` in converted units, scheduled flows), the
+    // successful targets of `execute_flow_fanout`, and each step of a
+    // resumable flow in the month it completes or is compensated. At most
+    // `MAX_REMITTANCE_HISTORY_PERIODS` (120) months are kept per owner.
+    pub fn get_remittance_history(
+        env: Env,
+        owner: Address,
+        from_period: u64,
+        to_period: u64,
+    ) -> Vec<RemittancePeriod> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&(REMITTANCE_HISTORY_INDEX, owner.clone()))
+            .unwrap_or_else(|| Vec::new(&env));
+        let mut periods = Vec::new(&env);
+        for period_key in index.iter() {
+            if period_key < from_period || period_key > to_period {
+                continue;
+            }
+            if let Some(period) = env.storage().persistent().get::<_, RemittancePeriod>(&(
+                REMITTANCE_HISTORY,
+                owner.clone(),
+                period_key,
+            )) {
+                periods.push_back(period);
+            }
+        }
+        periods
+    }
+
+    /// Schedule the remittance flow for `owner`: `amount` split across the
+    /// given target lists, first at `next_due` and then every `interval`
+    /// seconds (`interval == 0` runs once).
+    //
+    // Dependency addresses are those configured at `init`. `spending_cap` is
+    // the total the owner pre-authorizes across all runs, so keepers can run
+    // the schedule through [`Self::execute_due_flows`] without per-run auth.
+    // Runs only succeed once `owner` has registered this contract with
+    // `set_authorized_executor` on each downstream contract the targets use.
+    //
+    // # Errors
+    // - `InvalidAmount` if `amount <= 0`
+    // - `InvalidSchedule` if the timing or cap is invalid
+    // - `InvalidTargetList` if a target list is malformed
+    // - `ScheduleLimitReached` if the owner is at `MAX_FLOW_SCHEDULES_PER_OWNER`
+    //
+    // # Events
+    // Emits `fsch_new` with `(schedule_id, owner)`.
+    pub fn create_flow_schedule(
+        env: Env,
+        owner: Address,
+        amount: i128,
+        goal_targets: TargetList,
+        bill_targets: TargetList,
+        policy_targets: TargetList,
+        next_due: u64,
+        interval: u64,
+        spending_cap: i128,
+    ) -> Result<u32, OrchestratorError> {
+        owner.require_auth();
+
+        if amount <= 0 {
+            return Err(OrchestratorError::InvalidAmount);
+        }
+        let now = env.ledger().timestamp();
+        if next_due <= now
+            || next_due - now > MAX_FLOW_SCHEDULE_LEAD_TIME
+            || (interval > 0 && interval < MIN_FLOW_SCHEDULE_INTERVAL)
+            || spending_cap < amount
+        {
+            return Err(OrchestratorError::InvalidSchedule);
+        }
+        Self::validate_target_list(&goal_targets)?;
+        Self::validate_target_list(&bill_targets)?;
+        Self::validate_target_list(&policy_targets)?;
+
+        let owner_key = (OWNER_FLOW_SCHEDULES, owner.clone());
+        let mut owner_ids: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&owner_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        if owner_ids.len() >= MAX_FLOW_SCHEDULES_PER_OWNER {
+            return Err(OrchestratorError::ScheduleLimitReached);
+        }
+
+        Self::extend_instance_ttl(&env);
+        let id = env
+            .storage()
+            .instance()
+            .get::<_, u32>(&FLOW_SCHEDULE_NEXT)
+            .unwrap_or(0)
+            .checked_add(1)
+            .ok_or(OrchestratorError::Overflow)?;
+        env.storage().instance().set(&FLOW_SCHEDULE_NEXT, &id);
+
+        Self::save_flow_schedule(
+            &env,
+            &FlowSchedule {
+                id,
+                owner: owner.clone(),
+                amount,
+                goal_targets,
+                bill_targets,
+                policy_targets,
+                next_due,
+                interval,
+                recurring: interval > 0,
+                active: true,
+                created_at: now,
+                last_executed: None,
+                missed_count: 0,
+                spending_cap,
+                spent: 0,
+            },
+        );
+        owner_ids.push_back(id);
+        env.storage().persistent().set(&owner_key, &owner_ids);
+        env.storage().persistent().extend_ttl(
+            &owner_key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+
+        Self::append_audit(&env, symbol_short!("fsch_new"), &owner, true);
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::State,
+            EventPriority::Medium,
+            symbol_short!("fsch_new"),
+            (id, owner),
+        );
+        Ok(id)
+    }
+
+    /// Replace the pre-authorized spending cap of one of `owner`'s schedules.
+    //
+    // A cap that no longer covers another run leaves the schedule inactive;
+    // raising the cap of a schedule deactivated by its cap reactivates it.
+    //
+    // # Errors
+    // - `ScheduleNotFound` if the schedule does not exist or is not `owner`'s
+    // - `InvalidSchedule` if `new_cap` is below what was already spent
+    pub fn set_flow_schedule_cap(
+        env: Env,
+        owner: Address,
+        schedule_id: u32,
+        new_cap: i128,
+    ) -> Result<bool, OrchestratorError> {
+        owner.require_auth();
+        let mut schedule = Self::load_owned_flow_schedule(&env, &owner, schedule_id)?;
+        if new_cap < schedule.spent {
+            return Err(OrchestratorError::InvalidSchedule);
+        }
+        schedule.spending_cap = new_cap;
+        let covers_next_run = schedule
+            .spent
+            .checked_add(schedule.amount)
+            .is_some_and(|total| total <= new_cap);
+        let exhausted = schedule.last_executed.is_some() && !schedule.recurring;
+        schedule.active = covers_next_run && !exhausted;
+        Self::save_flow_schedule(&env, &schedule);
+        Self::append_audit(&env, symbol_short!("fsch_cap"), &owner, true);
+        Ok(true)
+    }
+
+    /// Cancel one of `owner`'s flow schedules.
     ///
     /// # Errors
-    /// - `NoPendingRewards`   — caller has no accrued rewards.
-    /// - `ReentrancyDetected` — lock already held; re-entrant call rejected.
+    /// - `ScheduleNotFound` if the schedule does not exist or is not `owner`'s
+    ///
+    /// # Events
+    /// Emits `fsch_cncl` with `schedule_id`.
+    pub fn cancel_flow_schedule(
+        env: Env,
+        owner: Address,
+        schedule_id: u32,
+    ) -> Result<bool, OrchestratorError> {
+        owner.require_auth();
+        let mut schedule = Self::load_owned_flow_schedule(&env, &owner, schedule_id)?;
+        schedule.active = false;
+        Self::save_flow_schedule(&env, &schedule);
+        Self::append_audit(&env, symbol_short!("fsch_cncl"), &owner, true);
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::State,
+            EventPriority::Medium,
+            symbol_short!("fsch_cncl"),
+            schedule_id,
+        );
+        Ok(true)
+    }
+
+    /// Run every due flow schedule, up to `MAX_DUE_FLOWS_PER_CALL` per call.
+    //
+    // Permissionless: runs are authorized by each schedule's spending cap
+    // rather than by the caller. Each run executes the remittance flow (with
+    // compensation) for the schedule owner through the downstream
+    // `add_to_goal_for` / `pay_bill_for` / `pay_premium_for` entrypoints
+    // (and their `*_for` reversals), with this contract as executor; they
+    // accept the call only if the owner registered the orchestrator and owns
+    // the target.
+    //
+    // A successful run advances `next_due`, counting skipped intervals in
+    // `missed_count`, as in
+    // `remittance_split::execute_due_remittance_schedules`. A failed run is
+    // left due so a later call retries it. A run that would exceed the cap
+    // deactivates the schedule instead.
+    //
+    // Returns the ids of the schedules that ran successfully. Returns an empty
+    // list while another flow holds the execution lock.
+    //
+    // # Events
+    // Emits the flow lifecycle events for each run, plus `fsch_exec`
+    // `(schedule_id, amount)`, `fsch_miss` `(schedule_id, missed)`,
+    // `fsch_fail` `(schedule_id, error)` and `fsch_capx` `schedule_id`.
+    pub fn execute_due_flows(env: Env) -> Vec<u32> {
+        Self::extend_instance_ttl(&env);
+        let mut executed = Vec::new(&env);
+        let Ok(routing) = FlowRouting::from_storage(&env) else {
+            return executed;
+        };
+        let Ok(_guard) = Self::acquire_execution_lock(&env) else {
+            return executed;
+        };
+
+        let now = env.ledger().timestamp();
+        let last_id: u32 = env
+            .storage()
+            .instance()
+            .get(&FLOW_SCHEDULE_NEXT)
+            .unwrap_or(0);
+        let mut attempted = 0u32;
+
+        for schedule_id in 1..=last_id {
+            if attempted >= MAX_DUE_FLOWS_PER_CALL {
+                break;
+            }
+            let Some(mut schedule) = Self::load_flow_schedule(&env, schedule_id) else {
+                continue;
+            };
+            if !schedule.active || schedule.next_due > now {
+                continue;
+            }
+            if schedule
+                .last_executed
+                .is_some_and(|last| last >= schedule.next_due)
+            {
+                continue;
+            }
+
+            let within_cap = schedule
+                .spent
+                .checked_add(schedule.amount)
+                .is_some_and(|total| total <= schedule.spending_cap);
+            if !within_cap {
+                schedule.active = false;
+                Self::save_flow_schedule(&env, &schedule);
+                RemitwiseEvents::emit(
+                    &env,
+                    EventCategory::Alert,
+                    EventPriority::Medium,
+                    symbol_short!("fsch_capx"),
+                    schedule_id,
+                );
+                continue;
+            }
+
+            attempted += 1;
+            let mut schedule_routing = routing.clone();
+            schedule_routing.goal_targets = schedule.goal_targets.clone();
+            schedule_routing.bill_targets = schedule.bill_targets.clone();
+            schedule_routing.policy_targets = schedule.policy_targets.clone();
+            schedule_routing.executor = Some(env.current_contract_address());
+
+            Self::emit_flow_started(&env, &schedule.owner, schedule.amount);
+            let result = Self::run_remittance_fan_out(
+                &env,
+                &schedule.owner,
+                schedule.amount,
+                &schedule_routing,
+                true,
+            );
+            if let Err(e) =
+                Self::record_flow_outcome(&env, &schedule.owner, schedule.amount, result)
+            {
+                RemitwiseEvents::emit(
+                    &env,
+                    EventCategory::Alert,
+                    EventPriority::Medium,
+                    symbol_short!("fsch_fail"),
+                    (schedule_id, e as u32),
+                );
+                continue;
+            }
+
+            schedule.spent += schedule.amount;
+            schedule.last_executed = Some(now);
+            if schedule.recurring {
+                let mut missed = 0u32;
+                let mut next = schedule.next_due.saturating_add(schedule.interval);
+                while next <= now {
+                    missed = missed.saturating_add(1);
+                    next = next.saturating_add(schedule.interval);
+                }
+                schedule.missed_count = schedule.missed_count.saturating_add(missed);
+                schedule.next_due = next;
+                if missed > 0 {
+                    RemitwiseEvents::emit(
+                        &env,
+                        EventCategory::State,
+                        EventPriority::Low,
+                        symbol_short!("fsch_miss"),
+                        (schedule_id, missed),
+                    );
+                }
+            } else {
+                schedule.active = false;
+            }
+            Self::save_flow_schedule(&env, &schedule);
+
+            RemitwiseEvents::emit(
+                &env,
+                EventCategory::State,
+                EventPriority::Medium,
+                symbol_short!("fsch_exec"),
+                (schedule_id, schedule.amount),
+            );
+            executed.push_back(schedule_id);
+        }
+
+        executed
+    }
+
+    /// Execute every due schedule across the orchestrator and its downstream
+    /// contracts, and pay `keeper` the configured bounty per executed schedule.
+    //
+    // Runs [`Self::execute_due_flows`] and then the permissionless
+    // `execute_due_*_schedules` entrypoints of remittance split, savings
+    // goals, bill payments and insurance. A downstream executor that fails is
+    // counted as having executed nothing. Bounties are accrued to the keeper's
+    // pending rewards (claimed with `claim_rewards_summary_external`) and
+    // debited from the keeper treasury. The number rewarded is limited by the
+    // treasury balance and by the per-ledger limits in [`KeeperConfig`]; any
+    // schedules beyond those limits are still executed, just not rewarded.
+    // Without a keeper config no bounty is paid.
+    //
+    // # Errors
+    // - `InvalidDependency` if the contract is not initialized
+    // - `ExecutionLocked` if a flow is in progress
+    //
+    // # Events
+    // Emits `kpr_run` with `(keeper, executed, reward)`.
+    pub fn run_keeper(env: Env, keeper: Address) -> Result<KeeperRunResult, OrchestratorError> {
+        keeper.require_auth();
+        Self::extend_instance_ttl(&env);
+        let routing = FlowRouting::from_storage(&env)?;
+        if env.storage().instance().get(&EXEC_LOCK).unwrap_or(false) {
+            return Err(OrchestratorError::ExecutionLocked);
+        }
+
+        let flows = Self::execute_due_flows(env.clone()).len();
+
+        // Hold the lock across the downstream executors so none of them can
+        // re-enter a flow entrypoint mid-run.
+        let _guard = Self::acquire_execution_lock(&env)?;
+        let remittances = interface::RemittanceScheduleClient::new(&env, &routing.remittance_split)
+            .try_execute_due_remittance_schedules()
+            .map_or(0, |ids| ids.map_or(0, |ids| ids.len()));
+        let savings = interface::SavingsScheduleClient::new(&env, &routing.savings)
+            .try_execute_due_savings_schedules()
+            .map_or(0, |ids| ids.map_or(0, |ids| ids.len()));
+        let bills = interface::BillScheduleClient::new(&env, &routing.bills)
+            .try_execute_due_bill_schedules()
+            .map_or(0, |ids| ids.map_or(0, |ids| ids.len()));
+        let premiums = interface::PremiumScheduleClient::new(&env, &routing.insurance)
+            .try_execute_due_premium_schedules()
+            .map_or(0, |ids| ids.map_or(0, |ids| ids.len()));
+
+        let executed = flows
+            .saturating_add(remittances)
+            .saturating_add(savings)
+            .saturating_add(bills)
+            .saturating_add(premiums);
+        let (rewarded, reward) = Self::pay_keeper_bounty(&env, &keeper, executed);
+
+        Self::append_audit(&env, symbol_short!("kpr_run"), &keeper, true);
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Transaction,
+            EventPriority::Low,
+            symbol_short!("kpr_run"),
+            (keeper, executed, reward),
+        );
+
+        Ok(KeeperRunResult {
+            flows,
+            remittances,
+            savings,
+            bills,
+            premiums,
+            rewarded,
+            reward,
+        })
+    }
+
+    /// Set the keeper reward configuration. Owner only.
+    //
+    // # Errors
+    // - `Unauthorized` if `caller` is not the contract owner
+    // - `InvalidKeeperConfig` if `bounty < 0`, `max_rewards_per_keeper == 0`,
+    //   or `max_rewards_per_keeper > max_rewards_per_ledger`
+    // - `KeeperRewardsOutstanding` if `reward_token` changes while the treasury
+    //   or any pending reward balance is non-zero
+    pub fn set_keeper_config(
+        env: Env,
+        caller: Address,
+        config: KeeperConfig,
+    ) -> Result<bool, OrchestratorError> {
+        caller.require_auth();
+
+        let owner: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("OWNER"))
+            .ok_or(OrchestratorError::Unauthorized)?;
+
+        if caller != owner {
+            return Err(OrchestratorError::Unauthorized);
+        }
+
+        if config.bounty < 0
+            || config.max_rewards_per_keeper == 0
+            || config.max_rewards_per_keeper > config.max_rewards_per_ledger
+        {
+            return Err(OrchestratorError::InvalidKeeperConfig);
+        }
+
+        // Treasury and pending balances are denominated in the current token,
+        // so it may only change once both are empty.
+        if let Some(current) = env
+            .storage()
+            .instance()
+            .get::<_, KeeperConfig>(&KEEPER_CONFIG)
+        {
+            if current.reward_token != config.reward_token
+                && (Self::get_keeper_treasury(env.clone()) > 0 || Self::has_pending_rewards(&env))
+            {
+                return Err(OrchestratorError::KeeperRewardsOutstanding);
+            }
+        }
+
+        Self::extend_instance_ttl(&env);
+        env.storage().instance().set(&KEEPER_CONFIG, &config);
+        Self::append_audit(&env, symbol_short!("kpr_cfg"), &caller, true);
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::System,
+            EventPriority::Medium,
+            symbol_short!("kpr_cfg"),
+            (
+                config.bounty,
+                config.max_rewards_per_ledger,
+                config.max_rewards_per_keeper,
+            ),
+        );
+        Ok(true)
+    }
+
+    /// Return the keeper reward configuration, if set.
+    pub fn get_keeper_config(env: Env) -> Option<KeeperConfig> {
+        env.storage().instance().get(&KEEPER_CONFIG)
+    }
+
+    /// Transfer `amount` of the configured reward token from `funder` into the
+    /// keeper treasury. Anyone may fund it.
+    //
+    // Returns the new treasury balance.
+    //
+    // # Errors
+    // - `KeeperNotConfigured` if no keeper config is set
+    // - `InvalidAmount` if `amount <= 0`
+    // - `Overflow` if the treasury balance would overflow
+    //
+    // # Events
+    // Emits `kpr_fund` with `(funder, amount)`.
+    pub fn fund_keeper_treasury(
+        env: Env,
+        funder: Address,
+        amount: i128,
+    ) -> Result<i128, OrchestratorError> {
+        funder.require_auth();
+        let config: KeeperConfig = env
+            .storage()
+            .instance()
+            .get(&KEEPER_CONFIG)
+            .ok_or(OrchestratorError::KeeperNotConfigured)?;
+        if amount <= 0 {
+            return Err(OrchestratorError::InvalidAmount);
+        }
+        let balance = Self::get_keeper_treasury(env.clone())
+            .checked_add(amount)
+            .ok_or(OrchestratorError::Overflow)?;
+
+        Self::extend_instance_ttl(&env);
+        env.storage().instance().set(&KEEPER_TREASURY, &balance);
+        interface::RewardTokenClient::new(&env, &config.reward_token).transfer(
+            &funder,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        Self::append_audit(&env, symbol_short!("kpr_fund"), &funder, true);
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::Transaction,
+            EventPriority::Low,
+            symbol_short!("kpr_fund"),
+            (funder, amount),
+        );
+        Ok(balance)
+    }
+
+    /// Return the keeper treasury balance not yet accrued to any keeper.
+    pub fn get_keeper_treasury(env: Env) -> i128 {
+        env.storage().instance().get(&KEEPER_TREASURY).unwrap_or(0)
+    }
+
+    /// Return a flow schedule by id.
+    pub fn get_flow_schedule(env: Env, schedule_id: u32) -> Option<FlowSchedule> {
+        Self::load_flow_schedule(&env, schedule_id)
+    }
+
+    /// Return every flow schedule `owner` has created, including inactive ones.
+    pub fn get_flow_schedules(env: Env, owner: Address) -> Vec<FlowSchedule> {
+        let ids: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&(OWNER_FLOW_SCHEDULES, owner))
+            .unwrap_or_else(|| Vec::new(&env));
+        let mut schedules = Vec::new(&env);
+        for id in ids.iter() {
+            if let Some(schedule) = Self::load_flow_schedule(&env, id) {
+                schedules.push_back(schedule);
+            }
+        }
+        schedules
+    }
+
+    /// Set the caller's routing profile: the goals, bills and policies their
+    /// remittances fund when routing is resolved from storage.
+    //
+    // Each list carries its own [`AllocationRule`]; use `DueDate` to settle the
+    // oldest bill first. Replaces any existing profile.
+    //
+    // # Errors
+    // - `InvalidTargetList` if any list fails [`TargetList`] validation
+    //
+    // # Events
+    // Emits `("Remitwise", State, Low, "route_set")` with `user`.
+    pub fn set_routing_profile(
+        env: Env,
+        user: Address,
+        goal_targets: TargetList,
+        bill_targets: TargetList,
+        policy_targets: TargetList,
+    ) -> Result<bool, OrchestratorError> {
+        user.require_auth();
+        Self::validate_target_list(&goal_targets)?;
+        Self::validate_target_list(&bill_targets)?;
+        Self::validate_target_list(&policy_targets)?;
+
+        let key = (ROUTING_PROFILES, user.clone());
+        env.storage().persistent().set(
+            &key,
+            &RoutingProfile {
+                goal_targets,
+                bill_targets,
+                policy_targets,
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+
+        Self::append_audit(&env, symbol_short!("route_set"), &user, true);
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::State,
+            EventPriority::Low,
+            symbol_short!("route_set"),
+            user,
+        );
+        Ok(true)
+    }
+
+    /// Remove the caller's routing profile so flows fall back to the
+    /// contract-wide execution IDs. Returns `false` if no profile was set.
+    pub fn clear_routing_profile(env: Env, user: Address) -> bool {
+        user.require_auth();
+        let key = (ROUTING_PROFILES, user.clone());
+        if !env.storage().persistent().has(&key) {
+            return false;
+        }
+        env.storage().persistent().remove(&key);
+        Self::append_audit(&env, symbol_short!("route_clr"), &user, true);
+        RemitwiseEvents::emit(
+            &env,
+            EventCategory::State,
+            EventPriority::Low,
+            symbol_short!("route_clr"),
+            user,
+        );
+        true
+    }
+
+    /// Return `user`'s routing profile, if one has been set.
+    pub fn get_routing_profile(env: Env, user: Address) -> Option<RoutingProfile> {
+        Self::load_routing_profile(&env, &user)
+    }
+
+    /// Get the current execution nonce for an address.
+    pub fn get_nonce(env: Env, address: Address) -> u64 {
+        Self::get_nonce_value(&env, &address)
+    }
+
+    /// Get current execution statistics, including evicted audit entry count.
+    pub fn get_execution_stats(env: Env) -> Option<ExecutionStats> {
+        Self::extend_instance_ttl(&env);
+        env.storage().instance().get(&symbol_short!("STATS"))
+    }
+
+    /// Get the current fee schedule (split percentages) from the remittance split contract.
+    //
+    // Returns a tuple of (spending_percent, savings_percent, bills_percent, insurance_percent)
+    // representing the percentage allocation for each category. The percentages are
+    // in basis points (1% = 100 basis points).
+    //
+    // This is a read-only view function that does not require authorization.
+    // Returns the configured signed-flow deadline window in seconds -- the
+    // maximum distance into the future a caller-supplied deadline may sit
+    // (see `require_nonce_hardened`). Falls back to `MAX_DEADLINE_WINDOW_SECS`
+    // if `init_with_deadline_window` was never called (i.e. plain `init` was
+    // used). No authentication required — observable on-chain.
+    pub fn get_deadline_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("DL_WIN"))
+            .unwrap_or(MAX_DEADLINE_WINDOW_SECS)
+    }
+
+    pub fn get_fee_schedule(env: Env) -> Option<(u32, u32, u32, u32)> {
+        let rs_addr: Address = env.storage().instance().get(&symbol_short!("RS_ADDR"))?;
+
+        let rs_client = interface::RemittanceSplitClient::new(&env, &rs_addr);
+        let split = rs_client.get_split();
+
+        if split.len() != 4 {
+            return None;
+        }
+
+        Some((split.get(0)?, split.get(1)?, split.get(2)?, split.get(3)?))
+    }
+
+    /// Claim accrued rewards and transfer them from the reward-token contract
+    /// to the caller.
+    //
+    // # Threat mitigated — T-RE-02: re-entrant reward drain
+    //
+    // **Attack surface without this guard:**
+    // 1. `caller` invokes `claim_rewards_summary_external`.
+    // 2. Contract reads `pending` balance (e.g. 1 000 tokens) from storage.
+    // 3. Contract calls `token.transfer(this, caller, 1_000)`.
+    // 4. A malicious token contract re-enters `claim_rewards_summary_external`
+    //    before the balance is written to zero.
+    // 5. Step 4 reads `pending` again — still 1 000 — and triggers a second
+    //    transfer, draining twice the entitlement.
+    //
+    // **Fix applied:**
+    // * `EXEC_LOCK` is acquired (via RAII `LockGuard`) **before** any read of
+    //   the pending balance.
+    // * The pending balance is zeroed in storage (**effect written**) *before*
+    //   the external `token.transfer` call (**interaction**), following the
+    //   Checks-Effects-Interactions pattern.
+    // * Any re-entrant call while the lock is held receives
+    //   `OrchestratorError::ReentrancyDetected` immediately, without performing
+    //   any state mutation or token transfer.
+    //
+    // # Authorization
+    // `caller` must authorize the transaction.
+    //
+    // # Parameters
+    // - `caller`       — address whose pending reward balance is claimed.
+    // - `reward_token` — address of the SEP-41-compatible reward token contract;
+    //   must be the `reward_token` of the current [`KeeperConfig`], the token
+    //   every pending balance is accrued in.
+    //
+    // # Returns
+    // The amount transferred (always > 0 on success).
+    //
+    // # Errors
+    // - `NoPendingRewards`   — caller has no accrued rewards.
+    // - `RewardTokenMismatch` — `reward_token` is not the configured token.
+    // - `ReentrancyDetected` — lock already held; re-entrant call rejected.
     pub fn claim_rewards_summary_external(
         env: Env,
         caller: Address,
@@ -847,6 +2241,18 @@ impl Orchestrator {
         // 1. Authorize caller before touching any state.
         caller.require_auth();
 
+        // Pending balances are only ever accrued in the configured reward
+        // token; paying out in any other token would drain unrelated funds.
+        let configured = env
+            .storage()
+            .instance()
+            .get::<_, KeeperConfig>(&KEEPER_CONFIG)
+            .map(|config| config.reward_token);
+        if configured.as_ref() != Some(&reward_token) {
+            Self::append_audit(&env, symbol_short!("clm_rwd"), &caller, false);
+            return Err(OrchestratorError::RewardTokenMismatch);
+        }
+
         // 2. Reentrancy guard — check and acquire lock atomically.
         //    Surfaces a typed error instead of panicking so the condition is
         //    observable by indexers and off-chain dashboards.
@@ -899,7 +2305,6 @@ impl Orchestrator {
     /// Credit pending rewards for an address (called by internal flow logic or
     /// admin seeding).  Exposed as a private helper only; no public entry-point
     /// credits rewards directly to avoid bypassing accrual logic.
-    #[allow(dead_code)]
     fn credit_pending_rewards(env: &Env, recipient: &Address, amount: i128) {
         if amount <= 0 {
             return;
@@ -915,6 +2320,13 @@ impl Orchestrator {
         env.storage().instance().set(&PENDING_REWARDS, &rewards);
     }
 
+    fn has_pending_rewards(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get::<_, Map<Address, i128>>(&PENDING_REWARDS)
+            .is_some_and(|rewards| rewards.values().iter().any(|amount| amount > 0))
+    }
+
     /// Return the pending reward balance for an address without claiming it.
     pub fn get_pending_rewards(env: Env, address: Address) -> i128 {
         let rewards: Option<Map<Address, i128>> = env.storage().instance().get(&PENDING_REWARDS);
@@ -925,21 +2337,21 @@ impl Orchestrator {
     }
 
     /// Get a page of audit log entries.
-    ///
-    /// See [`docs/PAGINATION_HANDBOOK.md`](../../docs/PAGINATION_HANDBOOK.md) for the invariants
-    /// all paginated reads must satisfy, cursor semantics, and the reviewer checklist.
-    ///
-    /// # Parameters
-    /// - `from_index`: zero-based cursor into the current bounded window (oldest = 0)
-    /// - `limit`: entries to return; clamped to `[1, MAX_AUDIT_ENTRIES]`; 0 → default 20
-    ///
-    /// # Retention note
-    /// The log is a ring-buffer capped at `MAX_AUDIT_ENTRIES`. Entries are ordered
-    /// oldest-to-newest within the current window. Callers should treat `from_index`
-    /// as a position in the rotated window, not a global immutable ID.
-    ///
-    /// # Returns
-    /// Empty vec when `from_index` is past the end of the log (safe default).
+    //
+    // See [`docs/PAGINATION_HANDBOOK.md`](../../docs/PAGINATION_HANDBOOK.md) for the invariants
+    // all paginated reads must satisfy, cursor semantics, and the reviewer checklist.
+    //
+    // # Parameters
+    // - `from_index`: zero-based cursor into the current bounded window (oldest = 0)
+    // - `limit`: entries to return; clamped to `[1, MAX_AUDIT_ENTRIES]`; 0 → default 20
+    //
+    // # Retention note
+    // The log is a ring-buffer capped at `MAX_AUDIT_ENTRIES`. Entries are ordered
+    // oldest-to-newest within the current window. Callers should treat `from_index`
+    // as a position in the rotated window, not a global immutable ID.
+    //
+    // # Returns
+    // Empty vec when `from_index` is past the end of the log (safe default).
     pub fn get_audit_log(env: Env, from_index: u32, limit: u32) -> Vec<AuditEntry> {
         let log: Option<Vec<AuditEntry>> = env.storage().instance().get(&symbol_short!("AUDIT"));
         let log = log.unwrap_or_else(|| Vec::new(&env));
@@ -1006,23 +2418,23 @@ impl Orchestrator {
     }
 
     /// Bump the actor epoch to invalidate stale actor tokens.
-    ///
-    /// This is a defence-in-depth mechanism. When called, all actor tokens
-    /// created before the bump will fail the `verify_matching_epoch` check.
-    ///
-    /// # Threat mitigated
-    /// Without this check, an attacker who obtains a stale actor token (e.g.,
-    /// through a compromised signing service) could replay it indefinitely.
-    /// Bumping the epoch forces all actors to obtain fresh tokens.
-    ///
-    /// # Authorization
-    /// Only the contract owner may bump the epoch.
-    ///
-    /// # Errors
-    /// - `Unauthorized` if caller is not the owner
-    ///
-    /// # Events
-    /// Emits `(symbol_short!("orch"), symbol_short!("epoch_bump"))` with (old_epoch, new_epoch).
+    //
+    // This is a defence-in-depth mechanism. When called, all actor tokens
+    // created before the bump will fail the `verify_matching_epoch` check.
+    //
+    // # Threat mitigated
+    // Without this check, an attacker who obtains a stale actor token (e.g.,
+    // through a compromised signing service) could replay it indefinitely.
+    // Bumping the epoch forces all actors to obtain fresh tokens.
+    //
+    // # Authorization
+    // Only the contract owner may bump the epoch.
+    //
+    // # Errors
+    // - `Unauthorized` if caller is not the owner
+    //
+    // # Events
+    // Emits `(symbol_short!("orch"), symbol_short!("epoch_bump"))` with (old_epoch, new_epoch).
     pub fn bump_actor_epoch(env: Env, caller: Address) -> Result<u64, OrchestratorError> {
         caller.require_auth();
 
@@ -1061,19 +2473,19 @@ impl Orchestrator {
     }
 
     /// Capture a pre-upgrade snapshot of critical instance storage.
-    ///
-    /// Call this before performing a contract upgrade. The snapshot captures
-    /// the owner, dependency addresses, execution state, statistics, and
-    /// parameter IDs so the contract can be restored if the upgrade fails.
-    ///
-    /// # Authorization
-    /// Only the contract owner may take a snapshot.
-    ///
-    /// # Errors
-    /// - `Unauthorized` if `caller` is not the contract owner
-    ///
-    /// # Events
-    /// Emits `(symbol_short!("orch"), symbol_short!("snap_pre"))`.
+    //
+    // Call this before performing a contract upgrade. The snapshot captures
+    // the owner, dependency addresses, execution state, statistics, and
+    // parameter IDs so the contract can be restored if the upgrade fails.
+    //
+    // # Authorization
+    // Only the contract owner may take a snapshot.
+    //
+    // # Errors
+    // - `Unauthorized` if `caller` is not the contract owner
+    //
+    // # Events
+    // Emits `(symbol_short!("orch"), symbol_short!("snap_pre"))`.
     pub fn pre_upgrade(env: Env, caller: Address) -> Result<bool, OrchestratorError> {
         caller.require_auth();
         let owner: Address = env
@@ -1177,21 +2589,21 @@ impl Orchestrator {
     }
 
     /// Restore critical instance storage from a pre-upgrade snapshot.
-    ///
-    /// Reads the snapshot stored by `pre_upgrade` and writes the captured
-    /// owner, dependencies, execution state, stats, and parameter IDs back
-    /// to instance storage. The snapshot is consumed after a successful
-    /// restore.
-    ///
-    /// # Authorization
-    /// Only the contract owner may restore from a snapshot.
-    ///
-    /// # Errors
-    /// - `Unauthorized` if `caller` is not the owner
-    /// - `InvalidDependency` if no snapshot exists
-    ///
-    /// # Events
-    /// Emits `(symbol_short!("orch"), symbol_short!("snap_rst"))`.
+    //
+    // Reads the snapshot stored by `pre_upgrade` and writes the captured
+    // owner, dependencies, execution state, stats, and parameter IDs back
+    // to instance storage. The snapshot is consumed after a successful
+    // restore.
+    //
+    // # Authorization
+    // Only the contract owner may restore from a snapshot.
+    //
+    // # Errors
+    // - `Unauthorized` if `caller` is not the owner
+    // - `InvalidDependency` if no snapshot exists
+    //
+    // # Events
+    // Emits `(symbol_short!("orch"), symbol_short!("snap_rst"))`.
     pub fn restore_from_snapshot(env: Env, caller: Address) -> Result<bool, OrchestratorError> {
         caller.require_auth();
         let owner: Address = env
@@ -1372,8 +2784,9 @@ impl Orchestrator {
 
     /// Execute the signed remittance fan-out under `EXEC_LOCK`.
     ///
-    /// Resolves downstream contract addresses and execution parameter IDs from
-    /// instance storage (written at `init`) and delegates to
+    /// Resolves downstream contract addresses from instance storage (written at
+    /// `init`) and execution parameter IDs from the executor's routing profile,
+    /// then delegates to
     /// [`Self::run_remittance_fan_out`] with compensation enabled.
     ///
     /// Call ordering and failure semantics are documented on
@@ -1383,7 +2796,7 @@ impl Orchestrator {
         executor: &Address,
         amount: i128,
     ) -> Result<bool, OrchestratorError> {
-        let routing = FlowRouting::from_storage(env)?;
+        let routing = FlowRouting::for_user(env, executor)?;
         Self::run_remittance_fan_out(env, executor, amount, &routing, true)?;
         Ok(true)
     }
@@ -1394,17 +2807,20 @@ impl Orchestrator {
     /// entrypoints):
     /// 1. `check_spending_limit` on family wallet (read-only)
     /// 2. `calculate_split` on remittance split (read-only)
-    /// 3. `add_to_goal` when savings allocation > 0
-    /// 4. `pay_bill` when bills allocation > 0
-    /// 5. `pay_premium` when insurance allocation > 0
+    /// 3. `add_to_goal` for each goal target with a non-zero allocation
+    /// 4. `pay_bill` for each bill target with a non-zero allocation
+    /// 5. `pay_premium` for each policy target with a non-zero allocation
     ///
     /// Failure semantics:
     /// - Spending limit denial → [`OrchestratorError::Unauthorized`]
     /// - Split vector shorter than 4 or negative allocation →
     ///   [`OrchestratorError::InvalidAmount`]
-    /// - First write failure → [`OrchestratorError::CrossContractCallFailed`]
+    /// - Malformed target list → [`OrchestratorError::InvalidTargetList`]
+    /// - Write failure before any write succeeded →
+    ///   [`OrchestratorError::CrossContractCallFailed`]
     /// - Later write failure with `compensate_on_failure == true` → best-effort
-    ///   reverse calls then [`OrchestratorError::RemittanceFlowRolledBack`]
+    ///   reverse calls for every completed target, then
+    ///   [`OrchestratorError::RemittanceFlowRolledBack`]
     /// - Later write failure with `compensate_on_failure == false` →
     ///   [`OrchestratorError::CrossContractCallFailed`]
     fn run_remittance_fan_out(
@@ -1413,19 +2829,164 @@ impl Orchestrator {
         amount: i128,
         routing: &FlowRouting,
         compensate_on_failure: bool,
+    ) -> Result<(), OrchestratorError> {
+        Self::check_spending_limit(env, caller, amount, routing)?;
+        Self::split_and_fan_out(env, caller, amount, routing, compensate_on_failure)
+    }
+
+    fn check_spending_limit(
+        env: &Env,
+        caller: &Address,
+        amount: i128,
+        routing: &FlowRouting,
     ) -> Result<(), OrchestratorError> {
         let fw_client = interface::FamilyWalletClient::new(env, &routing.family_wallet);
         if !fw_client.check_spending_limit(caller, &amount) {
             return Err(OrchestratorError::Unauthorized);
         }
+        Ok(())
+    }
+
+    /// Swap `amount_in` through the conversion pool and return the amount
+    /// received, re-checking `min_out` rather than trusting the pool.
+    fn convert(
+        env: &Env,
+        caller: &Address,
+        amount_in: i128,
+        conversion: &ConversionParams,
+    ) -> Result<i128, OrchestratorError> {
+        let pool = interface::SwapPoolClient::new(env, &conversion.pool);
+        let amount_out = match pool.try_swap(
+            caller,
+            &conversion.token_in,
+            &conversion.token_out,
+            &amount_in,
+            &conversion.min_out,
+        ) {
+            Ok(Ok(amount_out)) => amount_out,
+            outcome => {
+                Self::emit_cross_contract_failure(
+                    env,
+                    Self::step_symbol(FlowStep::Conversion),
+                    outcome.is_ok(),
+                );
+                return Err(OrchestratorError::CrossContractCallFailed);
+            }
+        };
+        if amount_out < conversion.min_out {
+            return Err(OrchestratorError::SlippageExceeded);
+        }
+
+        RemitwiseEvents::emit(
+            env,
+            EventCategory::Transaction,
+            EventPriority::Medium,
+            symbol_short!("convert"),
+            (
+                conversion.token_in.clone(),
+                conversion.token_out.clone(),
+                amount_in,
+                amount_out,
+            ),
+        );
+        Ok(amount_out)
+    }
+
+    /// Split `amount` and pay every target, compensating completed targets
+    /// on failure when `compensate_on_failure` is set.
+    fn split_and_fan_out(
+        env: &Env,
+        caller: &Address,
+        amount: i128,
+        routing: &FlowRouting,
+        compensate_on_failure: bool,
+    ) -> Result<(), OrchestratorError> {
+        let mut plan = Self::plan_fan_out(env, amount, routing)?;
+
+        for i in 0..plan.len() {
+            let Some(mut target) = plan.get(i) else {
+                continue;
+            };
+            if target.amount > 0 {
+                if let Err(rejected_by_contract) = Self::execute_target(
+                    env,
+                    caller,
+                    routing,
+                    target.step,
+                    target.target_id,
+                    target.amount,
+                ) {
+                    Self::emit_cross_contract_failure(
+                        env,
+                        Self::step_symbol(target.step),
+                        rejected_by_contract,
+                    );
+                    if compensate_on_failure
+                        && Self::compensate_completed(env, caller, routing, &plan)
+                    {
+                        return Err(OrchestratorError::RemittanceFlowRolledBack);
+                    }
+                    return Err(OrchestratorError::CrossContractCallFailed);
+                }
+            }
+            target.succeeded = true;
+            plan.set(i, target);
+        }
+
+        Self::record_distribution(
+            env,
+            caller,
+            amount,
+            amount - Self::planned_total(&plan),
+            &plan,
+        );
+        Ok(())
+    }
 
+    /// Fetch the split once and expand each step's allocation over its target
+    /// list. Every returned entry has `succeeded == false`.
+    fn plan_fan_out(
+        env: &Env,
+        amount: i128,
+        routing: &FlowRouting,
+    ) -> Result<Vec<FanOutTargetResult>, OrchestratorError> {
         let rs_client = interface::RemittanceSplitClient::new(env, &routing.remittance_split);
         let allocations = rs_client.calculate_split(&amount);
+        let [_, savings_amt, bills_amt, insurance_amt] = Self::split_allocations(&allocations)?;
+
+        let mut plan = Vec::new(env);
+        Self::allocate_targets(
+            env,
+            FlowStep::SavingsGoal,
+            savings_amt,
+            &routing.goal_targets,
+            &mut plan,
+        )?;
+        Self::allocate_targets(
+            env,
+            FlowStep::BillPayment,
+            bills_amt,
+            &routing.bill_targets,
+            &mut plan,
+        )?;
+        Self::allocate_targets(
+            env,
+            FlowStep::InsurancePremium,
+            insurance_amt,
+            &routing.policy_targets,
+            &mut plan,
+        )?;
+        Ok(plan)
+    }
 
+    /// Validate a `calculate_split` result and return its spending, savings,
+    /// bills and insurance allocations.
+    fn split_allocations(allocations: &Vec<i128>) -> Result<[i128; 4], OrchestratorError> {
         if allocations.len() < 4 {
             return Err(OrchestratorError::InvalidAmount);
         }
 
+        let spending_amt = allocations.get(0).ok_or(OrchestratorError::InvalidAmount)?;
         let savings_amt = allocations.get(1).ok_or(OrchestratorError::InvalidAmount)?;
         let bills_amt = allocations.get(2).ok_or(OrchestratorError::InvalidAmount)?;
         let insurance_amt = allocations.get(3).ok_or(OrchestratorError::InvalidAmount)?;
@@ -1433,76 +2994,284 @@ impl Orchestrator {
         if savings_amt < 0 || bills_amt < 0 || insurance_amt < 0 {
             return Err(OrchestratorError::InvalidAmount);
         }
+        Ok([spending_amt, savings_amt, bills_amt, insurance_amt])
+    }
+
+    /// Shared dry run behind the `preview_*` entrypoints.
+    ///
+    /// `remittance_checks` adds the lock and spending-limit checks that
+    /// `execute_remittance_flow` makes but `execute_flow_fanout` does not.
+    /// Validation failures that stop a flow before any step runs leave
+    /// `steps` empty.
+    fn preview_flow(
+        env: &Env,
+        caller: &Address,
+        amount: i128,
+        routing: Result<FlowRouting, OrchestratorError>,
+        remittance_checks: bool,
+    ) -> FlowPreview {
+        let mut preview = FlowPreview {
+            steps: Vec::new(env),
+            targets: Vec::new(env),
+            spending_amount: 0,
+            error: 0,
+            would_succeed: false,
+        };
 
-        let mut savings_done = false;
-        let mut bills_done = false;
+        let routing = if amount <= 0 {
+            Err(OrchestratorError::InvalidAmount)
+        } else if remittance_checks && env.storage().instance().get(&EXEC_LOCK).unwrap_or(false) {
+            Err(OrchestratorError::ExecutionLocked)
+        } else {
+            routing
+        };
+        let routing = match routing {
+            Ok(routing) => routing,
+            Err(e) => {
+                preview.error = e as u32;
+                return preview;
+            }
+        };
 
-        if savings_amt > 0 {
-            let s_client = interface::SavingsGoalsClient::new(env, &routing.savings);
-            match s_client.try_add_to_goal(caller, &routing.goal_id, &savings_amt) {
-                Ok(Ok(_)) => savings_done = true,
-                Ok(Err(_)) => {
-                    Self::emit_cross_contract_failure(env, symbol_short!("savings"), true);
-                    return Err(OrchestratorError::CrossContractCallFailed);
-                }
-                Err(_) => {
-                    Self::emit_cross_contract_failure(env, symbol_short!("savings"), false);
-                    return Err(OrchestratorError::CrossContractCallFailed);
-                }
+        if remittance_checks {
+            let fw_client = interface::FamilyWalletClient::new(env, &routing.family_wallet);
+            let outcome = match fw_client.try_check_spending_limit(caller, &amount) {
+                Ok(Ok(true)) => Ok(()),
+                Ok(Ok(false)) => Err(OrchestratorError::Unauthorized),
+                _ => Err(OrchestratorError::CrossContractCallFailed),
+            };
+            Self::record_preview_step(&mut preview, FlowStep::SpendingCheck, amount, outcome);
+        }
+
+        let rs_client = interface::RemittanceSplitClient::new(env, &routing.remittance_split);
+        let allocations = match rs_client.try_calculate_split(&amount) {
+            Ok(Ok(allocations)) => Self::split_allocations(&allocations),
+            _ => Err(OrchestratorError::CrossContractCallFailed),
+        };
+        let [spending_amt, savings_amt, bills_amt, insurance_amt] = match allocations {
+            Ok(allocations) => {
+                Self::record_preview_step(&mut preview, FlowStep::SplitCalculation, amount, Ok(()));
+                allocations
+            }
+            Err(e) => {
+                Self::record_preview_step(&mut preview, FlowStep::SplitCalculation, amount, Err(e));
+                return preview;
+            }
+        };
+        preview.spending_amount = spending_amt;
+
+        for (step, step_amount, list) in [
+            (FlowStep::SavingsGoal, savings_amt, &routing.goal_targets),
+            (FlowStep::BillPayment, bills_amt, &routing.bill_targets),
+            (
+                FlowStep::InsurancePremium,
+                insurance_amt,
+                &routing.policy_targets,
+            ),
+        ] {
+            let mut planned = Vec::new(env);
+            let outcome = Self::allocate_targets(env, step, step_amount, list, &mut planned);
+            for mut target in planned.iter() {
+                target.succeeded = true;
+                preview.targets.push_back(target);
             }
+            Self::record_preview_step(&mut preview, step, step_amount, outcome);
+        }
+
+        preview.would_succeed = preview.error == 0;
+        preview
+    }
+
+    fn record_preview_step(
+        preview: &mut FlowPreview,
+        step: FlowStep,
+        amount: i128,
+        outcome: Result<(), OrchestratorError>,
+    ) {
+        let error = outcome.err().map_or(0, |e| e as u32);
+        if preview.error == 0 {
+            preview.error = error;
         }
+        preview.steps.push_back(FlowStepPreview {
+            step,
+            amount,
+            error,
+        });
+    }
 
-        if bills_amt > 0 {
-            let b_client = interface::BillPaymentsClient::new(env, &routing.bills);
-            let rejected_by_contract = match b_client.try_pay_bill(caller, &routing.bill_id, &bills_amt) {
-                Ok(Ok(_)) => {
-                    bills_done = true;
-                    None
+    /// Divide `amount` across `list` according to its rule and append one
+    /// entry per target to `plan`, in fill order.
+    fn allocate_targets(
+        env: &Env,
+        step: FlowStep,
+        amount: i128,
+        list: &TargetList,
+        plan: &mut Vec<FanOutTargetResult>,
+    ) -> Result<(), OrchestratorError> {
+        Self::validate_target_list(list)?;
+
+        let ordered = match list.rule {
+            AllocationRule::DueDate => Self::sort_by_due_date(env, &list.targets),
+            AllocationRule::ProRata | AllocationRule::Priority => list.targets.clone(),
+        };
+
+        let mut shares: Vec<i128> = Vec::new(env);
+        match list.rule {
+            AllocationRule::ProRata => {
+                let total_weight: i128 = ordered.iter().map(|t| i128::from(t.weight)).sum();
+                let mut assigned: i128 = 0;
+                for target in ordered.iter() {
+                    let share = amount
+                        .checked_mul(i128::from(target.weight))
+                        .ok_or(OrchestratorError::Overflow)?
+                        / total_weight;
+                    assigned += share;
+                    shares.push_back(share);
                 }
-                Ok(Err(_)) => Some(true),
-                Err(_) => Some(false),
-            };
-            if let Some(from_contract) = rejected_by_contract {
-                Self::emit_cross_contract_failure(env, symbol_short!("bills"), from_contract);
-                if compensate_on_failure {
-                    Self::compensate_savings(
-                        env,
-                        caller,
-                        routing.goal_id,
-                        savings_amt,
-                        savings_done,
-                    );
-                    return Err(OrchestratorError::RemittanceFlowRolledBack);
+                let first = shares.get(0).ok_or(OrchestratorError::InvalidTargetList)?;
+                shares.set(0, first + (amount - assigned));
+            }
+            AllocationRule::Priority | AllocationRule::DueDate => {
+                let mut remaining = amount;
+                for target in ordered.iter() {
+                    let share = if target.cap > 0 {
+                        remaining.min(target.cap)
+                    } else {
+                        remaining
+                    };
+                    remaining -= share;
+                    shares.push_back(share);
                 }
-                return Err(OrchestratorError::CrossContractCallFailed);
+                let last_index = shares.len() - 1;
+                let last = shares
+                    .get(last_index)
+                    .ok_or(OrchestratorError::InvalidTargetList)?;
+                shares.set(last_index, last + remaining);
             }
         }
 
-        if insurance_amt > 0 {
-            let i_client = interface::InsuranceClient::new(env, &routing.insurance);
-            let rejected_by_contract = match i_client.try_pay_premium(caller, &routing.policy_id, &insurance_amt) {
-                Ok(Ok(_)) => None,
-                Ok(Err(_)) => Some(true),
-                Err(_) => Some(false),
-            };
-            if let Some(from_contract) = rejected_by_contract {
-                Self::emit_cross_contract_failure(env, symbol_short!("insur"), from_contract);
-                if compensate_on_failure {
-                    Self::compensate_savings(
-                        env,
-                        caller,
-                        routing.goal_id,
-                        savings_amt,
-                        savings_done,
-                    );
-                    Self::compensate_bill(env, caller, routing.bill_id, bills_amt, bills_done);
-                    return Err(OrchestratorError::RemittanceFlowRolledBack);
+        for (target, share) in ordered.iter().zip(shares.iter()) {
+            plan.push_back(FanOutTargetResult {
+                step,
+                target_id: target.id,
+                amount: share,
+                succeeded: false,
+            });
+        }
+        Ok(())
+    }
+
+    /// Stable insertion sort of `targets` by ascending `due_date`.
+    fn sort_by_due_date(env: &Env, targets: &Vec<FlowTarget>) -> Vec<FlowTarget> {
+        let mut sorted: Vec<FlowTarget> = Vec::new(env);
+        for target in targets.iter() {
+            let mut pos = sorted.len();
+            while pos > 0 {
+                match sorted.get(pos - 1) {
+                    Some(prev) if prev.due_date > target.due_date => pos -= 1,
+                    _ => break,
                 }
-                return Err(OrchestratorError::CrossContractCallFailed);
             }
+            sorted.insert(pos, target);
         }
+        sorted
+    }
 
-        Ok(())
+    /// Issue the downstream write for one planned target.
+    ///
+    /// On failure returns whether the callee itself rejected the call (see
+    /// [`Self::emit_cross_contract_failure`]).
+    fn execute_target(
+        env: &Env,
+        caller: &Address,
+        routing: &FlowRouting,
+        step: FlowStep,
+        target_id: u32,
+        amount: i128,
+    ) -> Result<(), bool> {
+        if let Some(executor) = &routing.executor {
+            return Self::execute_target_for(
+                env, executor, caller, routing, step, target_id, amount,
+            );
+        }
+        let outcome = match step {
+            FlowStep::SavingsGoal => interface::SavingsGoalsClient::new(env, &routing.savings)
+                .try_add_to_goal(caller, &target_id, &amount)
+                .map(|r| r.is_ok()),
+            FlowStep::BillPayment => interface::BillPaymentsClient::new(env, &routing.bills)
+                .try_pay_bill(caller, &target_id, &amount)
+                .map(|r| r.is_ok()),
+            FlowStep::InsurancePremium => interface::InsuranceClient::new(env, &routing.insurance)
+                .try_pay_premium(caller, &target_id, &amount)
+                .map(|r| r.is_ok()),
+            FlowStep::SpendingCheck | FlowStep::SplitCalculation | FlowStep::Conversion => Ok(true),
+        };
+        match outcome {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(true),
+            Err(_) => Err(false),
+        }
+    }
+
+    /// [`Self::execute_target`] through the delegated `*_for` entrypoints,
+    /// with `executor` acting for `owner`.
+    fn execute_target_for(
+        env: &Env,
+        executor: &Address,
+        owner: &Address,
+        routing: &FlowRouting,
+        step: FlowStep,
+        target_id: u32,
+        amount: i128,
+    ) -> Result<(), bool> {
+        let outcome = match step {
+            FlowStep::SavingsGoal => {
+                interface::SavingsGoalsExecutorClient::new(env, &routing.savings)
+                    .try_add_to_goal_for(executor, owner, &target_id, &amount)
+                    .map(|r| r.is_ok())
+            }
+            FlowStep::BillPayment => {
+                interface::BillPaymentsExecutorClient::new(env, &routing.bills)
+                    .try_pay_bill_for(executor, owner, &target_id, &amount)
+                    .map(|r| r.is_ok())
+            }
+            FlowStep::InsurancePremium => {
+                interface::InsuranceExecutorClient::new(env, &routing.insurance)
+                    .try_pay_premium_for(executor, owner, &target_id, &amount)
+                    .map(|r| r.is_ok())
+            }
+            FlowStep::SpendingCheck | FlowStep::SplitCalculation | FlowStep::Conversion => Ok(true),
+        };
+        match outcome {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(true),
+            Err(_) => Err(false),
+        }
+    }
+
+    /// Collapse the per-target results of `step` into one step summary.
+    fn summarize_step(targets: &Vec<FanOutTargetResult>, step: FlowStep) -> FanOutStepResult {
+        let mut summary = FanOutStepResult {
+            step,
+            succeeded: true,
+            amount: 0,
+        };
+        for target in targets.iter().filter(|t| t.step == step) {
+            summary.amount = summary.amount.saturating_add(target.amount);
+            summary.succeeded &= target.succeeded;
+        }
+        summary
+    }
+
+    fn step_symbol(step: FlowStep) -> Symbol {
+        match step {
+            FlowStep::SpendingCheck => symbol_short!("spending"),
+            FlowStep::SplitCalculation => symbol_short!("split"),
+            FlowStep::SavingsGoal => symbol_short!("savings"),
+            FlowStep::BillPayment => symbol_short!("bills"),
+            FlowStep::InsurancePremium => symbol_short!("insur"),
+            FlowStep::Conversion => symbol_short!("convert"),
+        }
     }
 
     /// Surfaces the inner failure that `run_remittance_fan_out` would otherwise
@@ -1515,42 +3284,454 @@ impl Orchestrator {
     /// interface). Both were previously indistinguishable from the caller's
     /// perspective.
     fn emit_cross_contract_failure(env: &Env, step: Symbol, rejected_by_contract: bool) {
-        env.events().publish(
-            (symbol_short!("cctx_err"), step),
-            rejected_by_contract,
-        );
+        env.events()
+            .publish((symbol_short!("cctx_err"), step), rejected_by_contract);
     }
 
-    /// Compensate a savings-goal contribution if it was applied.
-    fn compensate_savings(
+    /// Best-effort reversal of every completed target in `plan`, newest first.
+    ///
+    /// Returns `false` when nothing had been applied, so the caller can report
+    /// a plain failure instead of a rollback.
+    fn compensate_completed(
         env: &Env,
-        executor: &Address,
-        goal_id: u32,
+        caller: &Address,
+        routing: &FlowRouting,
+        plan: &Vec<FanOutTargetResult>,
+    ) -> bool {
+        let mut compensated = false;
+        for i in (0..plan.len()).rev() {
+            if let Some(target) = plan.get(i) {
+                if target.succeeded && target.amount > 0 {
+                    Self::compensate_target(
+                        env,
+                        caller,
+                        routing,
+                        target.step,
+                        target.target_id,
+                        target.amount,
+                    );
+                    compensated = true;
+                }
+            }
+        }
+        compensated
+    }
+
+    /// Reverse one applied target through the step's compensation interface.
+    /// Returns whether the reverse call succeeded.
+    fn compensate_target(
+        env: &Env,
+        caller: &Address,
+        routing: &FlowRouting,
+        step: FlowStep,
+        target_id: u32,
         amount: i128,
-        applied: bool,
-    ) {
-        if !applied || amount <= 0 {
-            return;
+    ) -> bool {
+        if let Some(executor) = &routing.executor {
+            return match step {
+                FlowStep::SavingsGoal => {
+                    interface::SavingsGoalsExecutorCompClient::new(env, &routing.savings)
+                        .try_remove_from_goal_for(executor, caller, &target_id, &amount)
+                        .is_ok_and(|r| r.is_ok())
+                }
+                FlowStep::BillPayment => {
+                    interface::BillPaymentsExecutorCompClient::new(env, &routing.bills)
+                        .try_reverse_payment_for(executor, caller, &target_id, &amount)
+                        .is_ok_and(|r| r.is_ok())
+                }
+                FlowStep::InsurancePremium => {
+                    interface::InsuranceExecutorCompClient::new(env, &routing.insurance)
+                        .try_reverse_premium_for(executor, caller, &target_id, &amount)
+                        .is_ok_and(|r| r.is_ok())
+                }
+                FlowStep::SpendingCheck | FlowStep::SplitCalculation | FlowStep::Conversion => true,
+            };
+        }
+        match step {
+            FlowStep::SavingsGoal => interface::SavingsGoalsCompClient::new(env, &routing.savings)
+                .try_remove_from_goal(caller, &target_id, &amount)
+                .is_ok_and(|r| r.is_ok()),
+            FlowStep::BillPayment => interface::BillPaymentsCompClient::new(env, &routing.bills)
+                .try_reverse_payment(caller, &target_id, &amount)
+                .is_ok_and(|r| r.is_ok()),
+            FlowStep::InsurancePremium => {
+                interface::InsuranceCompClient::new(env, &routing.insurance)
+                    .try_reverse_premium(caller, &target_id, &amount)
+                    .is_ok_and(|r| r.is_ok())
+            }
+            FlowStep::SpendingCheck | FlowStep::SplitCalculation | FlowStep::Conversion => true,
+        }
+    }
+
+    /// Run the read-only steps of a resumable flow, plan its targets, attempt
+    /// them all and persist the resulting record under a new id.
+    fn begin_flow(
+        env: &Env,
+        params: &RemittanceFlowParams,
+    ) -> Result<FlowRecord, OrchestratorError> {
+        let routing = FlowRouting::from_params(params);
+        let fw_client = interface::FamilyWalletClient::new(env, &routing.family_wallet);
+        if !fw_client.check_spending_limit(&params.caller, &params.total_amount) {
+            return Err(OrchestratorError::Unauthorized);
+        }
+        let plan = Self::plan_fan_out(env, params.total_amount, &routing)?;
+
+        let mut steps = Vec::new(env);
+        for step in [FlowStep::SpendingCheck, FlowStep::SplitCalculation] {
+            steps.push_back(FlowStepRecord {
+                step,
+                target_id: 0,
+                amount: params.total_amount,
+                status: StepStatus::Completed,
+                attempts: 1,
+            });
+        }
+        for target in plan.iter() {
+            steps.push_back(FlowStepRecord {
+                step: target.step,
+                target_id: target.target_id,
+                amount: target.amount,
+                status: StepStatus::Pending,
+                attempts: 0,
+            });
         }
-        let sg_addr = match env.storage().instance().get(&symbol_short!("SG_ADDR")) {
-            Some(a) => a,
-            None => return,
+
+        let id = env
+            .storage()
+            .instance()
+            .get::<_, u64>(&FLOW_NEXT)
+            .unwrap_or(0)
+            .checked_add(1)
+            .ok_or(OrchestratorError::Overflow)?;
+        env.storage().instance().set(&FLOW_NEXT, &id);
+
+        let now = env.ledger().timestamp();
+        let mut record = FlowRecord {
+            id,
+            params: params.clone(),
+            status: FlowStatus::PartiallyCompleted,
+            steps,
+            created_at: now,
+            updated_at: now,
         };
-        let client = interface::SavingsGoalsCompClient::new(env, &sg_addr);
-        let _ = client.try_remove_from_goal(executor, &goal_id, &amount);
+        let completed = Self::run_outstanding_steps(env, &mut record, &routing);
+        Self::record_distribution(
+            env,
+            &params.caller,
+            params.total_amount,
+            params.total_amount - Self::planned_total(&plan),
+            &completed,
+        );
+        Self::save_flow(env, &record);
+        Ok(record)
     }
 
-    /// Compensate a bill payment if it was applied.
-    fn compensate_bill(env: &Env, executor: &Address, bill_id: u32, amount: i128, applied: bool) {
-        if !applied || amount <= 0 {
-            return;
+    /// Attempt every `Pending` or `Failed` step of `record`, then refresh its
+    /// overall status. Returns the targets completed by this call.
+    fn run_outstanding_steps(
+        env: &Env,
+        record: &mut FlowRecord,
+        routing: &FlowRouting,
+    ) -> Vec<FanOutTargetResult> {
+        let caller = record.params.caller.clone();
+        let mut completed = Vec::new(env);
+        let mut all_completed = true;
+        for i in 0..record.steps.len() {
+            let Some(mut step) = record.steps.get(i) else {
+                continue;
+            };
+            if step.status == StepStatus::Pending || step.status == StepStatus::Failed {
+                step.attempts = step.attempts.saturating_add(1);
+                step.status = if step.amount == 0 {
+                    StepStatus::Completed
+                } else {
+                    match Self::execute_target(
+                        env,
+                        &caller,
+                        routing,
+                        step.step,
+                        step.target_id,
+                        step.amount,
+                    ) {
+                        Ok(()) => StepStatus::Completed,
+                        Err(rejected_by_contract) => {
+                            Self::emit_cross_contract_failure(
+                                env,
+                                Self::step_symbol(step.step),
+                                rejected_by_contract,
+                            );
+                            StepStatus::Failed
+                        }
+                    }
+                };
+                record.steps.set(i, step.clone());
+                if step.status == StepStatus::Completed {
+                    completed.push_back(FanOutTargetResult {
+                        step: step.step,
+                        target_id: step.target_id,
+                        amount: step.amount,
+                        succeeded: true,
+                    });
+                }
+            }
+            all_completed &= step.status == StepStatus::Completed;
+        }
+        record.status = if all_completed {
+            FlowStatus::Completed
+        } else {
+            FlowStatus::PartiallyCompleted
+        };
+        record.updated_at = env.ledger().timestamp();
+        completed
+    }
+
+    /// Sum of the amounts planned for every target, succeeded or not.
+    fn planned_total(targets: &Vec<FanOutTargetResult>) -> i128 {
+        targets
+            .iter()
+            .fold(0i128, |total, t| total.saturating_add(t.amount))
+    }
+
+    /// Add a distribution to `owner`'s history for the current month.
+    ///
+    /// `received` and `spending` are added as given; of `targets`, only those
+    /// that `succeeded` are counted. A non-zero `received` counts as one flow.
+    fn record_distribution(
+        env: &Env,
+        owner: &Address,
+        received: i128,
+        spending: i128,
+        targets: &Vec<FanOutTargetResult>,
+    ) {
+        let period_key = Timestamp::to_period_key(env.ledger().timestamp(), PeriodKind::Month);
+        let key = (REMITTANCE_HISTORY, owner.clone(), period_key);
+        let mut period = match env.storage().persistent().get::<_, RemittancePeriod>(&key) {
+            Some(period) => period,
+            None => {
+                Self::index_history_period(env, owner, period_key);
+                RemittancePeriod {
+                    period_key,
+                    received: 0,
+                    spending: 0,
+                    savings: 0,
+                    bills: 0,
+                    insurance: 0,
+                    flow_count: 0,
+                }
+            }
+        };
+
+        period.received = period.received.saturating_add(received);
+        period.spending = period.spending.saturating_add(spending);
+        if received != 0 {
+            period.flow_count = period.flow_count.saturating_add(1);
+        }
+        for target in targets.iter().filter(|t| t.succeeded) {
+            let total = match target.step {
+                FlowStep::SavingsGoal => &mut period.savings,
+                FlowStep::BillPayment => &mut period.bills,
+                FlowStep::InsurancePremium => &mut period.insurance,
+                FlowStep::SpendingCheck | FlowStep::SplitCalculation | FlowStep::Conversion => {
+                    continue
+                }
+            };
+            *total = total.saturating_add(target.amount);
+        }
+
+        env.storage().persistent().set(&key, &period);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Append `period_key` to `owner`'s history index, dropping the oldest
+    /// month beyond `MAX_REMITTANCE_HISTORY_PERIODS`.
+    fn index_history_period(env: &Env, owner: &Address, period_key: u64) {
+        let index_key = (REMITTANCE_HISTORY_INDEX, owner.clone());
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&index_key)
+            .unwrap_or_else(|| Vec::new(env));
+        index.push_back(period_key);
+        if index.len() > MAX_REMITTANCE_HISTORY_PERIODS {
+            if let Some(oldest) = index.pop_front() {
+                env.storage()
+                    .persistent()
+                    .remove(&(REMITTANCE_HISTORY, owner.clone(), oldest));
+            }
+        }
+        env.storage().persistent().set(&index_key, &index);
+        env.storage().persistent().extend_ttl(
+            &index_key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Load a flow that `caller` may resume or abort.
+    fn load_open_flow(
+        env: &Env,
+        caller: &Address,
+        flow_id: u64,
+    ) -> Result<FlowRecord, OrchestratorError> {
+        let record: FlowRecord = env
+            .storage()
+            .persistent()
+            .get(&(FLOW_RECORD, flow_id))
+            .ok_or(OrchestratorError::FlowNotFound)?;
+        if record.params.caller != *caller {
+            return Err(OrchestratorError::Unauthorized);
+        }
+        if record.status != FlowStatus::PartiallyCompleted {
+            return Err(OrchestratorError::InvalidFlowState);
         }
-        let bp_addr = match env.storage().instance().get(&symbol_short!("BP_ADDR")) {
-            Some(a) => a,
-            None => return,
+        Ok(record)
+    }
+
+    fn save_flow(env: &Env, record: &FlowRecord) {
+        let key = (FLOW_RECORD, record.id);
+        env.storage().persistent().set(&key, record);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Accrue the bounty for up to `executed` schedules to `keeper`, limited by
+    /// the treasury balance and the per-ledger limits. Returns the number of
+    /// schedules rewarded and the total credited.
+    fn pay_keeper_bounty(env: &Env, keeper: &Address, executed: u32) -> (u32, i128) {
+        let Some(config) = env
+            .storage()
+            .instance()
+            .get::<_, KeeperConfig>(&KEEPER_CONFIG)
+        else {
+            return (0, 0);
         };
-        let client = interface::BillPaymentsCompClient::new(env, &bp_addr);
-        let _ = client.try_reverse_payment(executor, &bill_id, &amount);
+        if executed == 0 || config.bounty == 0 {
+            return (0, 0);
+        }
+
+        let sequence = env.ledger().sequence();
+        let mut usage: KeeperLedgerUsage = env
+            .storage()
+            .instance()
+            .get(&KEEPER_USAGE)
+            .filter(|usage: &KeeperLedgerUsage| usage.sequence == sequence)
+            .unwrap_or_else(|| KeeperLedgerUsage {
+                sequence,
+                rewarded: 0,
+                per_keeper: Map::new(env),
+            });
+        let keeper_rewarded = usage.per_keeper.get(keeper.clone()).unwrap_or(0);
+        let treasury: i128 = env.storage().instance().get(&KEEPER_TREASURY).unwrap_or(0);
+        let affordable = u32::try_from(treasury / config.bounty).unwrap_or(u32::MAX);
+
+        let rewarded = executed
+            .min(config.max_rewards_per_ledger.saturating_sub(usage.rewarded))
+            .min(
+                config
+                    .max_rewards_per_keeper
+                    .saturating_sub(keeper_rewarded),
+            )
+            .min(affordable);
+        if rewarded == 0 {
+            return (0, 0);
+        }
+
+        // rewarded <= treasury / bounty, so this cannot exceed the treasury.
+        let reward = config.bounty * i128::from(rewarded);
+        usage.rewarded += rewarded;
+        usage
+            .per_keeper
+            .set(keeper.clone(), keeper_rewarded + rewarded);
+        env.storage().instance().set(&KEEPER_USAGE, &usage);
+        env.storage()
+            .instance()
+            .set(&KEEPER_TREASURY, &(treasury - reward));
+        Self::credit_pending_rewards(env, keeper, reward);
+        (rewarded, reward)
+    }
+
+    fn load_flow_schedule(env: &Env, schedule_id: u32) -> Option<FlowSchedule> {
+        env.storage()
+            .persistent()
+            .get(&(FLOW_SCHEDULE, schedule_id))
+    }
+
+    fn load_owned_flow_schedule(
+        env: &Env,
+        owner: &Address,
+        schedule_id: u32,
+    ) -> Result<FlowSchedule, OrchestratorError> {
+        match Self::load_flow_schedule(env, schedule_id) {
+            Some(schedule) if schedule.owner == *owner => Ok(schedule),
+            _ => Err(OrchestratorError::ScheduleNotFound),
+        }
+    }
+
+    fn save_flow_schedule(env: &Env, schedule: &FlowSchedule) {
+        let key = (FLOW_SCHEDULE, schedule.id);
+        env.storage().persistent().set(&key, schedule);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    fn is_write_step(step: FlowStep) -> bool {
+        matches!(
+            step,
+            FlowStep::SavingsGoal | FlowStep::BillPayment | FlowStep::InsurancePremium
+        )
+    }
+
+    fn load_routing_profile(env: &Env, user: &Address) -> Option<RoutingProfile> {
+        env.storage()
+            .persistent()
+            .get(&(ROUTING_PROFILES, user.clone()))
+    }
+
+    /// Target lists must be non-empty, bounded, free of duplicate ids and
+    /// negative caps, and carry some weight when split `ProRata`.
+    fn validate_target_list(list: &TargetList) -> Result<(), OrchestratorError> {
+        let targets = &list.targets;
+        if targets.is_empty() || targets.len() > MAX_FLOW_TARGETS {
+            return Err(OrchestratorError::InvalidTargetList);
+        }
+        let mut total_weight: u64 = 0;
+        for i in 0..targets.len() {
+            let Some(target) = targets.get(i) else {
+                return Err(OrchestratorError::InvalidTargetList);
+            };
+            if target.cap < 0 {
+                return Err(OrchestratorError::InvalidTargetList);
+            }
+            total_weight += u64::from(target.weight);
+            for j in (i + 1)..targets.len() {
+                if targets.get(j).map(|other| other.id) == Some(target.id) {
+                    return Err(OrchestratorError::InvalidTargetList);
+                }
+            }
+        }
+        if list.rule == AllocationRule::ProRata && total_weight == 0 {
+            return Err(OrchestratorError::InvalidTargetList);
+        }
+        Ok(())
+    }
+
+    /// Bind a whole target list (rule, ids, weights and caps) into one slot
+    /// of the request hash: the leading 8 bytes of `sha256(list.to_xdr())`.
+    fn target_binding(env: &Env, list: &TargetList) -> u64 {
+        let digest = env.crypto().sha256(&list.clone().to_xdr(env)).to_array();
+        let mut lead = [0u8; 8];
+        lead.copy_from_slice(&digest[..8]);
+        u64::from_be_bytes(lead)
     }
 
     fn get_nonce_value(env: &Env, address: &Address) -> u64 {
@@ -1747,17 +3928,17 @@ impl Orchestrator {
 
     /// Deterministic request hash for signed remittance authorizations.
     ///
-    /// Binds `operation`, `nonce`, `amount`, `deadline`, and the execution
-    /// parameter IDs (`goal_id`, `bill_id`, `policy_id`) read from instance
-    /// storage at validation time.
+    /// Binds `operation`, `nonce`, `amount`, `deadline`, and the goal, bill
+    /// and policy target lists resolved at validation time, each reduced to
+    /// one slot by `target_binding`.
     fn compute_request_hash(
         operation: Symbol,
         nonce: u64,
         amount: i128,
         deadline: u64,
-        goal_id: u32,
-        bill_id: u32,
-        policy_id: u32,
+        goal_binding: u64,
+        bill_binding: u64,
+        policy_binding: u64,
     ) -> u64 {
         let op_bits: u64 = operation.to_val().get_payload();
         let amt_lo = amount as u64;
@@ -1768,9 +3949,9 @@ impl Orchestrator {
             .wrapping_add(amt_lo)
             .wrapping_add(amt_hi)
             .wrapping_add(deadline)
-            .wrapping_add(u64::from(goal_id))
-            .wrapping_add(u64::from(bill_id))
-            .wrapping_add(u64::from(policy_id))
+            .wrapping_add(goal_binding)
+            .wrapping_add(bill_binding)
+            .wrapping_add(policy_binding)
             .wrapping_mul(1_000_000_007)
     }
 
@@ -1912,8 +4093,17 @@ mod tests_nonce_eviction {
         BASE_TIME + MAX_DEADLINE_WINDOW_SECS
     }
 
-    fn request_hash(amount: i128, nonce: u64, deadline: u64) -> u64 {
-        Orchestrator::compute_request_hash(symbol_short!("flow"), nonce, amount, deadline, 1, 1, 1)
+    fn request_hash(env: &Env, amount: i128, nonce: u64, deadline: u64) -> u64 {
+        let binding = Orchestrator::target_binding(env, &TargetList::single(env, 1));
+        Orchestrator::compute_request_hash(
+            symbol_short!("flow"),
+            nonce,
+            amount,
+            deadline,
+            binding,
+            binding,
+            binding,
+        )
     }
 
     fn execute_signed_flow(
@@ -1923,7 +4113,7 @@ mod tests_nonce_eviction {
         nonce: u64,
         deadline: u64,
     ) {
-        let hash = request_hash(amount, nonce, deadline);
+        let hash = request_hash(&client.env, amount, nonce, deadline);
         assert!(client
             .execute_remittance_flow_signed(executor, &amount, &nonce, &deadline, &hash, &0u64));
     }
@@ -1935,7 +4125,7 @@ mod tests_nonce_eviction {
         let executor = Address::generate(&harness.env);
         let nonce = 0;
         let deadline = valid_deadline();
-        let hash = request_hash(FLOW_AMOUNT, nonce, deadline);
+        let hash = request_hash(&harness.env, FLOW_AMOUNT, nonce, deadline);
 
         let replay = harness.env.as_contract(&harness.contract_id, || {
             Orchestrator::mark_nonce_used(&harness.env, &executor, nonce);
@@ -1962,7 +4152,7 @@ mod tests_nonce_eviction {
         execute_signed_flow(&client, &executor, FLOW_AMOUNT, 0, deadline);
         assert_eq!(client.get_nonce(&executor), 1);
 
-        let replay_hash = request_hash(FLOW_AMOUNT, 0, deadline);
+        let replay_hash = request_hash(&harness.env, FLOW_AMOUNT, 0, deadline);
         let replay = client.try_execute_remittance_flow_signed(
             &executor,
             &FLOW_AMOUNT,
@@ -1973,7 +4163,7 @@ mod tests_nonce_eviction {
         );
         assert_eq!(replay, Err(Ok(OrchestratorError::NonceAlreadyUsed)));
 
-        let skipped_hash = request_hash(FLOW_AMOUNT, 3, deadline);
+        let skipped_hash = request_hash(&harness.env, FLOW_AMOUNT, 3, deadline);
         let skipped = client.try_execute_remittance_flow_signed(
             &executor,
             &FLOW_AMOUNT,
@@ -2001,7 +4191,7 @@ mod tests_nonce_eviction {
         let cap_nonce = u64::from(MAX_USED_NONCES_PER_ADDR);
         assert_eq!(client.get_nonce(&executor), cap_nonce);
 
-        let oldest_before_eviction_hash = request_hash(FLOW_AMOUNT, 0, deadline);
+        let oldest_before_eviction_hash = request_hash(&harness.env, FLOW_AMOUNT, 0, deadline);
         let oldest_before_eviction_replay = client.try_execute_remittance_flow_signed(
             &executor,
             &FLOW_AMOUNT,
@@ -2020,7 +4210,7 @@ mod tests_nonce_eviction {
         let next_nonce = u64::from(MAX_USED_NONCES_PER_ADDR) + 1;
         assert_eq!(client.get_nonce(&executor), next_nonce);
 
-        let evicted_nonce_hash = request_hash(FLOW_AMOUNT, 0, deadline);
+        let evicted_nonce_hash = request_hash(&harness.env, FLOW_AMOUNT, 0, deadline);
         let evicted_nonce_replay = client.try_execute_remittance_flow_signed(
             &executor,
             &FLOW_AMOUNT,
@@ -2046,7 +4236,7 @@ mod tests_nonce_eviction {
         let executor = Address::generate(&harness.env);
 
         let expired_deadline = BASE_TIME;
-        let expired_hash = request_hash(FLOW_AMOUNT, 0, expired_deadline);
+        let expired_hash = request_hash(&harness.env, FLOW_AMOUNT, 0, expired_deadline);
         let expired = client.try_execute_remittance_flow_signed(
             &executor,
             &FLOW_AMOUNT,
@@ -2059,7 +4249,7 @@ mod tests_nonce_eviction {
         assert_eq!(client.get_nonce(&executor), 0);
 
         let beyond_window_deadline = BASE_TIME + MAX_DEADLINE_WINDOW_SECS + 1;
-        let beyond_window_hash = request_hash(FLOW_AMOUNT, 0, beyond_window_deadline);
+        let beyond_window_hash = request_hash(&harness.env, FLOW_AMOUNT, 0, beyond_window_deadline);
         let beyond_window = client.try_execute_remittance_flow_signed(
             &executor,
             &FLOW_AMOUNT,
@@ -2082,7 +4272,7 @@ mod tests_nonce_eviction {
         let executor = Address::generate(&harness.env);
         let nonce = 0;
         let deadline = valid_deadline();
-        let original_hash = request_hash(FLOW_AMOUNT, nonce, deadline);
+        let original_hash = request_hash(&harness.env, FLOW_AMOUNT, nonce, deadline);
         let swapped_amount = FLOW_AMOUNT + 1;
 
         let swapped = client.try_execute_remittance_flow_signed(
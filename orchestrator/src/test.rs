@@ -163,21 +163,18 @@ fn do_flow(env: &Env, client: &OrchestratorClient, executor: &Address, _nonce: u
         savings: mock_id.clone(),
         bills: mock_id.clone(),
         insurance: mock_id.clone(),
-        goal_id: 1,
-        bill_id: 1,
-        policy_id: 1,
+        goal_targets: TargetList::single(env, 1),
+        bill_targets: TargetList::single(env, 1),
+        policy_targets: TargetList::single(env, 1),
     });
 }
 
 /// Mirror of `Orchestrator::compute_request_hash` for test use.
-fn compute_test_hash(
-    _env: &Env,
-    operation: Symbol,
-    nonce: u64,
-    amount: i128,
-    deadline: u64,
-) -> u64 {
-    Orchestrator::compute_request_hash(operation, nonce, amount, deadline, 1, 1, 1)
+fn compute_test_hash(env: &Env, operation: Symbol, nonce: u64, amount: i128, deadline: u64) -> u64 {
+    let binding = Orchestrator::target_binding(env, &TargetList::single(env, 1));
+    Orchestrator::compute_request_hash(
+        operation, nonce, amount, deadline, binding, binding, binding,
+    )
 }
 
 fn wasm_size_budgets() -> &'static [(&'static str, usize)] {
@@ -186,7 +183,9 @@ fn wasm_size_budgets() -> &'static [(&'static str, usize)] {
         ("savings_goals.wasm", 112_000),
         ("bill_payments.wasm", 135_000),
         ("insurance.wasm", 70_000), // Increased to accommodate kill switch guard & pagination security additions
-        ("family_wallet.wasm", 130_000),
+        ("family_wallet.wasm", 130_000), // Default feature set; see family_wallet/README.md for other builds
+        ("orchestrator.wasm", 120_000),
+        ("reporting.wasm", 125_000), // Default `core_reports` build; other report families deploy separately
     ]
 }
 
@@ -251,9 +250,9 @@ fn test_execute_flow_success() {
         savings: mock_id.clone(),
         bills: mock_id.clone(),
         insurance: mock_id.clone(),
-        goal_id: 1,
-        bill_id: 1,
-        policy_id: 1,
+        goal_targets: TargetList::single(&env, 1),
+        bill_targets: TargetList::single(&env, 1),
+        policy_targets: TargetList::single(&env, 1),
     });
 
     // Check lock is released
@@ -280,9 +279,9 @@ fn test_lock_released_on_invalid_amount() {
         savings: mock_id.clone(),
         bills: mock_id.clone(),
         insurance: mock_id.clone(),
-        goal_id: 1,
-        bill_id: 1,
-        policy_id: 1,
+        goal_targets: TargetList::single(&env, 1),
+        bill_targets: TargetList::single(&env, 1),
+        policy_targets: TargetList::single(&env, 1),
     });
 
     assert!(result.is_err());
@@ -313,9 +312,9 @@ fn test_reentrancy_rejection() {
         savings: mock_id.clone(),
         bills: mock_id.clone(),
         insurance: mock_id.clone(),
-        goal_id: 1,
-        bill_id: 1,
-        policy_id: 1,
+        goal_targets: TargetList::single(&env, 1),
+        bill_targets: TargetList::single(&env, 1),
+        policy_targets: TargetList::single(&env, 1),
     });
 
     match result {
@@ -347,9 +346,9 @@ fn test_lock_recovery_after_failure() {
         savings: failing_id.clone(),
         bills: failing_id.clone(),
         insurance: failing_id.clone(),
-        goal_id: 1,
-        bill_id: 1,
-        policy_id: 1,
+        goal_targets: TargetList::single(&env, 1),
+        bill_targets: TargetList::single(&env, 1),
+        policy_targets: TargetList::single(&env, 1),
     });
 
     assert!(result.is_err());
@@ -1410,9 +1409,9 @@ fn test_unsigned_rollback_bill_failure_returns_rolled_back() {
         savings: mock_id.clone(),
         bills: mock_id.clone(),
         insurance: mock_id.clone(),
-        goal_id: 1,
-        bill_id: 1,
-        policy_id: 1,
+        goal_targets: TargetList::single(&env, 1),
+        bill_targets: TargetList::single(&env, 1),
+        policy_targets: TargetList::single(&env, 1),
     });
 
     assert_eq!(
@@ -1445,9 +1444,9 @@ fn test_unsigned_rollback_insurance_failure_returns_rolled_back() {
         savings: mock_id.clone(),
         bills: mock_id.clone(),
         insurance: mock_id.clone(),
-        goal_id: 1,
-        bill_id: 1,
-        policy_id: 1,
+        goal_targets: TargetList::single(&env, 1),
+        bill_targets: TargetList::single(&env, 1),
+        policy_targets: TargetList::single(&env, 1),
     });
 
     assert_eq!(
@@ -1480,9 +1479,9 @@ fn test_unsigned_rollback_first_step_fails_no_compensation() {
         savings: mock_id.clone(),
         bills: mock_id.clone(),
         insurance: mock_id.clone(),
-        goal_id: 1,
-        bill_id: 1,
-        policy_id: 1,
+        goal_targets: TargetList::single(&env, 1),
+        bill_targets: TargetList::single(&env, 1),
+        policy_targets: TargetList::single(&env, 1),
     });
 
     assert_eq!(
@@ -1517,28 +1516,28 @@ fn test_fanout_flow_does_not_compensate_on_bill_failure() {
 
     let caller = Address::generate(&env);
 
-    // Fan-out with failing bill step: must NOT compensate (no rollback)
-    let result = client.try_execute_flow_fanout(&caller, &10000i128);
-
-    // The fan-out captures per-step results — the outer Result should be Ok,
-    // but the inner FanOutFlowResult.all_succeeded should be false.
-    assert!(result.is_ok(), "fan-out must not panic on step failure");
-    if let Ok(Ok(fanout)) = &result {
-        assert!(!fanout.all_succeeded, "fan-out must report all_succeeded=false when a step fails");
-        assert!(!fanout.savings.succeeded, "savings step must report failure when bill mock panics");
-        assert!(!fanout.bills.succeeded, "bill step must report failure");
-    } else if let Ok(Err(e)) = &result {
-        // If the fan-out returns an error, it must be CrossContractCallFailed,
-        // NOT RemittanceFlowRolledBack (which would indicate compensation).
-        assert_eq!(
-            *e,
-            OrchestratorError::CrossContractCallFailed,
-            "fan-out failure must be CrossContractCallFailed, not RemittanceFlowRolledBack"
-        );
-    }
+    // Fan-out with failing bill step: must NOT compensate (no rollback).
+    // The steps are independent, so savings and insurance still land.
+    let fanout = client.execute_flow_fanout(&caller, &10000i128);
+    assert!(
+        !fanout.all_succeeded,
+        "fan-out must report all_succeeded=false when a step fails"
+    );
+    assert!(!fanout.bills.succeeded, "bill step must report failure");
+    assert!(
+        fanout.savings.succeeded,
+        "savings step is independent of the bill step"
+    );
+    assert!(
+        fanout.insurance.succeeded,
+        "insurance step is independent of the bill step"
+    );
 
     // Lock must be released even on fan-out failure
-    assert!(!client.get_execution_state(), "Lock must be released after fan-out failure");
+    assert!(
+        !client.get_execution_state(),
+        "Lock must be released after fan-out failure"
+    );
 }
 
 // ---------------------------------------------------------------------------
@@ -1567,7 +1566,7 @@ fn count_remitwise_events(env: &Env, contract_id: &Address, action: Symbol) -> u
 }
 
 fn flow_params(
-    _env: &Env,
+    env: &Env,
     caller: &Address,
     mock_id: &Address,
     amount: i128,
@@ -1580,9 +1579,9 @@ fn flow_params(
         savings: mock_id.clone(),
         bills: mock_id.clone(),
         insurance: mock_id.clone(),
-        goal_id: 1,
-        bill_id: 1,
-        policy_id: 1,
+        goal_targets: TargetList::single(env, 1),
+        bill_targets: TargetList::single(env, 1),
+        policy_targets: TargetList::single(env, 1),
     }
 }
 
@@ -1984,9 +1983,9 @@ fn test_not_initialized_fails() {
         savings: mock_id.clone(),
         bills: mock_id.clone(),
         insurance: mock_id.clone(),
-        goal_id: 1,
-        bill_id: 1,
-        policy_id: 1,
+        goal_targets: TargetList::single(&env, 1),
+        bill_targets: TargetList::single(&env, 1),
+        policy_targets: TargetList::single(&env, 1),
     });
 
     let stats = client.get_execution_stats();
@@ -2164,9 +2163,9 @@ fn test_exec_lock_released_when_hostile_downstream_fails_bill() {
     let caller = Address::generate(&env);
 
     let result = client.try_execute_remittance_flow(&flow_params_single(&env, &caller, &mock_id));
-    // Unsigned path does not enable compensation (compensate_on_failure=false),
-    // so CrossContractCallFailed is expected instead of RemittanceFlowRolledBack.
-    assert_eq!(result, Err(Ok(OrchestratorError::CrossContractCallFailed)));
+    // The savings step already applied, so the unsigned path compensates it
+    // and reports a rollback.
+    assert_eq!(result, Err(Ok(OrchestratorError::RemittanceFlowRolledBack)));
     assert!(!client.get_execution_state());
 }
 
@@ -2192,7 +2191,7 @@ fn test_lock_recovers_for_subsequent_valid_call_after_hostile_failure() {
     assert!(!client.get_execution_state());
 }
 
-fn flow_params_single(_env: &Env, caller: &Address, mock_id: &Address) -> RemittanceFlowParams {
+fn flow_params_single(env: &Env, caller: &Address, mock_id: &Address) -> RemittanceFlowParams {
     RemittanceFlowParams {
         caller: caller.clone(),
         total_amount: 10_000i128,
@@ -2201,9 +2200,9 @@ fn flow_params_single(_env: &Env, caller: &Address, mock_id: &Address) -> Remitt
         savings: mock_id.clone(),
         bills: mock_id.clone(),
         insurance: mock_id.clone(),
-        goal_id: 1,
-        bill_id: 1,
-        policy_id: 1,
+        goal_targets: TargetList::single(env, 1),
+        bill_targets: TargetList::single(env, 1),
+        policy_targets: TargetList::single(env, 1),
     }
 }
 
@@ -3256,3 +3255,1914 @@ fn test_fanout_rejects_short_split_vector() {
 }
 
 
+
+// ---------------------------------------------------------------------------
+// Per-user routing profiles and multi-target allocation
+// ---------------------------------------------------------------------------
+
+/// Downstream mock that records `(target_id, amount)` for every write and
+/// reversal, keyed by kind, in storage and as a `(kind,)` event. Any call for
+/// target id 999, or for an id marked down with `set_down`, panics.
+///
+/// The delegated `*_for` entrypoints record under the same kinds, but like the
+/// real contracts they require the executor's auth and its registration by
+/// the owner through `set_authorized_executor`.
+mod mock_recording_targets {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol, Vec};
+
+    pub const FAILING_ID: u32 = 999;
+
+    #[contract]
+    pub struct Contract;
+
+    #[contractimpl]
+    impl Contract {
+        pub fn check_spending_limit(_env: Env, _user: Address, _amount: i128) -> bool {
+            true
+        }
+        pub fn calculate_split(env: Env, _total_amount: i128) -> Vec<i128> {
+            soroban_sdk::vec![&env, 2500i128, 2500i128, 2500i128, 2500i128]
+        }
+        pub fn add_to_goal(env: Env, _user: Address, goal_id: u32, amount: i128) {
+            Self::record(&env, symbol_short!("goal"), goal_id, amount);
+        }
+        pub fn pay_bill(env: Env, _user: Address, bill_id: u32, amount: i128) {
+            Self::record(&env, symbol_short!("bill"), bill_id, amount);
+        }
+        pub fn pay_premium(env: Env, _user: Address, policy_id: u32, amount: i128) {
+            Self::record(&env, symbol_short!("policy"), policy_id, amount);
+        }
+        pub fn remove_from_goal(env: Env, _user: Address, goal_id: u32, amount: i128) {
+            Self::record(&env, symbol_short!("rev_goal"), goal_id, amount);
+        }
+        pub fn reverse_payment(env: Env, _user: Address, bill_id: u32, amount: i128) {
+            Self::record(&env, symbol_short!("rev_bill"), bill_id, amount);
+        }
+        pub fn reverse_premium(env: Env, _user: Address, policy_id: u32, amount: i128) {
+            Self::record(&env, symbol_short!("rev_pol"), policy_id, amount);
+        }
+        pub fn set_authorized_executor(
+            env: Env,
+            owner: Address,
+            executor: Address,
+            authorized: bool,
+        ) {
+            owner.require_auth();
+            env.storage()
+                .instance()
+                .set(&(symbol_short!("exec"), owner, executor), &authorized);
+        }
+        pub fn add_to_goal_for(
+            env: Env,
+            executor: Address,
+            owner: Address,
+            goal_id: u32,
+            amount: i128,
+        ) -> i128 {
+            Self::require_executor(&env, &executor, &owner);
+            Self::record(&env, symbol_short!("goal"), goal_id, amount);
+            amount
+        }
+        pub fn pay_bill_for(
+            env: Env,
+            executor: Address,
+            owner: Address,
+            bill_id: u32,
+            amount: i128,
+        ) {
+            Self::require_executor(&env, &executor, &owner);
+            Self::record(&env, symbol_short!("bill"), bill_id, amount);
+        }
+        pub fn pay_premium_for(
+            env: Env,
+            executor: Address,
+            owner: Address,
+            policy_id: u32,
+            amount: i128,
+        ) {
+            Self::require_executor(&env, &executor, &owner);
+            Self::record(&env, symbol_short!("policy"), policy_id, amount);
+        }
+        pub fn remove_from_goal_for(
+            env: Env,
+            executor: Address,
+            owner: Address,
+            goal_id: u32,
+            amount: i128,
+        ) {
+            Self::require_executor(&env, &executor, &owner);
+            Self::record(&env, symbol_short!("rev_goal"), goal_id, amount);
+        }
+        pub fn reverse_payment_for(
+            env: Env,
+            executor: Address,
+            owner: Address,
+            bill_id: u32,
+            amount: i128,
+        ) {
+            Self::require_executor(&env, &executor, &owner);
+            Self::record(&env, symbol_short!("rev_bill"), bill_id, amount);
+        }
+        pub fn reverse_premium_for(
+            env: Env,
+            executor: Address,
+            owner: Address,
+            policy_id: u32,
+            amount: i128,
+        ) {
+            Self::require_executor(&env, &executor, &owner);
+            Self::record(&env, symbol_short!("rev_pol"), policy_id, amount);
+        }
+        pub fn set_down(env: Env, id: u32, down: bool) {
+            env.storage()
+                .instance()
+                .set(&(symbol_short!("down"), id), &down);
+        }
+        pub fn calls(env: Env, kind: Symbol) -> Vec<(u32, i128)> {
+            env.storage()
+                .instance()
+                .get(&kind)
+                .unwrap_or_else(|| Vec::new(&env))
+        }
+    }
+
+    impl Contract {
+        fn require_executor(env: &Env, executor: &Address, owner: &Address) {
+            executor.require_auth();
+            let authorized: bool = env
+                .storage()
+                .instance()
+                .get(&(symbol_short!("exec"), owner.clone(), executor.clone()))
+                .unwrap_or(false);
+            if !authorized {
+                panic!("executor not registered");
+            }
+        }
+
+        fn record(env: &Env, kind: Symbol, id: u32, amount: i128) {
+            let down: bool = env
+                .storage()
+                .instance()
+                .get(&(symbol_short!("down"), id))
+                .unwrap_or(false);
+            if id == FAILING_ID || down {
+                panic!("target rejected");
+            }
+            let mut calls = Self::calls(env.clone(), kind.clone());
+            calls.push_back((id, amount));
+            env.storage().instance().set(&kind, &calls);
+            env.events().publish((kind,), (id, amount));
+        }
+    }
+}
+
+fn setup_recording_orchestrator(
+    env: &Env,
+) -> (
+    OrchestratorClient<'_>,
+    mock_recording_targets::ContractClient<'_>,
+    Address,
+) {
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    let owner = Address::generate(env);
+    let (_, client) = register_orchestrator(env);
+    let fw = env.register_contract(None, MockContract);
+    let rs = env.register_contract(None, MockContract);
+    let downstream = env.register_contract(None, mock_recording_targets::Contract);
+    let bp = env.register_contract(None, mock_recording_targets::Contract);
+    let ins = env.register_contract(None, mock_recording_targets::Contract);
+    client.init(&owner, &fw, &rs, &downstream, &bp, &ins);
+    let recorder = mock_recording_targets::ContractClient::new(env, &downstream);
+    (client, recorder, bp)
+}
+
+/// `(target_id, amount)` pairs `contract` published under `kind`. Unlike the
+/// mock's storage these survive a top-level call that returns an error.
+fn recorded_events(env: &Env, contract: &Address, kind: Symbol) -> Vec<(u32, i128)> {
+    let topics: Vec<soroban_sdk::Val> = soroban_sdk::vec![env, kind.into_val(env)];
+    let mut out = Vec::new(env);
+    for (cid, event_topics, data) in env.events().all().iter() {
+        if cid == *contract && event_topics == topics {
+            out.push_back(<(u32, i128)>::from_val(env, &data));
+        }
+    }
+    out
+}
+
+/// Build a target list from `(id, weight, cap, due_date)` tuples.
+fn target_list(env: &Env, rule: AllocationRule, entries: &[(u32, u32, i128, u64)]) -> TargetList {
+    let mut targets = Vec::new(env);
+    for &(id, weight, cap, due_date) in entries {
+        targets.push_back(FlowTarget {
+            id,
+            weight,
+            cap,
+            due_date,
+        });
+    }
+    TargetList { rule, targets }
+}
+
+#[test]
+fn test_routing_profile_set_get_and_clear() {
+    let env = Env::default();
+    let (client, _, _) = setup_recording_orchestrator(&env);
+    let user = Address::generate(&env);
+    let goals = target_list(&env, AllocationRule::ProRata, &[(7, 1, 0, 0), (3, 1, 0, 0)]);
+    let bills = target_list(
+        &env,
+        AllocationRule::DueDate,
+        &[(12, 0, 0, 500), (4, 0, 0, 100)],
+    );
+
+    assert_eq!(client.get_routing_profile(&user), None);
+    assert!(client.set_routing_profile(&user, &goals, &bills, &TargetList::single(&env, 9)));
+
+    let profile = client.get_routing_profile(&user).unwrap();
+    assert_eq!(profile.goal_targets, goals);
+    assert_eq!(profile.bill_targets, bills);
+    assert_eq!(profile.policy_targets, TargetList::single(&env, 9));
+
+    assert!(client.clear_routing_profile(&user));
+    assert_eq!(client.get_routing_profile(&user), None);
+    assert!(!client.clear_routing_profile(&user));
+}
+
+#[test]
+fn test_routing_profile_rejects_invalid_lists() {
+    let env = Env::default();
+    let (client, _, _) = setup_recording_orchestrator(&env);
+    let user = Address::generate(&env);
+    let one = TargetList::single(&env, 1);
+
+    let empty = TargetList {
+        rule: AllocationRule::Priority,
+        targets: Vec::new(&env),
+    };
+    let result = client.try_set_routing_profile(&user, &empty, &one, &one);
+    assert_eq!(result, Err(Ok(OrchestratorError::InvalidTargetList)));
+
+    let duplicate = target_list(
+        &env,
+        AllocationRule::Priority,
+        &[(5, 0, 0, 0), (5, 0, 0, 0)],
+    );
+    let result = client.try_set_routing_profile(&user, &one, &duplicate, &one);
+    assert_eq!(result, Err(Ok(OrchestratorError::InvalidTargetList)));
+
+    let weightless = target_list(&env, AllocationRule::ProRata, &[(5, 0, 0, 0), (6, 0, 0, 0)]);
+    let result = client.try_set_routing_profile(&user, &weightless, &one, &one);
+    assert_eq!(result, Err(Ok(OrchestratorError::InvalidTargetList)));
+
+    let negative_cap = target_list(&env, AllocationRule::Priority, &[(5, 0, -1, 0)]);
+    let result = client.try_set_routing_profile(&user, &one, &one, &negative_cap);
+    assert_eq!(result, Err(Ok(OrchestratorError::InvalidTargetList)));
+
+    let mut too_many = Vec::new(&env);
+    for id in 0..=MAX_FLOW_TARGETS {
+        too_many.push_back(FlowTarget {
+            id,
+            weight: 1,
+            cap: 0,
+            due_date: 0,
+        });
+    }
+    let oversized = TargetList {
+        rule: AllocationRule::Priority,
+        targets: too_many,
+    };
+    let result = client.try_set_routing_profile(&user, &one, &one, &oversized);
+    assert_eq!(result, Err(Ok(OrchestratorError::InvalidTargetList)));
+    assert_eq!(client.get_routing_profile(&user), None);
+}
+
+#[test]
+fn test_target_binding_covers_whole_list() {
+    let env = Env::default();
+    let base = target_list(&env, AllocationRule::ProRata, &[(1, 1, 0, 0), (2, 2, 0, 0)]);
+    let reweighted = target_list(&env, AllocationRule::ProRata, &[(1, 2, 0, 0), (2, 1, 0, 0)]);
+    let capped = target_list(
+        &env,
+        AllocationRule::ProRata,
+        &[(1, 1, 500, 0), (2, 2, 0, 0)],
+    );
+    let reruled = target_list(
+        &env,
+        AllocationRule::Priority,
+        &[(1, 1, 0, 0), (2, 2, 0, 0)],
+    );
+    // The old fold mapped [0, 31] and [1, 0] to the same slot.
+    let fold_a = target_list(
+        &env,
+        AllocationRule::Priority,
+        &[(0, 0, 0, 0), (31, 0, 0, 0)],
+    );
+    let fold_b = target_list(
+        &env,
+        AllocationRule::Priority,
+        &[(1, 0, 0, 0), (0, 0, 0, 0)],
+    );
+
+    let binding = |list: &TargetList| Orchestrator::target_binding(&env, list);
+    assert_eq!(binding(&base), binding(&base.clone()));
+    assert_ne!(binding(&base), binding(&reweighted));
+    assert_ne!(binding(&base), binding(&capped));
+    assert_ne!(binding(&base), binding(&reruled));
+    assert_ne!(binding(&fold_a), binding(&fold_b));
+}
+
+#[test]
+fn test_fanout_routes_each_caller_to_own_profile() {
+    let env = Env::default();
+    let (client, savings, bp) = setup_recording_orchestrator(&env);
+    let bills = mock_recording_targets::ContractClient::new(&env, &bp);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+
+    client.set_routing_profile(
+        &alice,
+        &TargetList::single(&env, 11),
+        &TargetList::single(&env, 21),
+        &TargetList::single(&env, 31),
+    );
+    client.set_routing_profile(
+        &bob,
+        &TargetList::single(&env, 41),
+        &TargetList::single(&env, 52),
+        &TargetList::single(&env, 61),
+    );
+
+    assert!(client.execute_flow_fanout(&alice, &10_000).all_succeeded);
+    assert!(client.execute_flow_fanout(&bob, &10_000).all_succeeded);
+    // No profile: falls back to the contract-wide defaults written at init.
+    assert!(client.execute_flow_fanout(&carol, &10_000).all_succeeded);
+
+    assert_eq!(
+        savings.calls(&symbol_short!("goal")),
+        soroban_sdk::vec![&env, (11u32, 2500i128), (41, 2500), (1, 2500)]
+    );
+    assert_eq!(
+        bills.calls(&symbol_short!("bill")),
+        soroban_sdk::vec![&env, (21u32, 2500i128), (52, 2500), (1, 2500)]
+    );
+}
+
+#[test]
+fn test_fanout_allocates_each_list_by_its_rule() {
+    let env = Env::default();
+    let (client, savings, bp) = setup_recording_orchestrator(&env);
+    let bills = mock_recording_targets::ContractClient::new(&env, &bp);
+    let user = Address::generate(&env);
+
+    // 2500 pro-rata 1:2 → 833 / 1666, rounding dust to the first target.
+    let goals = target_list(&env, AllocationRule::ProRata, &[(1, 1, 0, 0), (2, 2, 0, 0)]);
+    // Oldest-due bill (id 8) is filled to its cap first, then id 7 takes the rest.
+    let bill_list = target_list(
+        &env,
+        AllocationRule::DueDate,
+        &[(7, 0, 0, 2_000), (8, 0, 1_000, 1_000)],
+    );
+    // Priority: 600 to policy 5, the remaining 1900 absorbed by the last target.
+    let policies = target_list(
+        &env,
+        AllocationRule::Priority,
+        &[(5, 0, 600, 0), (6, 0, 300, 0)],
+    );
+    client.set_routing_profile(&user, &goals, &bill_list, &policies);
+
+    let result = client.execute_flow_fanout(&user, &10_000);
+    assert!(result.all_succeeded);
+    assert_eq!(result.savings.amount, 2500);
+    assert_eq!(result.bills.amount, 2500);
+    assert_eq!(result.insurance.amount, 2500);
+
+    let step = |step, target_id, amount| FanOutTargetResult {
+        step,
+        target_id,
+        amount,
+        succeeded: true,
+    };
+    assert_eq!(
+        result.targets,
+        soroban_sdk::vec![
+            &env,
+            step(FlowStep::SavingsGoal, 1, 834),
+            step(FlowStep::SavingsGoal, 2, 1666),
+            step(FlowStep::BillPayment, 8, 1000),
+            step(FlowStep::BillPayment, 7, 1500),
+            step(FlowStep::InsurancePremium, 5, 600),
+            step(FlowStep::InsurancePremium, 6, 1900),
+        ]
+    );
+    assert_eq!(
+        savings.calls(&symbol_short!("goal")),
+        soroban_sdk::vec![&env, (1u32, 834i128), (2, 1666)]
+    );
+    assert_eq!(
+        bills.calls(&symbol_short!("bill")),
+        soroban_sdk::vec![&env, (8u32, 1000i128), (7, 1500)]
+    );
+}
+
+#[test]
+fn test_fanout_reports_failed_target_without_failing_siblings() {
+    let env = Env::default();
+    let (client, _, _) = setup_recording_orchestrator(&env);
+    let user = Address::generate(&env);
+    let bill_list = target_list(
+        &env,
+        AllocationRule::Priority,
+        &[
+            (3, 0, 1_000, 0),
+            (mock_recording_targets::FAILING_ID, 0, 0, 0),
+        ],
+    );
+    client.set_routing_profile(
+        &user,
+        &TargetList::single(&env, 1),
+        &bill_list,
+        &TargetList::single(&env, 1),
+    );
+
+    let result = client.execute_flow_fanout(&user, &10_000);
+    assert!(!result.all_succeeded);
+    assert!(result.savings.succeeded);
+    assert!(!result.bills.succeeded);
+    assert!(result.insurance.succeeded);
+    assert!(result.targets.get(1).unwrap().succeeded);
+    assert!(!result.targets.get(2).unwrap().succeeded);
+}
+
+#[test]
+fn test_multi_target_flow_compensates_every_completed_target() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    let (_, client) = register_orchestrator(&env);
+    let downstream = env.register_contract(None, mock_recording_targets::Contract);
+    let caller = Address::generate(&env);
+
+    let mut params = flow_params(&env, &caller, &downstream, 10_000);
+    params.goal_targets = target_list(&env, AllocationRule::ProRata, &[(1, 1, 0, 0), (2, 1, 0, 0)]);
+    params.bill_targets = target_list(
+        &env,
+        AllocationRule::Priority,
+        &[
+            (3, 0, 1_000, 0),
+            (mock_recording_targets::FAILING_ID, 0, 0, 0),
+        ],
+    );
+
+    let result = client.try_execute_remittance_flow(&params);
+    assert_eq!(result, Err(Ok(OrchestratorError::RemittanceFlowRolledBack)));
+
+    assert_eq!(
+        recorded_events(&env, &downstream, symbol_short!("rev_bill")),
+        soroban_sdk::vec![&env, (3u32, 1000i128)]
+    );
+    assert_eq!(
+        recorded_events(&env, &downstream, symbol_short!("rev_goal")),
+        soroban_sdk::vec![&env, (2u32, 1250i128), (1, 1250)]
+    );
+    assert_eq!(
+        recorded_events(&env, &downstream, symbol_short!("policy")).len(),
+        0
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Resumable flows
+// ---------------------------------------------------------------------------
+
+const PAUSED_BILL: u32 = 40;
+
+/// Start a flow funding goals 1 and 2 pro-rata, bill `PAUSED_BILL` (currently
+/// down) and policy 5, all on one recording downstream contract.
+fn start_flow_with_paused_bill(
+    env: &Env,
+) -> (
+    OrchestratorClient<'_>,
+    mock_recording_targets::ContractClient<'_>,
+    FlowRecord,
+) {
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    let (_, client) = register_orchestrator(env);
+    let downstream = env.register_contract(None, mock_recording_targets::Contract);
+    let recorder = mock_recording_targets::ContractClient::new(env, &downstream);
+    recorder.set_down(&PAUSED_BILL, &true);
+
+    let caller = Address::generate(env);
+    let mut params = flow_params(env, &caller, &downstream, 10_000);
+    params.goal_targets = target_list(env, AllocationRule::ProRata, &[(1, 1, 0, 0), (2, 1, 0, 0)]);
+    params.bill_targets = TargetList::single(env, PAUSED_BILL);
+    params.policy_targets = TargetList::single(env, 5);
+
+    let record = client.start_flow(&params);
+    (client, recorder, record)
+}
+
+fn step_statuses(record: &FlowRecord) -> std::vec::Vec<(FlowStep, u32, StepStatus, u32)> {
+    record
+        .steps
+        .iter()
+        .map(|s| (s.step, s.target_id, s.status, s.attempts))
+        .collect()
+}
+
+#[test]
+fn test_start_flow_persists_step_status_and_resume_retries_only_failed() {
+    let env = Env::default();
+    let (client, recorder, record) = start_flow_with_paused_bill(&env);
+
+    assert_eq!(record.id, 1);
+    assert_eq!(record.status, FlowStatus::PartiallyCompleted);
+    assert_eq!(
+        step_statuses(&record),
+        std::vec![
+            (FlowStep::SpendingCheck, 0, StepStatus::Completed, 1),
+            (FlowStep::SplitCalculation, 0, StepStatus::Completed, 1),
+            (FlowStep::SavingsGoal, 1, StepStatus::Completed, 1),
+            (FlowStep::SavingsGoal, 2, StepStatus::Completed, 1),
+            (FlowStep::BillPayment, PAUSED_BILL, StepStatus::Failed, 1),
+            (FlowStep::InsurancePremium, 5, StepStatus::Completed, 1),
+        ]
+    );
+    assert_eq!(
+        step_statuses(&client.get_flow(&1).unwrap()),
+        step_statuses(&record)
+    );
+    assert!(!client.get_execution_state());
+
+    // Still down: the retry fails again and only the bill step is attempted.
+    let retried = client.resume_flow(&record.params.caller, &1);
+    assert_eq!(retried.status, FlowStatus::PartiallyCompleted);
+    assert_eq!(retried.steps.get(4).unwrap().attempts, 2);
+
+    recorder.set_down(&PAUSED_BILL, &false);
+    let resumed = client.resume_flow(&record.params.caller, &1);
+    assert_eq!(resumed.status, FlowStatus::Completed);
+    assert_eq!(resumed.steps.get(4).unwrap().status, StepStatus::Completed);
+    assert_eq!(resumed.steps.get(4).unwrap().attempts, 3);
+    assert_eq!(resumed.steps.get(2).unwrap().attempts, 1);
+
+    // Completed steps were never re-executed.
+    assert_eq!(
+        recorder.calls(&symbol_short!("goal")),
+        soroban_sdk::vec![&env, (1u32, 1250i128), (2, 1250)]
+    );
+    assert_eq!(
+        recorder.calls(&symbol_short!("bill")),
+        soroban_sdk::vec![&env, (PAUSED_BILL, 2500i128)]
+    );
+    assert_eq!(client.get_flow(&1).unwrap().status, FlowStatus::Completed);
+}
+
+#[test]
+fn test_abort_flow_compensates_completed_steps() {
+    let env = Env::default();
+    let (client, recorder, record) = start_flow_with_paused_bill(&env);
+
+    let aborted = client.abort_flow(&record.params.caller, &record.id);
+    assert_eq!(aborted.status, FlowStatus::Aborted);
+    assert_eq!(
+        step_statuses(&aborted)[2..],
+        [
+            (FlowStep::SavingsGoal, 1, StepStatus::Compensated, 1),
+            (FlowStep::SavingsGoal, 2, StepStatus::Compensated, 1),
+            (FlowStep::BillPayment, PAUSED_BILL, StepStatus::Failed, 1),
+            (FlowStep::InsurancePremium, 5, StepStatus::Compensated, 1),
+        ]
+    );
+    assert_eq!(
+        recorder.calls(&symbol_short!("rev_goal")),
+        soroban_sdk::vec![&env, (2u32, 1250i128), (1, 1250)]
+    );
+    assert_eq!(
+        recorder.calls(&symbol_short!("rev_pol")),
+        soroban_sdk::vec![&env, (5u32, 2500i128)]
+    );
+    assert_eq!(recorder.calls(&symbol_short!("rev_bill")).len(), 0);
+
+    let again = client.try_resume_flow(&record.params.caller, &record.id);
+    assert_eq!(again, Err(Ok(OrchestratorError::InvalidFlowState)));
+}
+
+#[test]
+fn test_abort_flow_records_steps_it_could_not_reverse() {
+    let env = Env::default();
+    let (client, recorder, record) = start_flow_with_paused_bill(&env);
+
+    // Goal 2 now rejects every call, including its reversal.
+    recorder.set_down(&2, &true);
+    let aborted = client.abort_flow(&record.params.caller, &record.id);
+    assert_eq!(aborted.status, FlowStatus::Aborted);
+    assert_eq!(
+        step_statuses(&aborted)[2..],
+        [
+            (FlowStep::SavingsGoal, 1, StepStatus::Compensated, 1),
+            (FlowStep::SavingsGoal, 2, StepStatus::CompensationFailed, 1),
+            (FlowStep::BillPayment, PAUSED_BILL, StepStatus::Failed, 1),
+            (FlowStep::InsurancePremium, 5, StepStatus::Compensated, 1),
+        ]
+    );
+    assert_eq!(
+        recorder.calls(&symbol_short!("rev_goal")),
+        soroban_sdk::vec![&env, (1u32, 1250i128)]
+    );
+    assert_eq!(
+        client.get_flow(&record.id).unwrap().status,
+        FlowStatus::Aborted
+    );
+    let audit = client.get_audit_log(&0, &100);
+    assert_eq!(audit.last().unwrap().operation, symbol_short!("flow_abrt"));
+    assert!(!audit.last().unwrap().success);
+}
+
+#[test]
+fn test_resume_and_abort_flow_guard_ownership_and_existence() {
+    let env = Env::default();
+    let (client, _, record) = start_flow_with_paused_bill(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_resume_flow(&stranger, &record.id),
+        Err(Ok(OrchestratorError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_abort_flow(&stranger, &record.id),
+        Err(Ok(OrchestratorError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_resume_flow(&record.params.caller, &99),
+        Err(Ok(OrchestratorError::FlowNotFound))
+    );
+    assert!(client.get_flow(&99).is_none());
+}
+
+// ---------------------------------------------------------------------------
+// Scheduled flows
+// ---------------------------------------------------------------------------
+
+const SCHEDULE_START: u64 = 1_000_000;
+const DAY: u64 = 86_400;
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|l| l.timestamp = timestamp);
+}
+
+/// [`setup_recording_orchestrator`] plus a schedule owner who registered the
+/// orchestrator as executor on the savings, bills and insurance mocks. The
+/// ledger starts at `SCHEDULE_START`.
+fn setup_flow_schedules(
+    env: &Env,
+) -> (
+    OrchestratorClient<'_>,
+    mock_recording_targets::ContractClient<'_>,
+    Address,
+) {
+    let (client, recorder, bills) = setup_recording_orchestrator(env);
+    let owner = Address::generate(env);
+    let insurance: Address = env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("INS_ADDR"))
+            .unwrap()
+    });
+    for downstream in [recorder.address.clone(), bills, insurance] {
+        mock_recording_targets::ContractClient::new(env, &downstream).set_authorized_executor(
+            &owner,
+            &client.address,
+            &true,
+        );
+    }
+    set_time(env, SCHEDULE_START);
+    (client, recorder, owner)
+}
+
+/// Schedule a daily 10_000 flow into goal 1, bill 2 and policy 3, first due
+/// one day after `SCHEDULE_START`.
+fn create_daily_schedule(
+    env: &Env,
+    client: &OrchestratorClient<'_>,
+    owner: &Address,
+    spending_cap: i128,
+) -> u32 {
+    client.create_flow_schedule(
+        owner,
+        &10_000,
+        &TargetList::single(env, 1),
+        &TargetList::single(env, 2),
+        &TargetList::single(env, 3),
+        &(SCHEDULE_START + DAY),
+        &DAY,
+        &spending_cap,
+    )
+}
+
+#[test]
+fn test_create_flow_schedule_validates_inputs() {
+    let env = Env::default();
+    let (client, _, owner) = setup_flow_schedules(&env);
+    let one = TargetList::single(&env, 1);
+    let due = SCHEDULE_START + DAY;
+
+    let create = |amount: i128, next_due: u64, interval: u64, cap: i128| {
+        client.try_create_flow_schedule(
+            &owner, &amount, &one, &one, &one, &next_due, &interval, &cap,
+        )
+    };
+    assert_eq!(
+        create(0, due, DAY, 10_000),
+        Err(Ok(OrchestratorError::InvalidAmount))
+    );
+    assert_eq!(
+        create(10_000, SCHEDULE_START, DAY, 10_000),
+        Err(Ok(OrchestratorError::InvalidSchedule))
+    );
+    assert_eq!(
+        create(10_000, due, 60, 10_000),
+        Err(Ok(OrchestratorError::InvalidSchedule))
+    );
+    assert_eq!(
+        create(10_000, SCHEDULE_START + 400 * DAY, DAY, 10_000),
+        Err(Ok(OrchestratorError::InvalidSchedule))
+    );
+    assert_eq!(
+        create(10_000, due, DAY, 9_999),
+        Err(Ok(OrchestratorError::InvalidSchedule))
+    );
+
+    let id = create(10_000, due, DAY, 30_000).unwrap().unwrap();
+    let schedule = client.get_flow_schedule(&id).unwrap();
+    assert_eq!(schedule.owner, owner);
+    assert!(schedule.recurring && schedule.active);
+    assert_eq!(schedule.next_due, due);
+    assert_eq!(schedule.spent, 0);
+    assert_eq!(client.get_flow_schedules(&owner).len(), 1);
+}
+
+#[test]
+fn test_create_flow_schedule_enforces_per_owner_limit() {
+    let env = Env::default();
+    let (client, _, owner) = setup_flow_schedules(&env);
+
+    for _ in 0..MAX_FLOW_SCHEDULES_PER_OWNER {
+        create_daily_schedule(&env, &client, &owner, 10_000);
+    }
+    let one = TargetList::single(&env, 1);
+    let result = client.try_create_flow_schedule(
+        &owner,
+        &10_000,
+        &one,
+        &one,
+        &one,
+        &(SCHEDULE_START + DAY),
+        &DAY,
+        &10_000,
+    );
+    assert_eq!(result, Err(Ok(OrchestratorError::ScheduleLimitReached)));
+
+    // Another owner is unaffected.
+    create_daily_schedule(&env, &client, &Address::generate(&env), 10_000);
+}
+
+#[test]
+fn test_execute_due_flows_runs_due_schedule_and_advances() {
+    let env = Env::default();
+    let (client, recorder, owner) = setup_flow_schedules(&env);
+    let id = create_daily_schedule(&env, &client, &owner, 100_000);
+
+    // Not yet due.
+    assert_eq!(client.execute_due_flows().len(), 0);
+
+    set_time(&env, SCHEDULE_START + DAY);
+    assert_eq!(client.execute_due_flows(), soroban_sdk::vec![&env, id]);
+    assert_eq!(
+        recorder.calls(&symbol_short!("goal")),
+        soroban_sdk::vec![&env, (1u32, 2500i128)]
+    );
+    let schedule = client.get_flow_schedule(&id).unwrap();
+    assert_eq!(schedule.next_due, SCHEDULE_START + 2 * DAY);
+    assert_eq!(schedule.last_executed, Some(SCHEDULE_START + DAY));
+    assert_eq!(schedule.spent, 10_000);
+    assert_eq!(schedule.missed_count, 0);
+
+    // Idempotent within the same period.
+    assert_eq!(client.execute_due_flows().len(), 0);
+    assert_eq!(recorder.calls(&symbol_short!("goal")).len(), 1);
+    assert!(!client.get_execution_state());
+}
+
+#[test]
+fn test_execute_due_flows_counts_missed_intervals() {
+    let env = Env::default();
+    let (client, _, owner) = setup_flow_schedules(&env);
+    let id = create_daily_schedule(&env, &client, &owner, 100_000);
+
+    // Three and a half days late: one run, three intervals missed.
+    set_time(&env, SCHEDULE_START + DAY + 3 * DAY + DAY / 2);
+    assert_eq!(client.execute_due_flows(), soroban_sdk::vec![&env, id]);
+
+    let schedule = client.get_flow_schedule(&id).unwrap();
+    assert_eq!(schedule.missed_count, 3);
+    assert_eq!(schedule.next_due, SCHEDULE_START + 5 * DAY);
+    assert_eq!(schedule.spent, 10_000);
+}
+
+#[test]
+fn test_execute_due_flows_deactivates_schedule_past_spending_cap() {
+    let env = Env::default();
+    let (client, recorder, owner) = setup_flow_schedules(&env);
+    let id = create_daily_schedule(&env, &client, &owner, 15_000);
+
+    set_time(&env, SCHEDULE_START + DAY);
+    assert_eq!(client.execute_due_flows().len(), 1);
+
+    set_time(&env, SCHEDULE_START + 2 * DAY);
+    assert_eq!(client.execute_due_flows().len(), 0);
+    let schedule = client.get_flow_schedule(&id).unwrap();
+    assert!(!schedule.active);
+    assert_eq!(schedule.spent, 10_000);
+    assert_eq!(recorder.calls(&symbol_short!("goal")).len(), 1);
+
+    // Raising the cap reactivates it.
+    assert!(client.set_flow_schedule_cap(&owner, &id, &20_000));
+    assert!(client.get_flow_schedule(&id).unwrap().active);
+    assert_eq!(client.execute_due_flows(), soroban_sdk::vec![&env, id]);
+    assert_eq!(client.get_flow_schedule(&id).unwrap().spent, 20_000);
+
+    assert_eq!(
+        client.try_set_flow_schedule_cap(&owner, &id, &19_999),
+        Err(Ok(OrchestratorError::InvalidSchedule))
+    );
+}
+
+#[test]
+fn test_execute_due_flows_leaves_failed_run_due_for_retry() {
+    let env = Env::default();
+    let (client, recorder, owner) = setup_flow_schedules(&env);
+    let id = create_daily_schedule(&env, &client, &owner, 100_000);
+    recorder.set_down(&1, &true);
+
+    set_time(&env, SCHEDULE_START + DAY);
+    assert_eq!(client.execute_due_flows().len(), 0);
+    let schedule = client.get_flow_schedule(&id).unwrap();
+    assert!(schedule.active);
+    assert_eq!(schedule.next_due, SCHEDULE_START + DAY);
+    assert_eq!(schedule.last_executed, None);
+    assert_eq!(schedule.spent, 0);
+    assert!(!client.get_execution_state());
+
+    recorder.set_down(&1, &false);
+    assert_eq!(client.execute_due_flows(), soroban_sdk::vec![&env, id]);
+}
+
+#[test]
+fn test_cancel_flow_schedule_requires_owner_and_stops_runs() {
+    let env = Env::default();
+    let (client, recorder, owner) = setup_flow_schedules(&env);
+    let id = create_daily_schedule(&env, &client, &owner, 100_000);
+
+    assert_eq!(
+        client.try_cancel_flow_schedule(&Address::generate(&env), &id),
+        Err(Ok(OrchestratorError::ScheduleNotFound))
+    );
+    assert_eq!(
+        client.try_cancel_flow_schedule(&owner, &42),
+        Err(Ok(OrchestratorError::ScheduleNotFound))
+    );
+    assert!(client.cancel_flow_schedule(&owner, &id));
+
+    set_time(&env, SCHEDULE_START + DAY);
+    assert_eq!(client.execute_due_flows().len(), 0);
+    assert_eq!(recorder.calls(&symbol_short!("goal")).len(), 0);
+    assert!(!client.get_flow_schedule(&id).unwrap().active);
+}
+
+#[test]
+fn test_one_off_flow_schedule_runs_once() {
+    let env = Env::default();
+    let (client, _, owner) = setup_flow_schedules(&env);
+    let one = TargetList::single(&env, 1);
+    let id = client.create_flow_schedule(
+        &owner,
+        &10_000,
+        &one,
+        &one,
+        &one,
+        &(SCHEDULE_START + DAY),
+        &0,
+        &50_000,
+    );
+
+    set_time(&env, SCHEDULE_START + 3 * DAY);
+    assert_eq!(client.execute_due_flows(), soroban_sdk::vec![&env, id]);
+    let schedule = client.get_flow_schedule(&id).unwrap();
+    assert!(!schedule.recurring && !schedule.active);
+    assert_eq!(schedule.missed_count, 0);
+    assert_eq!(client.execute_due_flows().len(), 0);
+}
+
+#[test]
+fn test_execute_due_flows_needs_no_owner_signature() {
+    let env = Env::default();
+    let (client, recorder, owner) = setup_flow_schedules(&env);
+    let id = create_daily_schedule(&env, &client, &owner, 100_000);
+
+    // From here on no signature is mocked: the owner could not even cancel.
+    env.set_auths(&[]);
+    assert!(client.try_cancel_flow_schedule(&owner, &id).is_err());
+
+    // The orchestrator authorizes the downstream writes as the executor the
+    // owner registered, so the keeper run still goes through.
+    set_time(&env, SCHEDULE_START + DAY);
+    assert_eq!(client.execute_due_flows(), soroban_sdk::vec![&env, id]);
+    assert_eq!(
+        recorder.calls(&symbol_short!("goal")),
+        soroban_sdk::vec![&env, (1u32, 2500i128)]
+    );
+    assert_eq!(client.get_flow_schedule(&id).unwrap().spent, 10_000);
+}
+
+#[test]
+fn test_execute_due_flows_requires_owner_to_register_orchestrator() {
+    let env = Env::default();
+    let (client, recorder, _) = setup_flow_schedules(&env);
+    // Registered by another owner only.
+    let unregistered = Address::generate(&env);
+    let id = create_daily_schedule(&env, &client, &unregistered, 100_000);
+
+    set_time(&env, SCHEDULE_START + DAY);
+    assert_eq!(client.execute_due_flows().len(), 0);
+    assert_eq!(recorder.calls(&symbol_short!("goal")).len(), 0);
+    let schedule = client.get_flow_schedule(&id).unwrap();
+    assert!(schedule.active);
+    assert_eq!(schedule.spent, 0);
+}
+
+#[test]
+fn test_execute_due_flows_compensates_through_executor_reversals() {
+    let env = Env::default();
+    let (client, recorder, owner) = setup_flow_schedules(&env);
+    let goals = target_list(&env, AllocationRule::ProRata, &[(1, 1, 0, 0), (7, 1, 0, 0)]);
+    let one = TargetList::single(&env, 1);
+    let id = client.create_flow_schedule(
+        &owner,
+        &10_000,
+        &goals,
+        &one,
+        &one,
+        &(SCHEDULE_START + DAY),
+        &DAY,
+        &100_000,
+    );
+    recorder.set_down(&7, &true);
+
+    set_time(&env, SCHEDULE_START + DAY);
+    env.set_auths(&[]);
+    assert_eq!(client.execute_due_flows().len(), 0);
+    assert_eq!(
+        recorder.calls(&symbol_short!("rev_goal")),
+        soroban_sdk::vec![&env, (1u32, 1250i128)]
+    );
+    assert_eq!(client.get_flow_schedule(&id).unwrap().spent, 0);
+}
+
+// ---------------------------------------------------------------------------
+// Keeper rewards
+// ---------------------------------------------------------------------------
+
+/// Downstream mock whose `execute_due_*_schedules` entrypoints report
+/// `set_due(n)` executed schedules, or panic after `set_broken(true)`. Also
+/// accepts the delegated flow writes so scheduled flows can run against it.
+mod mock_schedule_executors {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Vec};
+
+    #[contract]
+    pub struct Contract;
+
+    #[contractimpl]
+    impl Contract {
+        pub fn calculate_split(env: Env, _total_amount: i128) -> Vec<i128> {
+            soroban_sdk::vec![&env, 2500i128, 2500i128, 2500i128, 2500i128]
+        }
+        pub fn add_to_goal_for(
+            _env: Env,
+            _executor: Address,
+            _owner: Address,
+            _goal_id: u32,
+            amount: i128,
+        ) -> i128 {
+            amount
+        }
+        pub fn pay_bill_for(
+            _env: Env,
+            _executor: Address,
+            _owner: Address,
+            _bill_id: u32,
+            _amount: i128,
+        ) {
+        }
+        pub fn pay_premium_for(
+            _env: Env,
+            _executor: Address,
+            _owner: Address,
+            _policy_id: u32,
+            _amount: i128,
+        ) {
+        }
+        pub fn set_due(env: Env, count: u32) {
+            env.storage().instance().set(&symbol_short!("due"), &count);
+        }
+        pub fn set_broken(env: Env, broken: bool) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("broken"), &broken);
+        }
+        pub fn execute_due_remittance_schedules(env: Env) -> Vec<u32> {
+            Self::executed(&env)
+        }
+        pub fn execute_due_savings_schedules(env: Env) -> Vec<u32> {
+            Self::executed(&env)
+        }
+        pub fn execute_due_bill_schedules(env: Env) -> Vec<u32> {
+            Self::executed(&env)
+        }
+        pub fn execute_due_premium_schedules(env: Env) -> Vec<u32> {
+            Self::executed(&env)
+        }
+    }
+
+    impl Contract {
+        fn executed(env: &Env) -> Vec<u32> {
+            if env
+                .storage()
+                .instance()
+                .get(&symbol_short!("broken"))
+                .unwrap_or(false)
+            {
+                panic!("executor failed");
+            }
+            let count: u32 = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("due"))
+                .unwrap_or(0);
+            let mut ids = Vec::new(env);
+            for id in 1..=count {
+                ids.push_back(id);
+            }
+            // Executed schedules are no longer due.
+            env.storage().instance().set(&symbol_short!("due"), &0u32);
+            ids
+        }
+    }
+}
+
+struct KeeperSetup<'a> {
+    client: OrchestratorClient<'a>,
+    owner: Address,
+    token: Address,
+    executors: std::vec::Vec<mock_schedule_executors::ContractClient<'a>>,
+}
+
+/// Orchestrator whose remittance split, savings, bills and insurance
+/// dependencies are schedule-executor mocks, plus a reward token.
+fn setup_keeper_orchestrator(env: &Env) -> KeeperSetup<'_> {
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    let owner = Address::generate(env);
+    let (_, client) = register_orchestrator(env);
+    let fw = env.register_contract(None, MockContract);
+    let executors: std::vec::Vec<_> = (0..4)
+        .map(|_| {
+            let id = env.register_contract(None, mock_schedule_executors::Contract);
+            mock_schedule_executors::ContractClient::new(env, &id)
+        })
+        .collect();
+    client.init(
+        &owner,
+        &fw,
+        &executors[0].address,
+        &executors[1].address,
+        &executors[2].address,
+        &executors[3].address,
+    );
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    KeeperSetup {
+        client,
+        owner,
+        token,
+        executors,
+    }
+}
+
+fn keeper_config(token: &Address, bounty: i128, per_ledger: u32, per_keeper: u32) -> KeeperConfig {
+    KeeperConfig {
+        reward_token: token.clone(),
+        bounty,
+        max_rewards_per_ledger: per_ledger,
+        max_rewards_per_keeper: per_keeper,
+    }
+}
+
+/// Configure a bounty of 10 and fund the treasury with `treasury` tokens.
+fn configure_keeper_rewards(
+    env: &Env,
+    setup: &KeeperSetup<'_>,
+    treasury: i128,
+    per_ledger: u32,
+    per_keeper: u32,
+) {
+    setup.client.set_keeper_config(
+        &setup.owner,
+        &keeper_config(&setup.token, 10, per_ledger, per_keeper),
+    );
+    let funder = Address::generate(env);
+    soroban_sdk::token::StellarAssetClient::new(env, &setup.token).mint(&funder, &treasury);
+    setup.client.fund_keeper_treasury(&funder, &treasury);
+}
+
+#[test]
+fn test_run_keeper_without_config_executes_but_pays_nothing() {
+    let env = Env::default();
+    let setup = setup_keeper_orchestrator(&env);
+    setup.executors[1].set_due(&2);
+    let keeper = Address::generate(&env);
+
+    let result = setup.client.run_keeper(&keeper);
+    assert_eq!(result.savings, 2);
+    assert_eq!(result.rewarded, 0);
+    assert_eq!(result.reward, 0);
+    assert_eq!(setup.client.get_pending_rewards(&keeper), 0);
+    assert!(!setup.client.get_execution_state());
+}
+
+#[test]
+fn test_run_keeper_accrues_bounty_from_treasury_and_can_be_claimed() {
+    let env = Env::default();
+    let setup = setup_keeper_orchestrator(&env);
+    configure_keeper_rewards(&env, &setup, 1_000, 50, 50);
+    for (executor, due) in setup.executors.iter().zip([1u32, 2, 3, 4]) {
+        executor.set_due(&due);
+    }
+    let keeper = Address::generate(&env);
+
+    let result = setup.client.run_keeper(&keeper);
+    assert_eq!(
+        result,
+        KeeperRunResult {
+            flows: 0,
+            remittances: 1,
+            savings: 2,
+            bills: 3,
+            premiums: 4,
+            rewarded: 10,
+            reward: 100,
+        }
+    );
+    assert_eq!(setup.client.get_pending_rewards(&keeper), 100);
+    assert_eq!(setup.client.get_keeper_treasury(), 900);
+
+    // Nothing left due: a second run executes and earns nothing.
+    assert_eq!(setup.client.run_keeper(&keeper).reward, 0);
+
+    assert_eq!(
+        setup
+            .client
+            .claim_rewards_summary_external(&keeper, &setup.token),
+        100
+    );
+    let token = soroban_sdk::token::Client::new(&env, &setup.token);
+    assert_eq!(token.balance(&keeper), 100);
+    assert_eq!(token.balance(&setup.client.address), 900);
+}
+
+#[test]
+fn test_run_keeper_counts_scheduled_flows() {
+    let env = Env::default();
+    let setup = setup_keeper_orchestrator(&env);
+    configure_keeper_rewards(&env, &setup, 1_000, 50, 50);
+    set_time(&env, SCHEDULE_START);
+    let owner = Address::generate(&env);
+    let id = setup.client.create_flow_schedule(
+        &owner,
+        &10_000,
+        &TargetList::single(&env, 1),
+        &TargetList::single(&env, 2),
+        &TargetList::single(&env, 3),
+        &(SCHEDULE_START + DAY),
+        &DAY,
+        &100_000,
+    );
+
+    set_time(&env, SCHEDULE_START + DAY);
+    let keeper = Address::generate(&env);
+    let result = setup.client.run_keeper(&keeper);
+    assert_eq!(result.flows, 1);
+    assert_eq!(result.reward, 10);
+    assert_eq!(
+        setup.client.get_flow_schedule(&id).unwrap().next_due,
+        SCHEDULE_START + 2 * DAY
+    );
+}
+
+#[test]
+fn test_run_keeper_enforces_per_ledger_limits() {
+    let env = Env::default();
+    let setup = setup_keeper_orchestrator(&env);
+    configure_keeper_rewards(&env, &setup, 1_000, 5, 3);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+
+    // Per-keeper limit: 4 executed, 3 rewarded.
+    setup.executors[0].set_due(&4);
+    let result = setup.client.run_keeper(&first);
+    assert_eq!((result.remittances, result.rewarded), (4, 3));
+
+    // The same keeper is at its limit for this ledger.
+    setup.executors[0].set_due(&1);
+    assert_eq!(setup.client.run_keeper(&first).rewarded, 0);
+
+    // Per-ledger limit: only 2 bounties remain for everyone else.
+    setup.executors[0].set_due(&3);
+    assert_eq!(setup.client.run_keeper(&second).rewarded, 2);
+    setup.executors[0].set_due(&1);
+    assert_eq!(setup.client.run_keeper(&third).rewarded, 0);
+
+    // A new ledger resets both limits.
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    setup.executors[0].set_due(&1);
+    assert_eq!(setup.client.run_keeper(&first).rewarded, 1);
+
+    assert_eq!(setup.client.get_pending_rewards(&first), 40);
+    assert_eq!(setup.client.get_pending_rewards(&second), 20);
+    assert_eq!(setup.client.get_pending_rewards(&third), 0);
+    assert_eq!(setup.client.get_keeper_treasury(), 940);
+}
+
+#[test]
+fn test_run_keeper_never_pays_more_than_the_treasury() {
+    let env = Env::default();
+    let setup = setup_keeper_orchestrator(&env);
+    configure_keeper_rewards(&env, &setup, 25, 50, 50);
+    setup.executors[2].set_due(&5);
+    let keeper = Address::generate(&env);
+
+    let result = setup.client.run_keeper(&keeper);
+    assert_eq!((result.bills, result.rewarded, result.reward), (5, 2, 20));
+    assert_eq!(setup.client.get_keeper_treasury(), 5);
+}
+
+#[test]
+fn test_run_keeper_treats_failing_executor_as_nothing_executed() {
+    let env = Env::default();
+    let setup = setup_keeper_orchestrator(&env);
+    configure_keeper_rewards(&env, &setup, 1_000, 50, 50);
+    setup.executors[1].set_due(&3);
+    setup.executors[1].set_broken(&true);
+    setup.executors[3].set_due(&2);
+    let keeper = Address::generate(&env);
+
+    let result = setup.client.run_keeper(&keeper);
+    assert_eq!((result.savings, result.premiums), (0, 2));
+    assert_eq!(result.reward, 20);
+    assert!(!setup.client.get_execution_state());
+}
+
+#[test]
+fn test_keeper_config_is_owner_only_and_validated() {
+    let env = Env::default();
+    let setup = setup_keeper_orchestrator(&env);
+    let funder = Address::generate(&env);
+
+    assert_eq!(setup.client.get_keeper_config(), None);
+    assert_eq!(
+        setup.client.try_fund_keeper_treasury(&funder, &100),
+        Err(Ok(OrchestratorError::KeeperNotConfigured))
+    );
+    assert_eq!(
+        setup.client.try_set_keeper_config(
+            &Address::generate(&env),
+            &keeper_config(&setup.token, 10, 5, 5)
+        ),
+        Err(Ok(OrchestratorError::Unauthorized))
+    );
+    for invalid in [
+        keeper_config(&setup.token, -1, 5, 5),
+        keeper_config(&setup.token, 10, 5, 0),
+        keeper_config(&setup.token, 10, 5, 6),
+    ] {
+        assert_eq!(
+            setup.client.try_set_keeper_config(&setup.owner, &invalid),
+            Err(Ok(OrchestratorError::InvalidKeeperConfig))
+        );
+    }
+
+    let config = keeper_config(&setup.token, 10, 5, 5);
+    assert!(setup.client.set_keeper_config(&setup.owner, &config));
+    assert_eq!(setup.client.get_keeper_config(), Some(config));
+    assert_eq!(
+        setup.client.try_fund_keeper_treasury(&funder, &0),
+        Err(Ok(OrchestratorError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_keeper_rewards_are_claimable_only_in_the_configured_token() {
+    let env = Env::default();
+    let setup = setup_keeper_orchestrator(&env);
+    configure_keeper_rewards(&env, &setup, 1_000, 50, 50);
+    setup.executors[0].set_due(&2);
+    let keeper = Address::generate(&env);
+    setup.client.run_keeper(&keeper);
+
+    // The orchestrator holds another token, e.g. sent to it by mistake.
+    let other = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &other).mint(&setup.client.address, &500);
+    assert_eq!(
+        setup
+            .client
+            .try_claim_rewards_summary_external(&keeper, &other),
+        Err(Ok(OrchestratorError::RewardTokenMismatch))
+    );
+    assert_eq!(setup.client.get_pending_rewards(&keeper), 20);
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &other).balance(&keeper),
+        0
+    );
+}
+
+#[test]
+fn test_keeper_reward_token_is_locked_while_balances_are_outstanding() {
+    let env = Env::default();
+    let setup = setup_keeper_orchestrator(&env);
+    configure_keeper_rewards(&env, &setup, 10, 50, 50);
+    let other = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let switched = keeper_config(&other, 10, 50, 50);
+
+    // Treasury funded.
+    assert_eq!(
+        setup.client.try_set_keeper_config(&setup.owner, &switched),
+        Err(Ok(OrchestratorError::KeeperRewardsOutstanding))
+    );
+
+    // Treasury drained into a pending accrual.
+    setup.executors[0].set_due(&1);
+    let keeper = Address::generate(&env);
+    setup.client.run_keeper(&keeper);
+    assert_eq!(setup.client.get_keeper_treasury(), 0);
+    assert_eq!(
+        setup.client.try_set_keeper_config(&setup.owner, &switched),
+        Err(Ok(OrchestratorError::KeeperRewardsOutstanding))
+    );
+
+    // Other settings may still change.
+    let retuned = keeper_config(&setup.token, 20, 10, 5);
+    assert!(setup.client.set_keeper_config(&setup.owner, &retuned));
+
+    // Once everything is claimed the token can change.
+    setup
+        .client
+        .claim_rewards_summary_external(&keeper, &setup.token);
+    assert!(setup.client.set_keeper_config(&setup.owner, &switched));
+    assert_eq!(setup.client.get_keeper_config(), Some(switched));
+}
+
+// ---------------------------------------------------------------------------
+// Converted flows
+// ---------------------------------------------------------------------------
+
+/// Constant-product AMM: `swap` returns
+/// `reserve_out * amount_in / (reserve_in + amount_in)` and panics below
+/// `min_out`. With `set_short_change(true)` it ignores `min_out` and returns
+/// one unit less, modelling a misbehaving pool.
+mod mock_amm {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    #[contract]
+    pub struct Contract;
+
+    #[contractimpl]
+    impl Contract {
+        pub fn set_reserves(env: Env, reserve_in: i128, reserve_out: i128) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("reserves"), &(reserve_in, reserve_out));
+        }
+        pub fn set_short_change(env: Env, short: bool) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("short"), &short);
+        }
+        pub fn swap(
+            env: Env,
+            trader: Address,
+            _token_in: Address,
+            _token_out: Address,
+            amount_in: i128,
+            min_out: i128,
+        ) -> i128 {
+            trader.require_auth();
+            if env
+                .storage()
+                .instance()
+                .get(&symbol_short!("short"))
+                .unwrap_or(false)
+            {
+                return min_out - 1;
+            }
+            let (reserve_in, reserve_out): (i128, i128) = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("reserves"))
+                .unwrap_or((1, 1));
+            let amount_out = reserve_out * amount_in / (reserve_in + amount_in);
+            if amount_out < min_out {
+                panic!("slippage");
+            }
+            env.storage().instance().set(
+                &symbol_short!("reserves"),
+                &(reserve_in + amount_in, reserve_out - amount_out),
+            );
+            amount_out
+        }
+    }
+}
+
+/// Downstream mock splitting 25% to each bucket (insurance takes the
+/// remainder) and publishing every write as a `(kind,)` `(id, amount)` event.
+mod mock_proportional_targets {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Vec};
+
+    #[contract]
+    pub struct Contract;
+
+    #[contractimpl]
+    impl Contract {
+        pub fn check_spending_limit(_env: Env, _user: Address, amount: i128) -> bool {
+            amount <= 1_000_000
+        }
+        pub fn calculate_split(env: Env, total_amount: i128) -> Vec<i128> {
+            let quarter = total_amount / 4;
+            soroban_sdk::vec![&env, quarter, quarter, quarter, total_amount - 3 * quarter]
+        }
+        pub fn add_to_goal(env: Env, _user: Address, goal_id: u32, amount: i128) {
+            env.events()
+                .publish((symbol_short!("goal"),), (goal_id, amount));
+        }
+        pub fn pay_bill(env: Env, _user: Address, bill_id: u32, amount: i128) {
+            env.events()
+                .publish((symbol_short!("bill"),), (bill_id, amount));
+        }
+        pub fn pay_premium(env: Env, _user: Address, policy_id: u32, amount: i128) {
+            env.events()
+                .publish((symbol_short!("policy"),), (policy_id, amount));
+        }
+    }
+}
+
+struct ConversionSetup<'a> {
+    client: OrchestratorClient<'a>,
+    amm: mock_amm::ContractClient<'a>,
+    downstream: Address,
+    caller: Address,
+}
+
+/// AMM with reserves of 1_000_000 source units to 500_000_000 destination
+/// units (about 500 destination units per source unit).
+fn setup_conversion(env: &Env) -> ConversionSetup<'_> {
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    let (_, client) = register_orchestrator(env);
+    let amm_id = env.register_contract(None, mock_amm::Contract);
+    let amm = mock_amm::ContractClient::new(env, &amm_id);
+    amm.set_reserves(&1_000_000, &500_000_000);
+    ConversionSetup {
+        client,
+        amm,
+        downstream: env.register_contract(None, mock_proportional_targets::Contract),
+        caller: Address::generate(env),
+    }
+}
+
+fn conversion(env: &Env, amm: &Address, min_out: i128) -> ConversionParams {
+    ConversionParams {
+        pool: amm.clone(),
+        token_in: Address::generate(env),
+        token_out: Address::generate(env),
+        min_out,
+    }
+}
+
+#[test]
+fn test_converted_flow_splits_the_converted_amount() {
+    let env = Env::default();
+    let setup = setup_conversion(&env);
+    let params = flow_params(&env, &setup.caller, &setup.downstream, 10_000);
+
+    // 500_000_000 * 10_000 / 1_010_000 = 4_950_495
+    let converted = setup
+        .client
+        .execute_converted_flow(&params, &conversion(&env, &setup.amm.address, 4_900_000));
+    assert_eq!(converted, 4_950_495);
+    assert_eq!(
+        recorded_events(&env, &setup.downstream, symbol_short!("goal")),
+        soroban_sdk::vec![&env, (1u32, 1_237_623i128)]
+    );
+    assert_eq!(
+        recorded_events(&env, &setup.downstream, symbol_short!("policy")),
+        soroban_sdk::vec![&env, (1u32, 1_237_626i128)]
+    );
+    assert!(!setup.client.get_execution_state());
+}
+
+#[test]
+fn test_converted_flow_reports_and_records_converted_units() {
+    let env = Env::default();
+    let setup = setup_conversion(&env);
+    set_time(&env, JAN_2024);
+    let params = flow_params(&env, &setup.caller, &setup.downstream, 10_000);
+
+    let converted = setup
+        .client
+        .execute_converted_flow(&params, &conversion(&env, &setup.amm.address, 1));
+    let ok_topic = remitwise_topic(&env, symbol_short!("flow_ok"));
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(cid, topics, _)| *cid == setup.client.address && *topics == ok_topic)
+        .unwrap();
+    assert_eq!(
+        <(Address, i128)>::from_val(&env, &data),
+        (setup.caller.clone(), converted)
+    );
+    let month = history(&setup.client, &setup.caller, 202401, 202401);
+    assert_eq!(month[0].1, converted);
+}
+
+#[test]
+fn test_converted_flow_checks_spending_limit_in_source_units() {
+    let env = Env::default();
+    let setup = setup_conversion(&env);
+
+    // 10_000 source units convert to several million destination units,
+    // well above the mock's 1_000_000 limit, but the limit applies pre-swap.
+    let params = flow_params(&env, &setup.caller, &setup.downstream, 10_000);
+    let amm = &setup.amm.address;
+    assert!(setup
+        .client
+        .try_execute_converted_flow(&params, &conversion(&env, amm, 1))
+        .is_ok());
+
+    let params = flow_params(&env, &setup.caller, &setup.downstream, 2_000_000);
+    assert_eq!(
+        setup
+            .client
+            .try_execute_converted_flow(&params, &conversion(&env, amm, 1)),
+        Err(Ok(OrchestratorError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_converted_flow_fails_when_pool_rejects_min_out() {
+    let env = Env::default();
+    let setup = setup_conversion(&env);
+    let params = flow_params(&env, &setup.caller, &setup.downstream, 10_000);
+
+    let result = setup
+        .client
+        .try_execute_converted_flow(&params, &conversion(&env, &setup.amm.address, 5_000_000));
+    assert_eq!(result, Err(Ok(OrchestratorError::CrossContractCallFailed)));
+    assert_eq!(
+        recorded_events(&env, &setup.downstream, symbol_short!("goal")).len(),
+        0
+    );
+    assert!(!setup.client.get_execution_state());
+}
+
+#[test]
+fn test_converted_flow_rechecks_min_out_returned_by_pool() {
+    let env = Env::default();
+    let setup = setup_conversion(&env);
+    setup.amm.set_short_change(&true);
+    let params = flow_params(&env, &setup.caller, &setup.downstream, 10_000);
+
+    let result = setup
+        .client
+        .try_execute_converted_flow(&params, &conversion(&env, &setup.amm.address, 4_900_000));
+    assert_eq!(result, Err(Ok(OrchestratorError::SlippageExceeded)));
+    assert_eq!(
+        recorded_events(&env, &setup.downstream, symbol_short!("goal")).len(),
+        0
+    );
+}
+
+#[test]
+fn test_converted_flow_rejects_invalid_conversion() {
+    let env = Env::default();
+    let setup = setup_conversion(&env);
+    let params = flow_params(&env, &setup.caller, &setup.downstream, 10_000);
+    let amm = &setup.amm.address;
+
+    let mut same_asset = conversion(&env, amm, 1);
+    same_asset.token_out = same_asset.token_in.clone();
+    let mut self_pool = conversion(&env, amm, 1);
+    self_pool.pool = setup.client.address.clone();
+
+    for invalid in [conversion(&env, amm, 0), same_asset, self_pool] {
+        assert_eq!(
+            setup.client.try_execute_converted_flow(&params, &invalid),
+            Err(Ok(OrchestratorError::InvalidConversion))
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Flow previews
+// ---------------------------------------------------------------------------
+
+/// `(step, amount, error)` for each previewed step.
+fn preview_steps(preview: &FlowPreview) -> std::vec::Vec<(FlowStep, i128, u32)> {
+    preview
+        .steps
+        .iter()
+        .map(|s| (s.step, s.amount, s.error))
+        .collect()
+}
+
+#[test]
+fn test_preview_remittance_flow_plans_targets_without_writing() {
+    let env = Env::default();
+    let (client, savings, _) = setup_recording_orchestrator(&env);
+    let caller = Address::generate(&env);
+    let mut params = flow_params(&env, &caller, &savings.address, 10_000);
+    params.goal_targets = target_list(
+        &env,
+        AllocationRule::ProRata,
+        &[(11, 3, 0, 0), (12, 1, 0, 0)],
+    );
+
+    let preview = client.preview_remittance_flow(&params);
+
+    assert!(preview.would_succeed);
+    assert_eq!(preview.error, 0);
+    assert_eq!(preview.spending_amount, 2500);
+    assert_eq!(
+        preview_steps(&preview),
+        std::vec![
+            (FlowStep::SpendingCheck, 10_000, 0),
+            (FlowStep::SplitCalculation, 10_000, 0),
+            (FlowStep::SavingsGoal, 2500, 0),
+            (FlowStep::BillPayment, 2500, 0),
+            (FlowStep::InsurancePremium, 2500, 0),
+        ]
+    );
+    let planned: std::vec::Vec<(FlowStep, u32, i128)> = preview
+        .targets
+        .iter()
+        .map(|t| (t.step, t.target_id, t.amount))
+        .collect();
+    assert_eq!(
+        planned,
+        std::vec![
+            (FlowStep::SavingsGoal, 11, 1875),
+            (FlowStep::SavingsGoal, 12, 625),
+            (FlowStep::BillPayment, 1, 2500),
+            (FlowStep::InsurancePremium, 1, 2500),
+        ]
+    );
+
+    // Nothing was written downstream, and the real flow matches the preview.
+    assert_eq!(savings.calls(&symbol_short!("goal")).len(), 0);
+    client.execute_remittance_flow(&params);
+    assert_eq!(
+        savings.calls(&symbol_short!("goal")),
+        soroban_sdk::vec![&env, (11u32, 1875i128), (12, 625)]
+    );
+}
+
+#[test]
+fn test_preview_remittance_flow_reports_every_failing_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = register_orchestrator(&env);
+    let denied = env.register_contract(None, mock_no_limit::Contract);
+    let caller = Address::generate(&env);
+    let mut params = flow_params(&env, &caller, &denied, 10_000);
+    params.bill_targets = target_list(&env, AllocationRule::Priority, &[]);
+
+    let preview = client.preview_remittance_flow(&params);
+
+    let unauthorized = OrchestratorError::Unauthorized as u32;
+    let invalid_list = OrchestratorError::InvalidTargetList as u32;
+    assert!(!preview.would_succeed);
+    assert_eq!(preview.error, unauthorized);
+    assert_eq!(
+        preview_steps(&preview),
+        std::vec![
+            (FlowStep::SpendingCheck, 10_000, unauthorized),
+            (FlowStep::SplitCalculation, 10_000, 0),
+            (FlowStep::SavingsGoal, 2500, 0),
+            (FlowStep::BillPayment, 2500, invalid_list),
+            (FlowStep::InsurancePremium, 2500, 0),
+        ]
+    );
+    // The invalid bill list plans no targets; the others are still shown.
+    assert_eq!(preview.targets.len(), 2);
+    assert_eq!(
+        client.try_execute_remittance_flow(&params),
+        Err(Ok(OrchestratorError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_preview_remittance_flow_stops_at_invalid_amount_or_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = register_orchestrator(&env);
+    let caller = Address::generate(&env);
+    let mock_id = env.register_contract(None, MockContract);
+
+    let preview = client.preview_remittance_flow(&flow_params(&env, &caller, &mock_id, 0));
+    assert_eq!(preview.error, OrchestratorError::InvalidAmount as u32);
+    assert!(preview.steps.is_empty());
+
+    let negative = env.register_contract(None, mock_split_negative::Contract);
+    let preview = client.preview_remittance_flow(&flow_params(&env, &caller, &negative, 10_000));
+    let invalid_amount = OrchestratorError::InvalidAmount as u32;
+    assert_eq!(preview.error, invalid_amount);
+    assert_eq!(
+        preview_steps(&preview),
+        std::vec![
+            (FlowStep::SpendingCheck, 10_000, 0),
+            (FlowStep::SplitCalculation, 10_000, invalid_amount),
+        ]
+    );
+    assert!(preview.targets.is_empty());
+
+    // A split contract that cannot be called is a predicted call failure.
+    let mut params = flow_params(&env, &caller, &mock_id, 10_000);
+    params.remittance_split = env.register_contract(None, FailingMock);
+    let preview = client.preview_remittance_flow(&params);
+    assert_eq!(
+        preview.error,
+        OrchestratorError::CrossContractCallFailed as u32
+    );
+}
+
+#[test]
+fn test_preview_flow_fanout_uses_routing_profile_without_spending_check() {
+    let env = Env::default();
+    let (client, savings, _) = setup_recording_orchestrator(&env);
+    let user = Address::generate(&env);
+    client.set_routing_profile(
+        &user,
+        &TargetList::single(&env, 11),
+        &TargetList::single(&env, 21),
+        &TargetList::single(&env, 31),
+    );
+
+    let preview = client.preview_flow_fanout(&user, &10_000);
+
+    assert!(preview.would_succeed);
+    assert_eq!(
+        preview.steps.get(0).unwrap().step,
+        FlowStep::SplitCalculation
+    );
+    let ids: std::vec::Vec<u32> = preview.targets.iter().map(|t| t.target_id).collect();
+    assert_eq!(ids, std::vec![11, 21, 31]);
+    assert_eq!(savings.calls(&symbol_short!("goal")).len(), 0);
+
+    // An uninitialized orchestrator cannot resolve routing.
+    let (_, uninitialized) = register_orchestrator(&env);
+    assert_eq!(
+        uninitialized.preview_flow_fanout(&user, &10_000).error,
+        OrchestratorError::InvalidDependency as u32
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Remittance history
+// ---------------------------------------------------------------------------
+
+const JAN_2024: u64 = 1_704_067_200;
+const FEB_2024: u64 = 1_706_745_600;
+
+/// `(period_key, received, spending, savings, bills, insurance, flow_count)`
+/// for each recorded month.
+fn history(
+    client: &OrchestratorClient,
+    owner: &Address,
+    from: u64,
+    to: u64,
+) -> std::vec::Vec<(u64, i128, i128, i128, i128, i128, u32)> {
+    client
+        .get_remittance_history(owner, &from, &to)
+        .iter()
+        .map(|p| {
+            (
+                p.period_key,
+                p.received,
+                p.spending,
+                p.savings,
+                p.bills,
+                p.insurance,
+                p.flow_count,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_remittance_history_records_completed_flows_per_month() {
+    let env = Env::default();
+    let (client, savings, _) = setup_recording_orchestrator(&env);
+    let caller = Address::generate(&env);
+
+    set_time(&env, JAN_2024);
+    client.execute_remittance_flow(&flow_params(&env, &caller, &savings.address, 10_000));
+    set_time(&env, JAN_2024 + 86_400);
+    client.execute_remittance_flow(&flow_params(&env, &caller, &savings.address, 10_000));
+    // A failed flow is rolled back and leaves no history.
+    let mut failing = flow_params(&env, &caller, &savings.address, 10_000);
+    failing.bill_targets = TargetList::single(&env, mock_recording_targets::FAILING_ID);
+    assert!(client.try_execute_remittance_flow(&failing).is_err());
+    set_time(&env, FEB_2024);
+    client.execute_remittance_flow(&flow_params(&env, &caller, &savings.address, 10_000));
+
+    assert_eq!(
+        history(&client, &caller, 202401, 202402),
+        std::vec![
+            (202401, 20_000, 5_000, 5_000, 5_000, 5_000, 2),
+            (202402, 10_000, 2_500, 2_500, 2_500, 2_500, 1),
+        ]
+    );
+    assert_eq!(history(&client, &caller, 202402, 202412).len(), 1);
+    assert!(history(&client, &Address::generate(&env), 0, u64::MAX).is_empty());
+}
+
+#[test]
+fn test_remittance_history_counts_only_successful_fanout_targets() {
+    let env = Env::default();
+    let (client, _, _) = setup_recording_orchestrator(&env);
+    let user = Address::generate(&env);
+    client.set_routing_profile(
+        &user,
+        &TargetList::single(&env, 1),
+        &TargetList::single(&env, mock_recording_targets::FAILING_ID),
+        &TargetList::single(&env, 1),
+    );
+    set_time(&env, JAN_2024);
+
+    assert!(!client.execute_flow_fanout(&user, &10_000).all_succeeded);
+
+    assert_eq!(
+        history(&client, &user, 202401, 202401),
+        std::vec![(202401, 10_000, 2_500, 2_500, 0, 2_500, 1)]
+    );
+}
+
+#[test]
+fn test_remittance_history_follows_resumable_flow_steps() {
+    let env = Env::default();
+    set_time(&env, JAN_2024);
+    let (client, recorder, record) = start_flow_with_paused_bill(&env);
+    let caller = record.params.caller.clone();
+
+    // The paused bill is recorded in the month it is finally paid.
+    recorder.set_down(&PAUSED_BILL, &false);
+    set_time(&env, FEB_2024);
+    client.resume_flow(&caller, &record.id);
+
+    assert_eq!(
+        history(&client, &caller, 202401, 202402),
+        std::vec![
+            (202401, 10_000, 2_500, 2_500, 0, 2_500, 1),
+            (202402, 0, 0, 0, 2_500, 0, 0),
+        ]
+    );
+
+    // Aborting a flow nets out the payments it reverses.
+    let env = Env::default();
+    set_time(&env, JAN_2024);
+    let (client, _, record) = start_flow_with_paused_bill(&env);
+    let caller = record.params.caller.clone();
+    client.abort_flow(&caller, &record.id);
+
+    assert_eq!(
+        history(&client, &caller, 202401, 202401),
+        std::vec![(202401, 10_000, 2_500, 0, 0, 0, 1)]
+    );
+}
@@ -12,7 +12,7 @@ mod tests_safe_math;
 
 use remitwise_common::{
     clamp_limit, guard_bytes_len, verify_no_dust, EventCategory, EventPriority, RemitwiseEvents,
-    Timestamp, ToI128Checked,
+    SplitInfo, SplitUnit, Timestamp, ToI128Checked, SPLIT_INFO_VERSION,
 };
 
 use soroban_sdk::{
@@ -105,6 +105,8 @@ pub enum RemittanceSplitError {
     BatchSizeExceeded = 41,
     /// A `set_min_deposit` value is below `params::MIN_CORRIDOR_AMOUNT`.
     InvalidMinDeposit = 42,
+    /// `propose_treasury` named this contract, which can never accept the role.
+    InvalidTreasuryAddress = 43,
 }
 
 #[derive(Clone)]
@@ -1371,7 +1373,8 @@ impl RemittanceSplit {
     /// Get the current split configuration
     ///
     /// # Returns
-    /// Vec containing [spending, savings, bills, insurance] percentages
+    /// Vec containing [spending, savings, bills, insurance] percentages in basis
+    /// points (summing to 10_000)
     pub fn get_split(env: &Env) -> Vec<u32> {
         Self::extend_instance_ttl(env);
         // Derive split percentages from the canonical CONFIG key (single source of truth).
@@ -1392,6 +1395,23 @@ impl RemittanceSplit {
             .unwrap_or_else(|| vec![&env, 5000, 3000, 1500, 500])
     }
 
+    /// Get the current split configuration together with its unit.
+    ///
+    /// Prefer this over [`Self::get_split`] in cross-contract callers: the
+    /// returned [`SplitInfo`] carries a layout `version` and the `unit` of
+    /// `values`, so consumers can normalise instead of assuming a scale.
+    ///
+    /// # Returns
+    /// `SplitInfo` with `values` = [spending, savings, bills, insurance] in
+    /// basis points (`SplitUnit::BasisPoints`, summing to 10_000)
+    pub fn get_split_info(env: Env) -> SplitInfo {
+        SplitInfo {
+            version: SPLIT_INFO_VERSION,
+            unit: SplitUnit::BasisPoints,
+            values: Self::get_split(&env),
+        }
+    }
+
     pub fn get_config(env: Env) -> Option<SplitConfig> {
         Self::extend_instance_ttl(&env);
         env.storage().instance().get(&symbol_short!("CONFIG"))
@@ -3392,3 +3392,29 @@ fn test_batch_transfer_rejects_replayed_nonce() {
     );
     assert_eq!(result, Err(Ok(RemittanceSplitError::InvalidNonce)));
 }
+
+#[test]
+fn test_get_split_info_reports_basis_points() {
+    let env = Env::default();
+    let harness = setup_split(&env, 4_000, 3_000, 2_000, 1_000);
+
+    let info = harness.client.get_split_info();
+    assert_eq!(info.version, SPLIT_INFO_VERSION);
+    assert_eq!(info.unit, SplitUnit::BasisPoints);
+    assert_eq!(info.values, harness.client.get_split());
+    assert_eq!(info.unit.scale(), info.values.iter().sum::<u32>());
+}
+
+#[test]
+fn test_calculate_split_treats_split_as_basis_points() {
+    let env = Env::default();
+    let harness = setup_split(&env, 4_000, 3_000, 2_000, 1_000);
+
+    let amounts = harness.client.calculate_split(&10_000);
+    assert_eq!(amounts, vec![&env, 4_000i128, 3_000, 2_000, 1_000]);
+
+    // Regression: dividing basis points by 100 scaled every share up 100x
+    // and left insurance with a large negative remainder.
+    let amounts = harness.client.calculate_split(&999);
+    assert_eq!(amounts, vec![&env, 399i128, 299, 199, 102]);
+}
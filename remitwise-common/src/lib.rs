@@ -16,6 +16,11 @@ pub use tokens::{
 /// CI picks them up with `cargo test -p remitwise-common`.
 pub mod period;
 
+/// Price-oracle client and quote validation for multi-currency reporting:
+/// [`oracle::fetch_price`] and [`oracle::convert_amount`].
+pub mod oracle;
+pub use oracle::{OracleConfig, OracleError, PriceData, PriceOracleClient};
+
 #[soroban_sdk::contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -40,6 +45,41 @@ pub enum Category {
     Insurance = 4,
 }
 
+/// Current version of the [`SplitInfo`] layout returned by
+/// `remittance_split::get_split_info`.
+pub const SPLIT_INFO_VERSION: u32 = 1;
+
+/// Unit in which split percentages are expressed.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SplitUnit {
+    /// Whole percents; the four values sum to 100.
+    Percent = 1,
+    /// Basis points; the four values sum to 10_000.
+    BasisPoints = 2,
+}
+
+impl SplitUnit {
+    /// The value the four split entries must sum to in this unit.
+    pub fn scale(self) -> u32 {
+        match self {
+            SplitUnit::Percent => 100,
+            SplitUnit::BasisPoints => 10_000,
+        }
+    }
+}
+
+/// Self-describing split configuration: `values` are ordered
+/// `[spending, savings, bills, insurance]` and expressed in `unit`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitInfo {
+    pub version: u32,
+    pub unit: SplitUnit,
+    pub values: soroban_sdk::Vec<u32>,
+}
+
 /// Family roles for access control
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -64,7 +104,7 @@ pub enum CoverageType {
 }
 
 /// Policy mode for access control
-#[contracttype]
+#[contracttype(export = false)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum PolicyMode {
@@ -141,7 +181,7 @@ pub const DEFAULT_PAGE_LIMIT: u32 = 20;
 pub const MAX_PAGE_LIMIT: u32 = 50;
 
 /// Typed error returned when a pagination limit is invalid or exceeds `MAX_PAGE_LIMIT`.
-#[soroban_sdk::contracterror]
+#[soroban_sdk::contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum PageLimitError {
@@ -279,7 +319,7 @@ pub const MAX_BYTES_RETURN: u32 = 8192;
 
 /// Error returned when a `Bytes` value about to leave a contract entry point
 /// exceeds [`MAX_BYTES_RETURN`].
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum BytesReturnError {
@@ -319,7 +359,7 @@ pub struct RemitwiseEvents;
 /// constants.
 ///
 /// # Errors
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum SymbolError {
@@ -365,7 +405,7 @@ pub fn guard_bytes_len(bytes: &Bytes) -> Result<(), BytesReturnError> {
 ///
 /// # Returns
 /// * `Ok(())` if the epoch is greater than or equal to the current pending dispute epoch
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum DisputeError {
@@ -386,7 +426,7 @@ pub fn require_no_pending_dispute_epoch(env: &Env, ep: u64) -> Result<(), Disput
 }
 
 /// Typed error returned when a caller supplies an outdated cross-contract epoch.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum CrossContractEpochError {
@@ -430,7 +470,7 @@ pub fn require_matching_cross_contract_epoch(
 // ---------------------------------------------------------------------------
 
 /// Error returned when a `BytesN` value is completely zeroed.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum BytesNError {
@@ -470,7 +510,7 @@ pub fn require_non_zero_bytes<const N: usize>(bytes: &BytesN<N>) -> Result<(), B
 pub const SYMBOL_SHORT_MAX_LEN: u32 = 9;
 
 /// Error returned when a candidate symbol name fails the length check enforced    /// by [`require_valid_symbol_name_length`].
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum SymbolLengthError {
@@ -523,7 +563,7 @@ pub const SNAPSHOT_MAX_AGE_SECS: u64 = 30 * 24 * 60 * 60;
 pub const SNAPSHOT_KEY: Symbol = symbol_short!("SNAPSHOT");
 
 /// Typed error returned when a pre-upgrade snapshot is older than the freshness window.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum SnapshotError {
@@ -544,7 +584,7 @@ pub fn require_recent_snapshot(env: &Env, snapshot_taken_at: u64) -> Result<(),
 pub const MAX_SETTLEMENT_WINDOW_SECS: u64 = 30 * 24 * 60 * 60; // 30 days
 
 /// Typed error returned when settlement occurs outside the acceptable window
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum SettlementWindowError {
@@ -581,7 +621,7 @@ pub const RATE_LIMIT_WINDOW_SECONDS: u64 = 86400; // 24 hours
 const STORAGE_RATE_LIMIT: Symbol = symbol_short!("RATE_LIM");
 
 /// Rate limit record: stores count per address + operation + window
-#[contracttype]
+#[contracttype(export = false)]
 #[derive(Clone)]
 pub struct RateLimitRecord {
     pub count: u32,
@@ -589,7 +629,7 @@ pub struct RateLimitRecord {
 }
 
 /// Error for operator validation
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum OperatorError {
@@ -645,8 +685,60 @@ pub fn require_registered_operator(env: &Env, caller: &Address) -> Result<(), Op
     Ok(())
 }
 
+/// Error for owner-delegated executor validation
+#[contracterror(export = false)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExecutorError {
+    NotAuthorized = 98,
+}
+
+const STORAGE_EXECUTORS: Symbol = symbol_short!("EXECUTOR");
+
+/// Records whether `owner` lets `executor` act on their records.
+///
+/// Unlike operators, executors are granted per owner: an executor registered
+/// by one owner cannot touch another owner's records. Does not enforce
+/// authentication — the calling contract must require `owner`'s auth first.
+pub fn set_authorized_executor(env: &Env, owner: &Address, executor: &Address, authorized: bool) {
+    let key = (STORAGE_EXECUTORS, owner.clone(), executor.clone());
+    if authorized {
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Whether `owner` has registered `executor` via [`set_authorized_executor`].
+pub fn is_authorized_executor(env: &Env, owner: &Address, executor: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&(STORAGE_EXECUTORS, owner.clone(), executor.clone()))
+        .unwrap_or(false)
+}
+
+/// Enforce that `owner` has registered `executor`.
+///
+/// The calling contract must still require `executor`'s auth and check that
+/// the record being touched belongs to `owner`.
+pub fn require_authorized_executor(
+    env: &Env,
+    owner: &Address,
+    executor: &Address,
+) -> Result<(), ExecutorError> {
+    if !is_authorized_executor(env, owner, executor) {
+        return Err(ExecutorError::NotAuthorized);
+    }
+    Ok(())
+}
+
 /// Error for missing required environment/configuration variable.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum EnvVarError {
@@ -766,7 +858,7 @@ pub fn get_rate_limit_status(env: &Env, caller: &Address, operation: Symbol) ->
 
 /// Typed error returned by [`verify_no_dust`], distinguishing *why* an amount
 /// was rejected instead of collapsing every case into an opaque `()`.
-#[soroban_sdk::contracterror]
+#[soroban_sdk::contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum AmountError {
@@ -1332,7 +1424,7 @@ pub enum RateUnit {
 }
 
 /// Error returned when an externally supplied rate unit is unsupported.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum RateUnitError {
@@ -1531,11 +1623,13 @@ impl ToI128Checked for Rate {
 }
 
 /// Error related to time and periods.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum TimeError {
     InvalidPeriod = 7,
+    /// The supplied timestamp is not strictly after the current ledger time.
+    TimestampNotInFuture = 8,
 }
 
 /// Namespace for shared timestamp helpers.
@@ -1543,6 +1637,7 @@ pub enum TimeError {
 pub struct Timestamp;
 
 /// Enumeration of period bucket types for timestamp bucketing.
+#[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum PeriodKind {
     Day,
@@ -1600,6 +1695,36 @@ impl Timestamp {
             }
         }
     }
+
+    /// Returns the first second (UTC) of the period identified by `key`.
+    ///
+    /// Inverse of [`Timestamp::to_period_key`]: for every timestamp `t`,
+    /// `period_start(to_period_key(t, p), p) <= t`. Month keys must be valid
+    /// `YYYYMM` values at or after 197001; other keys saturate at `0` or
+    /// `u64::MAX` rather than panicking.
+    pub fn period_start(key: u64, period: PeriodKind) -> u64 {
+        match period {
+            PeriodKind::Day => key.saturating_mul(SECONDS_PER_DAY),
+            PeriodKind::Week => key.saturating_mul(SECONDS_PER_WEEK),
+            PeriodKind::Month => {
+                // days_from_civil from the same reference as `to_period_key`.
+                let month = (key % 100).clamp(1, 12) as i64;
+                let year = (key / 100).min(i64::MAX as u64 / 2) as i64;
+                let y = if month <= 2 { year - 1 } else { year };
+                let era = y.div_euclid(400);
+                let yoe = y - era * 400; // [0, 399]
+                let mp = (month + 9) % 12; // March = 0
+                let doy = (153 * mp + 2) / 5; // first day of the month
+                let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+                let days = era.saturating_mul(146097).saturating_add(doe - 719468);
+                if days <= 0 {
+                    0
+                } else {
+                    (days as u64).saturating_mul(SECONDS_PER_DAY)
+                }
+            }
+        }
+    }
 }
 
 /// Validates that a requested period is logically ordered.
@@ -1614,8 +1739,20 @@ pub fn validate_period(start: u64, end: u64) -> Result<(), TimeError> {
     }
 }
 
+/// Validates that `timestamp` lies strictly after the current ledger time.
+///
+/// # Errors
+/// Returns `TimeError::TimestampNotInFuture` if `timestamp <= ledger.timestamp()`.
+pub fn require_future_timestamp(env: &Env, timestamp: u64) -> Result<(), TimeError> {
+    if timestamp <= env.ledger().timestamp() {
+        Err(TimeError::TimestampNotInFuture)
+    } else {
+        Ok(())
+    }
+}
+
 /// Error returned when the current ledger sequence does not match the expected value.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum LedgerError {
@@ -1856,7 +1993,7 @@ pub enum TagError {
 }
 
 /// Signature verification failure.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum SignatureError {
@@ -1993,7 +2130,7 @@ pub fn verify_signature(
 }
 
 /// Typed error for slash signature verification.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum SlashError {
@@ -2189,7 +2326,7 @@ pub mod events;
 pub mod reversible_op;
 
 /// Error returned when a currency symbol is not a supported stable asset.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum StableCurrencyError {
@@ -2689,7 +2826,7 @@ pub(crate) const STORAGE_INVESTIGATION_EPOCH: Symbol = symbol_short!("INV_EPOCH"
 
 /// Error returned when a write operation is blocked because an
 /// investigation epoch is active.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum InvestigationEpochError {
@@ -2782,7 +2919,7 @@ pub(crate) const STORAGE_KILL_SWITCH: Symbol = symbol_short!("KILL_SW");
 
 /// Error returned when a write operation is blocked because the kill switch
 /// is active.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum KillSwitchError {
@@ -3609,7 +3746,7 @@ const RESERVED_KEY_PREFIXES: &[&[u8]] = &[
 ///
 /// Calling `verify_storage_key_reserved` at the entry point that receives a
 /// caller-supplied key rejects the write before it reaches storage.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum ReservedKeyError {
@@ -3801,7 +3938,7 @@ pub const STORAGE_UPGRADE_EPOCH: Symbol = symbol_short!("UPGR_EP");
 ///
 /// Rejecting any epoch that does not exactly match the current stored value
 /// closes both vectors at once.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum UpgradeEpochError {
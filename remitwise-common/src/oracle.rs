@@ -0,0 +1,213 @@
+//! Price-oracle integration for expressing multi-currency figures in a single
+//! reporting currency.
+//!
+//! Contracts that aggregate amounts denominated in different currencies (for
+//! example bills in `"NGN"` and `"KES"`) store an admin-configured
+//! [`OracleConfig`] and fetch quotes through [`PriceOracleClient`] using
+//! [`fetch_price`], which rejects stale, future-dated and non-positive quotes.
+//! [`convert_amount`] then applies a quote with checked arithmetic.
+//!
+//! Any oracle adapter implementing [`PriceOracle`] can be configured; the
+//! trait is deliberately minimal so existing oracle deployments can be wrapped
+//! by a thin adapter contract.
+
+use soroban_sdk::{contractclient, contracterror, contracttype, Address, Env, String};
+
+/// Largest staleness window an [`OracleConfig`] may allow (one day).
+pub const MAX_ORACLE_PRICE_AGE_SECS: u64 = 86_400;
+
+/// Largest number of decimals a [`PriceData`] quote may use.
+pub const MAX_PRICE_DECIMALS: u32 = 18;
+
+/// Errors returned by oracle configuration and price validation.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OracleError {
+    /// The oracle has no quote for the pair, or the oracle call failed.
+    PriceUnavailable = 1,
+    /// The quote is older than the configured `max_age_secs`, or dated in the
+    /// future.
+    StalePrice = 2,
+    /// The quote's price is not positive or its decimals exceed
+    /// [`MAX_PRICE_DECIMALS`].
+    InvalidPrice = 3,
+    /// Converting an amount with the quote overflowed `i128`.
+    Overflow = 4,
+    /// `max_age_secs` is zero or above [`MAX_ORACLE_PRICE_AGE_SECS`].
+    InvalidConfig = 5,
+}
+
+/// A quote converting minor units of a base currency into minor units of a
+/// quote currency: `quote_amount = base_amount * price / 10^decimals`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub decimals: u32,
+    /// Ledger timestamp at which the oracle observed the price.
+    pub timestamp: u64,
+}
+
+/// Oracle address and staleness bound, set by a contract's admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleConfig {
+    pub oracle: Address,
+    /// Maximum age, in seconds, of a quote accepted by [`fetch_price`].
+    pub max_age_secs: u64,
+}
+
+/// Interface an oracle (or oracle adapter) contract must implement.
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    /// Latest quote for converting `base` into `quote`, or `None` when the
+    /// pair is not supported.
+    fn get_price(env: Env, base: String, quote: String) -> Option<PriceData>;
+}
+
+/// Validate an [`OracleConfig`] before it is stored.
+pub fn validate_oracle_config(config: &OracleConfig) -> Result<(), OracleError> {
+    if config.max_age_secs == 0 || config.max_age_secs > MAX_ORACLE_PRICE_AGE_SECS {
+        return Err(OracleError::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Require `price` to be positive, use at most [`MAX_PRICE_DECIMALS`], and be
+/// no older than `max_age_secs` relative to the current ledger timestamp.
+pub fn require_fresh_price(
+    env: &Env,
+    price: &PriceData,
+    max_age_secs: u64,
+) -> Result<(), OracleError> {
+    if price.price <= 0 || price.decimals > MAX_PRICE_DECIMALS {
+        return Err(OracleError::InvalidPrice);
+    }
+    let now = env.ledger().timestamp();
+    if price.timestamp > now || now - price.timestamp > max_age_secs {
+        return Err(OracleError::StalePrice);
+    }
+    Ok(())
+}
+
+/// Fetch and validate the quote converting `base` into `quote`.
+///
+/// Identical currencies short-circuit to a unit quote timestamped now, without
+/// calling the oracle.
+pub fn fetch_price(
+    env: &Env,
+    config: &OracleConfig,
+    base: &String,
+    quote: &String,
+) -> Result<PriceData, OracleError> {
+    if base == quote {
+        return Ok(PriceData {
+            price: 1,
+            decimals: 0,
+            timestamp: env.ledger().timestamp(),
+        });
+    }
+    let price = match PriceOracleClient::new(env, &config.oracle).try_get_price(base, quote) {
+        Ok(Ok(Some(price))) => price,
+        _ => return Err(OracleError::PriceUnavailable),
+    };
+    require_fresh_price(env, &price, config.max_age_secs)?;
+    Ok(price)
+}
+
+/// Convert `amount` with `price`, rounding toward zero.
+pub fn convert_amount(amount: i128, price: &PriceData) -> Result<i128, OracleError> {
+    let scale = 10i128
+        .checked_pow(price.decimals)
+        .ok_or(OracleError::InvalidPrice)?;
+    amount
+        .checked_mul(price.price)
+        .map(|scaled| scaled / scale)
+        .ok_or(OracleError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    fn quote(price: i128, decimals: u32, timestamp: u64) -> PriceData {
+        PriceData {
+            price,
+            decimals,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn convert_amount_applies_scaled_price() {
+        // 1 USD = 1_550.25 NGN
+        let usd_ngn = quote(155_025, 2, 0);
+        assert_eq!(convert_amount(100, &usd_ngn), Ok(155_025));
+        assert_eq!(convert_amount(-3, &usd_ngn), Ok(-4_650));
+        assert_eq!(
+            convert_amount(i128::MAX, &usd_ngn),
+            Err(OracleError::Overflow)
+        );
+    }
+
+    #[test]
+    fn require_fresh_price_enforces_age_and_sign() {
+        let env = Env::default();
+        env.ledger().with_mut(|l| l.timestamp = 10_000);
+
+        assert_eq!(require_fresh_price(&env, &quote(5, 0, 9_400), 600), Ok(()));
+        assert_eq!(
+            require_fresh_price(&env, &quote(5, 0, 9_399), 600),
+            Err(OracleError::StalePrice)
+        );
+        assert_eq!(
+            require_fresh_price(&env, &quote(5, 0, 10_001), 600),
+            Err(OracleError::StalePrice)
+        );
+        assert_eq!(
+            require_fresh_price(&env, &quote(0, 0, 10_000), 600),
+            Err(OracleError::InvalidPrice)
+        );
+        assert_eq!(
+            require_fresh_price(&env, &quote(5, MAX_PRICE_DECIMALS + 1, 10_000), 600),
+            Err(OracleError::InvalidPrice)
+        );
+    }
+
+    #[test]
+    fn validate_oracle_config_bounds_max_age() {
+        let env = Env::default();
+        let config = |max_age_secs| OracleConfig {
+            oracle: Address::generate(&env),
+            max_age_secs,
+        };
+        assert_eq!(validate_oracle_config(&config(3_600)), Ok(()));
+        assert_eq!(
+            validate_oracle_config(&config(0)),
+            Err(OracleError::InvalidConfig)
+        );
+        assert_eq!(
+            validate_oracle_config(&config(MAX_ORACLE_PRICE_AGE_SECS + 1)),
+            Err(OracleError::InvalidConfig)
+        );
+    }
+
+    #[test]
+    fn fetch_price_short_circuits_same_currency() {
+        let env = Env::default();
+        env.ledger().with_mut(|l| l.timestamp = 42);
+        let config = OracleConfig {
+            oracle: Address::generate(&env),
+            max_age_secs: 60,
+        };
+        let ngn = String::from_str(&env, "NGN");
+        assert_eq!(fetch_price(&env, &config, &ngn, &ngn), Ok(quote(1, 0, 42)));
+        // A different pair reaches the (unregistered) oracle and fails.
+        assert_eq!(
+            fetch_price(&env, &config, &ngn, &String::from_str(&env, "USD")),
+            Err(OracleError::PriceUnavailable)
+        );
+    }
+}
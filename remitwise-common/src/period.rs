@@ -13,7 +13,7 @@
 use soroban_sdk::contracterror;
 
 /// Errors returned by period-key validation.
-#[contracterror]
+#[contracterror(export = false)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum PeriodKeyError {
     /// The entities belong to different periods.
@@ -1071,6 +1071,34 @@ fn test_period_key_month_bucket_epoch_and_dec_2023() {
     );
 }
 
+#[test]
+fn test_period_start_inverts_period_key() {
+    assert_eq!(Timestamp::period_start(197001, PeriodKind::Month), 0);
+    assert_eq!(
+        Timestamp::period_start(202401, PeriodKind::Month),
+        1704067200
+    );
+    assert_eq!(
+        Timestamp::period_start(202003, PeriodKind::Month),
+        1583020800
+    );
+    assert_eq!(Timestamp::period_start(1, PeriodKind::Day), 86400);
+    assert_eq!(Timestamp::period_start(1, PeriodKind::Week), 604800);
+
+    for period in [PeriodKind::Day, PeriodKind::Week, PeriodKind::Month] {
+        for t in (1_580_000_000u64..1_720_000_000).step_by(2_345_678) {
+            let key = Timestamp::to_period_key(t, period);
+            let start = Timestamp::period_start(key, period);
+            assert!(start <= t);
+            assert_eq!(Timestamp::to_period_key(start, period), key);
+            assert_ne!(
+                Timestamp::to_period_key(start.saturating_sub(1), period),
+                key
+            );
+        }
+    }
+}
+
 #[test]
 fn test_period_key_exact_rollover_edges() {
     // Midnight UTC at 2021-02-28 to Mar 1st transition, and leap-year
@@ -50,7 +50,7 @@ pub const MAX_CURRENCY_LEN: u32 = 10;
 /// *Adding a token*: append a new `#[repr(u32)]` variant **at the end** to
 /// preserve discriminant stability across upgrades. The compiler will then
 /// require every exhaustive `match` in the workspace to handle the new case.
-#[contracttype]
+#[contracttype(export = false)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum SupportedToken {
@@ -39,8 +39,25 @@ fn report_event_variant_set_is_stable() {
         ReportEvent::AddressesConfigured,
         ReportEvent::ReportsArchived,
         ReportEvent::ArchivesCleaned,
+        ReportEvent::OracleConfigured,
+        ReportEvent::HistorySourceConfigured,
+        ReportEvent::HealthScoreModelUpdated,
+        ReportEvent::SnapshotKeeperConfigured,
+        ReportEvent::HealthSnapshotCaptured,
+        ReportEvent::AttestationIssued,
+        ReportEvent::AttestationShared,
+        ReportEvent::AttestationRevoked,
+        ReportEvent::AccessGranted,
+        ReportEvent::AccessRevoked,
+        ReportEvent::AnomalyConfigUpdated,
+        ReportEvent::CoverageGapConfigUpdated,
+        ReportEvent::ReportSubscribed,
+        ReportEvent::ReportUnsubscribed,
+        ReportEvent::RetentionPolicyUpdated,
+        ReportEvent::GrantAuthorityConfigured,
+        ReportEvent::ReportInstanceLinked,
     ];
-    assert_eq!(variants.len(), 5, "ReportEvent variant count drifted");
+    assert_eq!(variants.len(), 22, "ReportEvent variant count drifted");
 
     for v in variants {
         // Each variant must serialize cleanly so the topic
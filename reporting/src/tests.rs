@@ -8,8 +8,8 @@ use soroban_sdk::{
 use testutils::{same_address, set_ledger_time};
 
 use crate::{
-    Category, ContractAddresses, DataAvailability, ReportingContract, ReportingContractClient,
-    ReportingError, MAX_DEP_PAGES,
+    Category, ContractAddresses, DataAvailability, HealthMetric, HealthScoreModel, PeriodKind,
+    ReportingContract, ReportingContractClient, ReportingError, MAX_DEP_PAGES,
 };
 
 /// Minimal env with mock_all_auths — replaces the removed create_test_env helper.
@@ -302,7 +302,16 @@ mod family_wallet {
                     .set(&symbol_short!("MBRS"), &members);
             }
 
+            pub fn set_owner(env: Env, owner: Address) {
+                env.storage()
+                    .instance()
+                    .set(&symbol_short!("OWNER"), &owner);
+            }
+
             pub fn get_owner(env: Env) -> Address {
+                if let Some(owner) = env.storage().instance().get(&symbol_short!("OWNER")) {
+                    return owner;
+                }
                 let members: Vec<Address> = env
                     .storage()
                     .instance()
@@ -456,19 +465,25 @@ mod family_wallet_infinite {
 
     #[contractimpl]
     impl FamilyWalletInfinite {
-        pub fn seed(env: Env) {
+        pub fn seed(env: Env, owner: Address) {
             let mut addrs = Vec::new(&env);
             for _ in 0..25 {
                 addrs.push_back(Address::generate(&env));
             }
             env.storage().instance().set(&symbol_short!("ADDR"), &addrs);
+            env.storage()
+                .instance()
+                .set(&symbol_short!("OWNER"), &owner);
         }
     }
 
     #[contractimpl]
     impl FamilyWalletTrait for FamilyWalletInfinite {
         fn get_owner(env: &Env) -> Address {
-            Address::generate(env)
+            env.storage()
+                .instance()
+                .get(&symbol_short!("OWNER"))
+                .unwrap_or_else(|| Address::generate(env))
         }
 
         fn get_member_addresses_page(env: Env, cursor: u32, _limit: u32) -> MemberAddressPage {
@@ -1288,6 +1303,7 @@ fn test_get_family_spending_report_complete() {
     members.push_back(Address::generate(&env));
     let family_client = family_wallet::FamilyWalletScenarioClient::new(&env, &family_wallet_id);
     family_client.seed(&family_wallet::MODE_COMPLETE, &members);
+    family_client.set_owner(&user);
 
     client.configure_addresses(
         &admin,
@@ -1344,6 +1360,7 @@ fn test_get_family_spending_report_partial_when_member_tracker_fails() {
     members.push_back(Address::generate(&env));
     let family_client = family_wallet::FamilyWalletScenarioClient::new(&env, &family_wallet_id);
     family_client.seed(&family_wallet::MODE_PARTIAL_TRACKER, &members);
+    family_client.set_owner(&user);
 
     client.configure_addresses(
         &admin,
@@ -1386,6 +1403,7 @@ fn test_get_family_spending_report_missing_when_wallet_unreachable() {
     members.push_back(Address::generate(&env));
     let family_client = family_wallet::FamilyWalletScenarioClient::new(&env, &family_wallet_id);
     family_client.seed(&family_wallet::MODE_MISSING, &members);
+    family_client.set_owner(&user);
 
     client.configure_addresses(
         &admin,
@@ -1424,6 +1442,7 @@ fn test_get_family_spending_report_zero_members_maps_to_missing() {
     let family_wallet_id = env.register_contract(None, family_wallet::FamilyWalletScenario);
     let family_client = family_wallet::FamilyWalletScenarioClient::new(&env, &family_wallet_id);
     family_client.seed(&family_wallet::MODE_EMPTY, &soroban_sdk::Vec::new(&env));
+    family_client.set_owner(&user);
 
     client.configure_addresses(
         &admin,
@@ -1463,6 +1482,7 @@ fn test_get_family_spending_report_overflow_clamps_and_marks_partial() {
     members.push_back(Address::generate(&env));
     let family_client = family_wallet::FamilyWalletScenarioClient::new(&env, &family_wallet_id);
     family_client.seed(&family_wallet::MODE_OVERFLOW, &members);
+    family_client.set_owner(&user);
 
     client.configure_addresses(
         &admin,
@@ -1504,6 +1524,7 @@ fn test_get_family_spending_report_requires_user_auth() {
     members.push_back(Address::generate(&env));
     let family_client = family_wallet::FamilyWalletScenarioClient::new(&env, &family_wallet_id);
     family_client.seed(&family_wallet::MODE_COMPLETE, &members);
+    family_client.set_owner(&user);
 
     client.configure_addresses(
         &admin,
@@ -1521,6 +1542,48 @@ fn test_get_family_spending_report_requires_user_auth() {
     assert!(found, "family spending report must require user auth");
 }
 
+#[test]
+fn test_get_family_spending_report_rejects_non_owner() {
+    let env = create_test_env();
+    set_ledger_time(&env, 1, 1704067200);
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    client.init(&admin);
+
+    let remittance_split_id = env.register_contract(None, remittance_split::RemittanceSplit);
+    let savings_goals_id = env.register_contract(None, savings_goals::SavingsGoalsContract);
+    let bill_payments_id = env.register_contract(None, bill_payments::BillPayments);
+    let insurance_id = env.register_contract(None, insurance::Insurance);
+    let family_wallet_id = env.register_contract(None, family_wallet::FamilyWalletScenario);
+    let mut members = soroban_sdk::Vec::new(&env);
+    members.push_back(owner.clone());
+    members.push_back(outsider.clone());
+    let family_client = family_wallet::FamilyWalletScenarioClient::new(&env, &family_wallet_id);
+    family_client.seed(&family_wallet::MODE_COMPLETE, &members);
+    family_client.set_owner(&owner);
+
+    client.configure_addresses(
+        &admin,
+        &remittance_split_id,
+        &savings_goals_id,
+        &bill_payments_id,
+        &insurance_id,
+        &family_wallet_id,
+    );
+
+    let result = client.try_get_family_spending_report(
+        &outsider,
+        &outsider,
+        &1_704_067_200u64,
+        &1_706_745_600u64,
+    );
+    assert!(matches!(result, Err(Ok(ReportingError::Unauthorized))));
+}
+
 #[test]
 fn test_get_family_spending_report_rejects_invalid_period() {
     let env = create_test_env();
@@ -1567,7 +1630,7 @@ fn test_get_family_spending_report_partial_when_member_pages_exceed_cap() {
     let insurance_id = env.register_contract(None, insurance::Insurance);
     let family_wallet_id =
         env.register_contract(None, family_wallet_infinite::FamilyWalletInfinite);
-    family_wallet_infinite::FamilyWalletInfiniteClient::new(&env, &family_wallet_id).seed();
+    family_wallet_infinite::FamilyWalletInfiniteClient::new(&env, &family_wallet_id).seed(&user);
 
     client.configure_addresses(
         &admin,
@@ -1614,6 +1677,7 @@ fn test_get_family_spending_report_deduplicates_members() {
     members.push_back(member.clone());
     let family_client = family_wallet::FamilyWalletScenarioClient::new(&env, &family_wallet_id);
     family_client.seed(&family_wallet::MODE_DUPLICATE_PAGES, &members);
+    family_client.set_owner(&user);
 
     client.configure_addresses(
         &admin,
@@ -1850,6 +1914,155 @@ fn test_calculate_health_score_bounds_guarantee() {
     }
 }
 
+fn setup_health_score_client(env: &Env) -> (ReportingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    set_ledger_time(env, 1, 1704067200);
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init(&admin);
+
+    client.configure_addresses(
+        &admin,
+        &env.register_contract(None, remittance_split::RemittanceSplit),
+        &env.register_contract(None, savings_goals::SavingsGoalsContract),
+        &env.register_contract(None, bill_payments::BillPayments),
+        &env.register_contract(None, insurance::Insurance),
+        &Address::generate(env),
+    );
+    (client, admin)
+}
+
+fn weighted_health_score_model() -> HealthScoreModel {
+    HealthScoreModel {
+        version: 2,
+        savings_weight: 30,
+        savings_no_goals_score: 15,
+        bills_weight: 30,
+        bills_unpaid_score: 25,
+        bills_overdue_score: 10,
+        insurance_weight: 10,
+        premium_income_weight: 15,
+        max_premium_income_bps: 500,
+        emergency_fund_weight: 15,
+        emergency_fund_target_months: 3,
+    }
+}
+
+#[test]
+fn test_health_score_default_model_is_version_1() {
+    let env = Env::default();
+    let (client, _) = setup_health_score_client(&env);
+    let user = Address::generate(&env);
+
+    let model = client.get_health_score_model();
+    assert_eq!(model.version, 1);
+    assert_eq!(client.get_health_score_model_version(&1), Some(model));
+    assert_eq!(client.get_health_score_model_version(&2), None);
+
+    let score = client.calculate_health_score(&user, &10000);
+    assert_eq!(score.model_version, 1);
+    assert_eq!(score.score, 87);
+
+    let inputs = client.get_health_score_inputs(&user, &10000);
+    assert_eq!(inputs.model_version, 1);
+    assert_eq!(inputs.goals_target, 15000);
+    assert_eq!(inputs.goals_saved, 12000);
+    assert_eq!(inputs.savings_progress_pct, 80);
+    assert_eq!(inputs.unpaid_bills, 1);
+    assert_eq!(inputs.overdue_bills, 0);
+    assert!(inputs.has_active_policy);
+    // Version 1 gives premiums no weight, so they are not fetched.
+    assert_eq!(inputs.monthly_premium, 0);
+}
+
+#[test]
+fn test_set_health_score_model_rejects_invalid_models() {
+    let env = Env::default();
+    let (client, admin) = setup_health_score_client(&env);
+    let model = weighted_health_score_model();
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_set_health_score_model(&outsider, &model),
+        Err(Ok(ReportingError::Unauthorized))
+    );
+
+    let mut skipped = model.clone();
+    skipped.version = 3;
+    let mut underweight = model.clone();
+    underweight.insurance_weight = 9;
+    let mut tier_above_weight = model.clone();
+    tier_above_weight.bills_unpaid_score = 31;
+    let mut no_threshold = model.clone();
+    no_threshold.max_premium_income_bps = 0;
+    let mut no_target = model.clone();
+    no_target.emergency_fund_target_months = 0;
+    for invalid in [
+        skipped,
+        underweight,
+        tier_above_weight,
+        no_threshold,
+        no_target,
+    ] {
+        assert_eq!(
+            client.try_set_health_score_model(&admin, &invalid),
+            Err(Ok(ReportingError::InvalidHealthScoreModel))
+        );
+    }
+
+    client.set_health_score_model(&admin, &model);
+    assert_eq!(client.get_health_score_model(), model);
+    // The same version cannot be applied twice.
+    assert_eq!(
+        client.try_set_health_score_model(&admin, &model),
+        Err(Ok(ReportingError::InvalidHealthScoreModel))
+    );
+}
+
+#[test]
+fn test_custom_health_score_model_weights_ratio_components() {
+    let env = Env::default();
+    let (client, admin) = setup_health_score_client(&env);
+    let user = Address::generate(&env);
+    client.set_health_score_model(&admin, &weighted_health_score_model());
+
+    // Premiums are 2% of income (within 5%); savings cover 1.2 of 3 months.
+    let score = client.calculate_health_score(&user, &10000);
+    assert_eq!(score.model_version, 2);
+    assert_eq!(score.savings_score, 24);
+    assert_eq!(score.bills_score, 25);
+    assert_eq!(score.insurance_score, 10);
+    assert_eq!(score.premium_income_score, 15);
+    assert_eq!(score.emergency_fund_score, 6);
+    assert_eq!(score.score, 80);
+
+    let inputs = client.get_health_score_inputs(&user, &10000);
+    assert_eq!(inputs.monthly_premium, 200);
+    assert_eq!(inputs.premium_income_bps, 200);
+    assert_eq!(inputs.emergency_fund_months_x100, 120);
+
+    // Premiums at 10% of income halve the premium component; savings now
+    // cover the full 3-month target.
+    let score = client.calculate_health_score(&user, &2000);
+    assert_eq!(score.premium_income_score, 7);
+    assert_eq!(score.emergency_fund_score, 15);
+    assert_eq!(score.score, 24 + 25 + 10 + 7 + 15);
+
+    // Earlier versions stay available for explaining older scores.
+    assert_eq!(
+        client
+            .get_health_score_model_version(&1)
+            .unwrap()
+            .savings_weight,
+        40
+    );
+    assert_eq!(
+        client.get_health_score_model_version(&2),
+        Some(weighted_health_score_model())
+    );
+}
+
 #[test]
 fn test_get_financial_health_report() {
     let env = Env::default();
@@ -1935,41 +2148,17 @@ fn trend_client(env: &Env) -> (ReportingContractClient<'_>, Address) {
 fn test_trend_multi_empty_returns_empty() {
     let env = create_test_env();
     let (client, user) = trend_client(&env);
-    let history = soroban_sdk::Vec::new(&env);
 
-    let trends = client.get_trend_analysis_multi(&user, &history);
+    let trends =
+        client.get_trend_analysis_multi(&user, &PeriodKind::Month, &HealthMetric::Score, &0, &0);
 
     assert_eq!(trends.len(), 0);
 }
 
-#[test]
-fn test_trend_multi_single_point_uses_zero_baseline() {
-    let env = create_test_env();
-    let (client, user) = trend_client(&env);
-    let mut history = soroban_sdk::Vec::new(&env);
-    history.push_back((1u64, 250i128));
-
-    let trends = client.get_trend_analysis_multi(&user, &history);
-    let first = trends.get(0).expect("single point produces one trend");
-
-    assert_eq!(trends.len(), 1);
-    assert_eq!(first.current_amount, 250);
-    assert_eq!(first.previous_amount, 0);
-    assert_eq!(first.change_amount, 250);
-    assert_eq!(first.change_percentage, 100);
-}
-
 #[test]
 fn test_trend_multi_zero_previous_amount_does_not_divide_by_zero() {
-    let env = create_test_env();
-    let (client, user) = trend_client(&env);
-    let mut history = soroban_sdk::Vec::new(&env);
-    history.push_back((1u64, 0i128));
-    history.push_back((2u64, 75i128));
-
-    let trends = client.get_trend_analysis_multi(&user, &history);
-    let first = trends.get(0).expect("first trend exists");
-    let second = trends.get(1).expect("second trend exists");
+    let first = crate::trend_from_amounts(0, 0);
+    let second = crate::trend_from_amounts(75, 0);
 
     assert_eq!(first.previous_amount, 0);
     assert_eq!(first.current_amount, 0);
@@ -1982,18 +2171,9 @@ fn test_trend_multi_zero_previous_amount_does_not_divide_by_zero() {
 
 #[test]
 fn test_trend_multi_sign_swings_follow_change_direction_for_positive_baseline() {
-    let env = create_test_env();
-    let (client, user) = trend_client(&env);
-    let mut history = soroban_sdk::Vec::new(&env);
-    history.push_back((1u64, -50i128));
-    history.push_back((2u64, 50i128));
-    history.push_back((3u64, -50i128));
-    history.push_back((4u64, 100i128));
-
-    let trends = client.get_trend_analysis_multi(&user, &history);
-    let negative_to_positive = trends.get(1).expect("negative-to-positive trend");
-    let positive_to_negative = trends.get(2).expect("positive-to-negative trend");
-    let negative_to_positive_again = trends.get(3).expect("second negative-to-positive trend");
+    let negative_to_positive = crate::trend_from_amounts(50, -50);
+    let positive_to_negative = crate::trend_from_amounts(-50, 50);
+    let negative_to_positive_again = crate::trend_from_amounts(100, -50);
 
     assert_eq!(negative_to_positive.previous_amount, -50);
     assert_eq!(negative_to_positive.current_amount, 50);
@@ -2011,40 +2191,10 @@ fn test_trend_multi_sign_swings_follow_change_direction_for_positive_baseline()
     assert_eq!(negative_to_positive_again.change_percentage, 100);
 }
 
-#[test]
-fn test_trend_multi_uses_input_order_for_unsorted_history() {
-    let env = create_test_env();
-    let (client, user) = trend_client(&env);
-    let mut history = soroban_sdk::Vec::new(&env);
-    history.push_back((3u64, 300i128));
-    history.push_back((1u64, 100i128));
-    history.push_back((2u64, 200i128));
-
-    let trends = client.get_trend_analysis_multi(&user, &history);
-
-    assert_eq!(trends.len(), 3);
-    assert_eq!(trends.get(0).unwrap().current_amount, 300);
-    assert_eq!(trends.get(0).unwrap().previous_amount, 0);
-    assert_eq!(trends.get(1).unwrap().current_amount, 100);
-    assert_eq!(trends.get(1).unwrap().previous_amount, 300);
-    assert_eq!(trends.get(1).unwrap().change_percentage, -66);
-    assert_eq!(trends.get(2).unwrap().current_amount, 200);
-    assert_eq!(trends.get(2).unwrap().previous_amount, 100);
-    assert_eq!(trends.get(2).unwrap().change_percentage, 100);
-}
-
 #[test]
 fn test_trend_multi_extreme_values_saturate_without_overflow() {
-    let env = create_test_env();
-    let (client, user) = trend_client(&env);
-    let mut history = soroban_sdk::Vec::new(&env);
-    history.push_back((1u64, i128::MAX));
-    history.push_back((2u64, i128::MIN));
-    history.push_back((3u64, i128::MAX));
-
-    let trends = client.get_trend_analysis_multi(&user, &history);
-    let max_to_min = trends.get(1).expect("max-to-min trend");
-    let min_to_max = trends.get(2).expect("min-to-max trend");
+    let max_to_min = crate::trend_from_amounts(i128::MIN, i128::MAX);
+    let min_to_max = crate::trend_from_amounts(i128::MAX, i128::MIN);
 
     assert_eq!(max_to_min.previous_amount, i128::MAX);
     assert_eq!(max_to_min.current_amount, i128::MIN);
@@ -2057,6 +2207,150 @@ fn test_trend_multi_extreme_values_saturate_without_overflow() {
     assert_eq!(min_to_max.change_percentage, 100);
 }
 
+const JAN_2024: u64 = 1704067200;
+const FEB_2024: u64 = 1706745600;
+const MAR_2024: u64 = 1709251200;
+
+#[test]
+fn test_capture_health_snapshot_once_per_period() {
+    let env = Env::default();
+    let (client, _) = setup_health_score_client(&env);
+    let user = Address::generate(&env);
+
+    assert!(client.capture_health_snapshot(&user, &user, &PeriodKind::Month));
+    set_ledger_time(&env, 1, JAN_2024 + 86400 * 10);
+    assert!(!client.capture_health_snapshot(&user, &user, &PeriodKind::Month));
+
+    let page = client.get_health_snapshots(&user, &PeriodKind::Month, &0, &0);
+    assert_eq!(page.count, 1);
+    assert_eq!(page.next_cursor, 0);
+    let snapshot = page.items.get(0).unwrap();
+    assert_eq!(snapshot.period_key, 202401);
+    assert_eq!(snapshot.captured_at, JAN_2024);
+    assert_eq!(snapshot.health_score.model_version, 1);
+}
+
+#[test]
+fn test_capture_health_snapshot_requires_user_or_keeper() {
+    let env = Env::default();
+    let (client, admin) = setup_health_score_client(&env);
+    let user = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    assert!(matches!(
+        client.try_capture_health_snapshot(&keeper, &user, &PeriodKind::Month),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+    assert!(matches!(
+        client.try_set_snapshot_keeper(&keeper, &Some(keeper.clone())),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+
+    client.set_snapshot_keeper(&admin, &Some(keeper.clone()));
+    assert_eq!(client.get_snapshot_keeper(), Some(keeper.clone()));
+    assert!(client.capture_health_snapshot(&keeper, &user, &PeriodKind::Month));
+
+    client.set_snapshot_keeper(&admin, &None);
+    assert_eq!(client.get_snapshot_keeper(), None);
+    set_ledger_time(&env, 1, FEB_2024);
+    assert!(matches!(
+        client.try_capture_health_snapshot(&keeper, &user, &PeriodKind::Month),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+}
+
+#[test]
+fn test_health_snapshots_paginate_oldest_first() {
+    let env = Env::default();
+    let (client, _) = setup_health_score_client(&env);
+    let user = Address::generate(&env);
+
+    for time in [JAN_2024, FEB_2024, MAR_2024] {
+        set_ledger_time(&env, 1, time);
+        client.capture_health_snapshot(&user, &user, &PeriodKind::Month);
+    }
+
+    let first = client.get_health_snapshots(&user, &PeriodKind::Month, &0, &2);
+    assert_eq!(first.count, 3);
+    assert_eq!(first.items.len(), 2);
+    assert_eq!(first.items.get(0).unwrap().period_key, 202401);
+    assert_eq!(first.items.get(1).unwrap().period_key, 202402);
+    assert_eq!(first.next_cursor, 2);
+
+    let second = client.get_health_snapshots(&user, &PeriodKind::Month, &first.next_cursor, &2);
+    assert_eq!(second.items.len(), 1);
+    assert_eq!(second.items.get(0).unwrap().period_key, 202403);
+    assert_eq!(second.next_cursor, 0);
+
+    let past_end = client.get_health_snapshots(&user, &PeriodKind::Month, &3, &2);
+    assert_eq!(past_end.items.len(), 0);
+    assert_eq!(past_end.next_cursor, 0);
+}
+
+#[test]
+fn test_trend_multi_reads_stored_snapshots_across_pages() {
+    let env = Env::default();
+    let (client, _) = setup_health_score_client(&env);
+    let user = Address::generate(&env);
+
+    for time in [JAN_2024, FEB_2024, MAR_2024] {
+        set_ledger_time(&env, 1, time);
+        client.capture_health_snapshot(&user, &user, &PeriodKind::Month);
+    }
+    let score = client
+        .get_health_snapshots(&user, &PeriodKind::Month, &0, &1)
+        .items
+        .get(0)
+        .unwrap()
+        .health_score
+        .score as i128;
+
+    let all =
+        client.get_trend_analysis_multi(&user, &PeriodKind::Month, &HealthMetric::Score, &0, &0);
+    assert_eq!(all.len(), 3);
+    let oldest = all.get(0).unwrap();
+    assert_eq!(oldest.previous_amount, 0);
+    assert_eq!(oldest.current_amount, score);
+    assert_eq!(oldest.change_amount, score);
+
+    // A later page compares against the snapshot just before the cursor, not zero.
+    let tail =
+        client.get_trend_analysis_multi(&user, &PeriodKind::Month, &HealthMetric::Score, &2, &1);
+    assert_eq!(tail.len(), 1);
+    let latest = tail.get(0).unwrap();
+    assert_eq!(latest.previous_amount, score);
+    assert_eq!(latest.current_amount, score);
+    assert_eq!(latest.change_amount, 0);
+    assert_eq!(latest.change_percentage, 0);
+}
+
+#[test]
+fn test_health_snapshots_evict_oldest_beyond_cap() {
+    // Filling the cap writes over a hundred snapshots; a ledger snapshot of
+    // that state is too large to keep under version control.
+    let env = Env::new_with_config(soroban_sdk::testutils::EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    let (client, _) = setup_health_score_client(&env);
+    let user = Address::generate(&env);
+
+    for day in 0..=crate::MAX_HEALTH_SNAPSHOTS {
+        set_ledger_time(&env, 1, JAN_2024 + day as u64 * 86400);
+        assert!(client.capture_health_snapshot(&user, &user, &PeriodKind::Day));
+    }
+
+    let page = client.get_health_snapshots(&user, &PeriodKind::Day, &0, &1);
+    assert_eq!(page.count, crate::MAX_HEALTH_SNAPSHOTS);
+    assert_eq!(page.items.get(0).unwrap().period_key, JAN_2024 / 86400 + 1);
+    // Month snapshots are tracked independently of day snapshots.
+    assert_eq!(
+        client
+            .get_health_snapshots(&user, &PeriodKind::Month, &0, &1)
+            .count,
+        0
+    );
+}
+
 #[test]
 fn test_store_and_retrieve_report() {
     let env = Env::default();
@@ -2375,8 +2669,9 @@ fn test_instance_ttl_extended_on_init() {
 //
 // Security assumptions validated here:
 //   1. store_report requires the caller to be the report owner (require_auth).
-//   2. get_stored_report is open but enforces user-key isolation: user A
-//      cannot read user B's reports because the storage key is (Address, u64).
+//   2. get_stored_report enforces user-key isolation: user A cannot read
+//      user B's reports without an access grant, and the storage key is
+//      (Address, u64).
 //   3. archive_old_reports is admin-only; non-admin callers are rejected.
 //   4. cleanup_old_reports is admin-only; non-admin callers are rejected.
 //   5. get_archived_reports filters by address, so user A cannot see user B's
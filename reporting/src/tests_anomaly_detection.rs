@@ -0,0 +1,522 @@
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol, TryFromVal, Val};
+
+use crate::{
+    AnomalyConfig, AnomalyKind, AnomalyReport, DataAvailability, PeriodKind, ReportingContract,
+    ReportingContractClient, ReportingError,
+};
+
+// 2024-01-15 (UTC); later checks step forward in whole months.
+const JAN_15_2024: u64 = 1_705_276_800;
+const MONTH: u64 = 31 * 86_400;
+
+/// Store `value` under `key` in `contract`'s instance storage.
+fn seed<V: IntoVal<Env, Val>>(env: &Env, contract: &Address, key: &str, value: V) {
+    let value: Val = value.into_val(env);
+    env.as_contract(contract, || {
+        env.storage().instance().set(&Symbol::new(env, key), &value)
+    });
+}
+
+fn seeded<V: TryFromVal<Env, Val>>(env: &Env, key: &str) -> Option<V> {
+    env.storage().instance().get(&Symbol::new(env, key))
+}
+
+mod savings {
+    use crate::{GoalPage, SavingsGoal, SavingsGoalsTrait, SavingsSchedule, SavingsScheduleTrait};
+    use soroban_sdk::{contract, contractimpl, vec, Address, Env, String, Vec};
+
+    /// One 10_000 goal holding the seeded `saved` balance, and one schedule
+    /// with the seeded `missed` count.
+    #[contract]
+    pub struct Savings;
+
+    #[contractimpl]
+    impl SavingsGoalsTrait for Savings {
+        fn get_all_goals(env: Env, owner: Address) -> Vec<SavingsGoal> {
+            vec![
+                &env,
+                SavingsGoal {
+                    id: 1,
+                    owner,
+                    name: String::from_str(&env, "goal"),
+                    target_amount: 10_000,
+                    current_amount: super::seeded(&env, "saved").unwrap_or(0),
+                    target_date: 0,
+                    locked: false,
+                    unlock_date: None,
+                    tags: Vec::new(&env),
+                },
+            ]
+        }
+
+        fn get_goals(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> GoalPage {
+            GoalPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn is_goal_completed(_env: Env, _goal_id: u32) -> bool {
+            false
+        }
+    }
+
+    #[contractimpl]
+    impl SavingsScheduleTrait for Savings {
+        fn get_savings_schedules(env: Env, owner: Address) -> Vec<SavingsSchedule> {
+            vec![
+                &env,
+                SavingsSchedule {
+                    id: 1,
+                    owner,
+                    goal_id: 1,
+                    amount: 100,
+                    next_due: 0,
+                    interval: 0,
+                    recurring: false,
+                    active: true,
+                    created_at: 0,
+                    last_executed: None,
+                    missed_count: super::seeded(&env, "missed").unwrap_or(0),
+                },
+            ]
+        }
+    }
+}
+
+mod bills {
+    use crate::{BillPage, BillPaymentsTrait, BillSchedule, BillScheduleTrait};
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    #[contract]
+    pub struct Bills;
+
+    #[contractimpl]
+    impl BillPaymentsTrait for Bills {
+        fn get_unpaid_bills(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> BillPage {
+            BillPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn get_total_unpaid(_env: Env, _owner: Address) -> i128 {
+            0
+        }
+
+        fn get_all_bills_for_owner(
+            env: Env,
+            _owner: Address,
+            _cursor: u32,
+            _limit: u32,
+        ) -> BillPage {
+            BillPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+    }
+
+    #[contractimpl]
+    impl BillScheduleTrait for Bills {
+        fn get_bill_schedules(env: Env, _owner: Address) -> Vec<BillSchedule> {
+            Vec::new(&env)
+        }
+    }
+}
+
+mod insurance {
+    use crate::{
+        CoverageType, DeactivatedPoliciesTrait, DeactivatedPolicy, DeactivatedPolicyPage,
+        InsurancePolicy, InsuranceTrait, NextPaymentSchedule, PolicyPage, PremiumScheduleTrait,
+    };
+    use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+
+    /// Deactivated policies carry the seeded `deact` timestamps.
+    #[contract]
+    pub struct Insurance;
+
+    #[contractimpl]
+    impl InsuranceTrait for Insurance {
+        fn get_active_policies(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> PolicyPage {
+            PolicyPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn get_policy(_env: Env, _policy_id: u32) -> Option<InsurancePolicy> {
+            None
+        }
+
+        fn get_total_monthly_premium(_env: Env, _owner: Address) -> i128 {
+            0
+        }
+    }
+
+    #[contractimpl]
+    impl PremiumScheduleTrait for Insurance {
+        fn get_premium_schedules(env: Env, _owner: Address) -> Vec<NextPaymentSchedule> {
+            Vec::new(&env)
+        }
+    }
+
+    #[contractimpl]
+    impl DeactivatedPoliciesTrait for Insurance {
+        fn get_deactivated_policies(
+            env: Env,
+            owner: Address,
+            _cursor: u32,
+            _limit: u32,
+        ) -> DeactivatedPolicyPage {
+            let times: Vec<u64> = super::seeded(&env, "deact").unwrap_or_else(|| Vec::new(&env));
+            let mut items = Vec::new(&env);
+            for (id, deactivated_at) in times.iter().enumerate() {
+                items.push_back(DeactivatedPolicy {
+                    id: id as u32 + 1,
+                    owner: owner.clone(),
+                    name: String::from_str(&env, "policy"),
+                    coverage_type: CoverageType::Health,
+                    monthly_premium: 10,
+                    coverage_amount: 1_000,
+                    external_ref: None,
+                    active: false,
+                    created_at: 0,
+                    last_payment_at: 0,
+                    next_payment_date: 0,
+                    deactivated_at,
+                });
+            }
+            DeactivatedPolicyPage {
+                count: items.len(),
+                items,
+                next_cursor: 0,
+            }
+        }
+    }
+}
+
+mod split {
+    use crate::{RemittanceSchedule, RemittanceScheduleTrait};
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    #[contract]
+    pub struct Split;
+
+    #[contractimpl]
+    impl RemittanceScheduleTrait for Split {
+        fn get_remittance_schedules(env: Env, _owner: Address) -> Vec<RemittanceSchedule> {
+            Vec::new(&env)
+        }
+    }
+}
+
+mod family {
+    use crate::{
+        EmergencyStatusTrait, FamilyWalletTrait, MemberAddressPage, SpendingPeriod,
+        SpendingTracker, WalletAuditEntry,
+    };
+    use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Env, Vec};
+
+    /// Emergency mode was switched on at the seeded `em_at` (when `em_on`),
+    /// and the user last spent 500 at the seeded `spent_at`.
+    #[contract]
+    pub struct Family;
+
+    #[contractimpl]
+    impl FamilyWalletTrait for Family {
+        fn get_owner(env: &Env) -> Address {
+            env.current_contract_address()
+        }
+
+        fn get_member_addresses_page(env: Env, _cursor: u32, _limit: u32) -> MemberAddressPage {
+            MemberAddressPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn get_spending_tracker(env: Env, _member: Address) -> Option<SpendingTracker> {
+            let spent_at: u64 = super::seeded(&env, "spent_at")?;
+            Some(SpendingTracker {
+                current_spent: 500,
+                last_tx_timestamp: spent_at,
+                tx_count: 1,
+                period: SpendingPeriod {
+                    period_type: 1,
+                    period_start: 0,
+                    period_duration: 86_400,
+                },
+            })
+        }
+    }
+
+    #[contractimpl]
+    impl EmergencyStatusTrait for Family {
+        fn is_emergency_mode(env: Env) -> bool {
+            super::seeded(&env, "em_on").unwrap_or(false)
+        }
+
+        fn get_access_audit(env: Env, _limit: u32) -> Vec<WalletAuditEntry> {
+            let entry = |operation, timestamp| WalletAuditEntry {
+                operation,
+                caller: env.current_contract_address(),
+                target: None,
+                success: true,
+                timestamp,
+            };
+            let em_at: u64 = super::seeded(&env, "em_at").unwrap_or(0);
+            vec![
+                &env,
+                entry(symbol_short!("em_mode"), em_at),
+                entry(symbol_short!("add_mem"), em_at + 1),
+            ]
+        }
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    client: ReportingContractClient<'a>,
+    admin: Address,
+    user: Address,
+    savings: Address,
+    insurance: Address,
+    family: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = JAN_15_2024);
+
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    let savings = env.register_contract(None, savings::Savings);
+    let insurance = env.register_contract(None, insurance::Insurance);
+    let family = env.register_contract(None, family::Family);
+    client.configure_addresses(
+        &admin,
+        &env.register_contract(None, split::Split),
+        &savings,
+        &env.register_contract(None, bills::Bills),
+        &insurance,
+        &family,
+    );
+
+    Setup {
+        user: Address::generate(&env),
+        env,
+        client,
+        admin,
+        savings,
+        insurance,
+        family,
+    }
+}
+
+fn signal(report: &AnomalyReport, kind: AnomalyKind) -> (bool, i128, i128) {
+    let signal = report.signals.iter().find(|s| s.kind == kind).unwrap();
+    (signal.tripped, signal.observed, signal.threshold)
+}
+
+#[test]
+fn test_withdrawal_spike_against_trailing_average() {
+    let s = setup();
+    let capture = |month: u64, saved: i128| {
+        s.env
+            .ledger()
+            .with_mut(|l| l.timestamp = JAN_15_2024 + month * MONTH);
+        seed(&s.env, &s.savings, "saved", saved);
+        s.client
+            .capture_health_snapshot(&s.user, &s.user, &PeriodKind::Month);
+    };
+
+    // Monthly withdrawals of 100, 100, 100: not enough history for a baseline yet.
+    for (month, saved) in [(0, 10_000), (1, 9_900), (2, 9_800), (3, 9_700)] {
+        capture(month, saved);
+    }
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(report.data_availability, DataAvailability::Partial);
+    assert_eq!(signal(&report, AnomalyKind::WithdrawalSpike), (false, 0, 1));
+
+    // 700 withdrawn against a trailing average of 100 (threshold 200%).
+    capture(4, 9_000);
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(report.data_availability, DataAvailability::Complete);
+    assert_eq!(
+        signal(&report, AnomalyKind::WithdrawalSpike),
+        (true, 700, 200)
+    );
+
+    // Back to 100 against an average of 300.
+    capture(5, 8_900);
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(
+        signal(&report, AnomalyKind::WithdrawalSpike),
+        (false, 100, 600)
+    );
+}
+
+#[test]
+fn test_missed_schedules_compare_with_previous_check() {
+    let s = setup();
+
+    seed(&s.env, &s.savings, "missed", 1u32);
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(signal(&report, AnomalyKind::MissedSchedules), (false, 0, 2));
+
+    seed(&s.env, &s.savings, "missed", 2u32);
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(signal(&report, AnomalyKind::MissedSchedules), (false, 1, 2));
+
+    seed(&s.env, &s.savings, "missed", 4u32);
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(signal(&report, AnomalyKind::MissedSchedules), (true, 2, 2));
+}
+
+#[test]
+fn test_policy_deactivation_burst_within_window() {
+    let s = setup();
+    let day = 86_400;
+
+    seed(
+        &s.env,
+        &s.insurance,
+        "deact",
+        soroban_sdk::vec![&s.env, JAN_15_2024 - 40 * day, JAN_15_2024 - day],
+    );
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(
+        signal(&report, AnomalyKind::PolicyDeactivations),
+        (false, 1, 2)
+    );
+
+    seed(
+        &s.env,
+        &s.insurance,
+        "deact",
+        soroban_sdk::vec![&s.env, JAN_15_2024 - 2 * day, JAN_15_2024 - day],
+    );
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(
+        signal(&report, AnomalyKind::PolicyDeactivations),
+        (true, 2, 2)
+    );
+}
+
+#[test]
+fn test_emergency_spending_after_mode_enabled() {
+    let s = setup();
+    let enabled_at = JAN_15_2024 - 3_600;
+    seed(&s.env, &s.family, "em_at", enabled_at);
+    seed(&s.env, &s.family, "spent_at", enabled_at + 600);
+
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(
+        signal(&report, AnomalyKind::EmergencySpending),
+        (false, 0, 1)
+    );
+
+    seed(&s.env, &s.family, "em_on", true);
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(
+        signal(&report, AnomalyKind::EmergencySpending),
+        (true, 500, 1)
+    );
+
+    let found = s.env.events().all().iter().any(|(_, topics, _)| {
+        topics.len() == 4
+            && Symbol::try_from_val(&s.env, &topics.get(0).unwrap())
+                == Ok(symbol_short!("Remitwise"))
+            && u32::try_from_val(&s.env, &topics.get(1).unwrap()) == Ok(2)
+            && u32::try_from_val(&s.env, &topics.get(2).unwrap()) == Ok(2)
+            && Symbol::try_from_val(&s.env, &topics.get(3).unwrap()) == Ok(symbol_short!("anomaly"))
+    });
+    assert!(found, "tripped signal must publish an Alert/High event");
+
+    // Spending before emergency mode was enabled does not count.
+    seed(&s.env, &s.family, "spent_at", enabled_at - 1);
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(
+        signal(&report, AnomalyKind::EmergencySpending),
+        (false, 0, 1)
+    );
+}
+
+#[test]
+fn test_unreachable_sources_are_missing() {
+    let s = setup();
+    s.client.configure_addresses(
+        &s.admin,
+        &Address::generate(&s.env),
+        &Address::generate(&s.env),
+        &Address::generate(&s.env),
+        &Address::generate(&s.env),
+        &Address::generate(&s.env),
+    );
+
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(report.data_availability, DataAvailability::Missing);
+    assert!(report.signals.iter().all(|signal| !signal.tripped));
+}
+
+#[test]
+fn test_detect_anomalies_requires_user_or_keeper() {
+    let s = setup();
+    let keeper = Address::generate(&s.env);
+
+    assert!(matches!(
+        s.client.try_detect_anomalies(&keeper, &s.user),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+    s.client
+        .set_snapshot_keeper(&s.admin, &Some(keeper.clone()));
+    assert_eq!(s.client.detect_anomalies(&keeper, &s.user).signals.len(), 4);
+}
+
+#[test]
+fn test_anomaly_config_is_admin_only_and_validated() {
+    let s = setup();
+    let mut config = s.client.get_anomaly_config();
+    config.deactivation_burst = 3;
+
+    assert!(matches!(
+        s.client.try_set_anomaly_config(&s.user, &config),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+    let invalid = [
+        AnomalyConfig {
+            withdrawal_spike_pct: 99,
+            ..config.clone()
+        },
+        AnomalyConfig {
+            trailing_periods: 0,
+            ..config.clone()
+        },
+        AnomalyConfig {
+            min_withdrawal: 0,
+            ..config.clone()
+        },
+    ];
+    for bad in invalid.iter() {
+        assert!(matches!(
+            s.client.try_set_anomaly_config(&s.admin, bad),
+            Err(Ok(ReportingError::InvalidAnomalyConfig))
+        ));
+    }
+
+    s.client.set_anomaly_config(&s.admin, &config);
+    assert_eq!(s.client.get_anomaly_config(), config);
+    let report = s.client.detect_anomalies(&s.user, &s.user);
+    assert_eq!(signal(&report, AnomalyKind::PolicyDeactivations).2, 3);
+}
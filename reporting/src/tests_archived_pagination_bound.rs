@@ -213,6 +213,9 @@ fn make_zero_report(env: &Env, _user: &Address, generated_at: u64) -> FinancialH
             savings_score: 0,
             bills_score: 0,
             insurance_score: 0,
+            premium_income_score: 0,
+            emergency_fund_score: 0,
+            model_version: 1,
         },
         remittance_summary: RemittanceSummary {
             total_received: 0,
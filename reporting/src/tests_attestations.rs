@@ -0,0 +1,279 @@
+extern crate std;
+
+use soroban_sdk::testutils::{storage::Persistent as _, Address as _, Ledger};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::{
+    ReportingContract, ReportingContractClient, ReportingError, ATTESTATION_DOMAIN,
+    MAX_ATTESTATION_SHARES, PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD,
+};
+
+const PERIOD_START: u64 = 1_704_067_200;
+const PERIOD_END: u64 = 1_735_689_599;
+
+mod bills {
+    use crate::{Bill, BillPage, BillPaymentsTrait};
+    use soroban_sdk::{contract, contractimpl, vec, Address, Env, String, Vec};
+
+    /// Two bills created in the period, one of them paid.
+    #[contract]
+    pub struct FixedBills;
+
+    #[contractimpl]
+    impl BillPaymentsTrait for FixedBills {
+        fn get_unpaid_bills(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> BillPage {
+            BillPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn get_total_unpaid(_env: Env, _owner: Address) -> i128 {
+            0
+        }
+
+        fn get_all_bills_for_owner(
+            env: Env,
+            owner: Address,
+            _cursor: u32,
+            _limit: u32,
+        ) -> BillPage {
+            let bill = |id: u32, paid: bool| Bill {
+                id,
+                owner: owner.clone(),
+                name: String::from_str(&env, "bill"),
+                external_ref: None,
+                amount: 1_000,
+                due_date: super::PERIOD_START + 86_400,
+                recurring: false,
+                frequency_days: 0,
+                paid,
+                created_at: super::PERIOD_START + 3_600,
+                paid_at: if paid {
+                    Some(super::PERIOD_START + 7_200)
+                } else {
+                    None
+                },
+                schedule_id: None,
+                tags: Vec::new(&env),
+                currency: String::from_str(&env, "XLM"),
+            };
+            let items = vec![&env, bill(1, true), bill(2, false)];
+            BillPage {
+                count: items.len(),
+                items,
+                next_cursor: 0,
+            }
+        }
+    }
+}
+
+mod lender {
+    use crate::ReportingContractClient;
+    use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+
+    /// Third-party contract that accepts a borrower whose attested
+    /// compliance is at least `min_percentage`.
+    #[contract]
+    pub struct Lender;
+
+    #[contractimpl]
+    impl Lender {
+        pub fn check(env: Env, reporting: Address, hash: BytesN<32>, min_percentage: u32) -> bool {
+            match ReportingContractClient::new(&env, &reporting)
+                .try_verify_attestation(&env.current_contract_address(), &hash)
+            {
+                Ok(Ok(attestation)) => attestation.report.compliance_percentage >= min_percentage,
+                _ => false,
+            }
+        }
+    }
+}
+
+fn setup(env: &Env, bills: &Address) -> ReportingContractClient<'static> {
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init(&admin);
+    client.configure_addresses(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        bills,
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+    client
+}
+
+fn env_with_bills() -> (Env, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = PERIOD_END);
+    let bills = env.register_contract(None, bills::FixedBills);
+    (env, bills)
+}
+
+#[test]
+fn test_attestation_hash_matches_documented_preimage() {
+    let (env, bills) = env_with_bills();
+    let client = setup(&env, &bills);
+    let user = Address::generate(&env);
+
+    let hash = client.attest_bill_compliance(&user, &PERIOD_START, &PERIOD_END);
+    let attestation = client.verify_attestation(&user, &hash);
+
+    let segments = [
+        Bytes::from_slice(&env, ATTESTATION_DOMAIN),
+        env.ledger().network_id().into(),
+        client.address.clone().to_xdr(&env),
+        user.clone().to_xdr(&env),
+        Bytes::from_array(&env, &PERIOD_START.to_be_bytes()),
+        Bytes::from_array(&env, &PERIOD_END.to_be_bytes()),
+        attestation.report.clone().to_xdr(&env),
+    ];
+    let mut preimage = Bytes::new(&env);
+    for segment in segments.iter() {
+        preimage.extend_from_array(&(segment.len() as u64).to_le_bytes());
+        preimage.append(segment);
+    }
+    let expected: BytesN<32> = env.crypto().sha256(&preimage).into();
+
+    assert_eq!(hash, expected);
+    assert_eq!(attestation.hash, hash);
+    assert_eq!(attestation.user, user);
+    assert_eq!(attestation.issued_at, PERIOD_END);
+    assert_eq!(attestation.report.paid_bills, 1);
+    assert_eq!(attestation.report.compliance_percentage, 50);
+}
+
+#[test]
+fn test_attestation_is_idempotent_and_bound_to_period_and_contract() {
+    let (env, bills) = env_with_bills();
+    let client = setup(&env, &bills);
+    let other = setup(&env, &bills);
+    let user = Address::generate(&env);
+
+    let hash = client.attest_bill_compliance(&user, &PERIOD_START, &PERIOD_END);
+    assert_eq!(
+        client.attest_bill_compliance(&user, &PERIOD_START, &PERIOD_END),
+        hash
+    );
+    assert_ne!(
+        client.attest_bill_compliance(&user, &PERIOD_START, &(PERIOD_END - 1)),
+        hash
+    );
+    assert_ne!(
+        other.attest_bill_compliance(&user, &PERIOD_START, &PERIOD_END),
+        hash
+    );
+    assert!(matches!(
+        other.try_verify_attestation(&user, &hash),
+        Err(Ok(ReportingError::AttestationNotFound))
+    ));
+}
+
+#[test]
+fn test_attestation_does_not_verify_on_another_network() {
+    let (env, bills) = env_with_bills();
+    let client = setup(&env, &bills);
+    let user = Address::generate(&env);
+    let hash = client.attest_bill_compliance(&user, &PERIOD_START, &PERIOD_END);
+
+    env.ledger().with_mut(|l| l.network_id = [7; 32]);
+    assert!(matches!(
+        client.try_verify_attestation(&user, &hash),
+        Err(Ok(ReportingError::InvalidAttestation))
+    ));
+}
+
+#[test]
+fn test_attestation_sharing_controls_verification() {
+    let (env, bills) = env_with_bills();
+    let client = setup(&env, &bills);
+    let user = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let hash = client.attest_bill_compliance(&user, &PERIOD_START, &PERIOD_END);
+
+    assert!(matches!(
+        client.try_verify_attestation(&viewer, &hash),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+    assert!(matches!(
+        client.try_share_attestation(&viewer, &hash, &viewer),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+    let unknown = BytesN::from_array(&env, &[0; 32]);
+    assert!(matches!(
+        client.try_share_attestation(&user, &unknown, &viewer),
+        Err(Ok(ReportingError::AttestationNotFound))
+    ));
+
+    client.share_attestation(&user, &hash, &viewer);
+    client.share_attestation(&user, &hash, &viewer);
+    assert_eq!(client.get_attestation_shares(&user, &hash).len(), 1);
+    assert!(matches!(
+        client.try_get_attestation_shares(&viewer, &hash),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+    assert_eq!(client.verify_attestation(&viewer, &hash).hash, hash);
+
+    client.revoke_attestation_share(&user, &hash, &viewer);
+    assert_eq!(client.get_attestation_shares(&user, &hash).len(), 0);
+    assert!(matches!(
+        client.try_verify_attestation(&viewer, &hash),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+}
+
+#[test]
+fn test_attestation_share_limit() {
+    let (env, bills) = env_with_bills();
+    let client = setup(&env, &bills);
+    let user = Address::generate(&env);
+    let hash = client.attest_bill_compliance(&user, &PERIOD_START, &PERIOD_END);
+
+    for _ in 0..MAX_ATTESTATION_SHARES {
+        client.share_attestation(&user, &hash, &Address::generate(&env));
+    }
+    assert!(matches!(
+        client.try_share_attestation(&user, &hash, &Address::generate(&env)),
+        Err(Ok(ReportingError::AttestationShareLimit))
+    ));
+}
+
+#[test]
+fn test_third_party_contract_verifies_shared_attestation() {
+    let (env, bills) = env_with_bills();
+    let client = setup(&env, &bills);
+    let lender_id = env.register_contract(None, lender::Lender);
+    let lender = lender::LenderClient::new(&env, &lender_id);
+    let user = Address::generate(&env);
+    let hash = client.attest_bill_compliance(&user, &PERIOD_START, &PERIOD_END);
+
+    assert!(!lender.check(&client.address, &hash, &50));
+    client.share_attestation(&user, &hash, &lender_id);
+    assert!(lender.check(&client.address, &hash, &50));
+    assert!(!lender.check(&client.address, &hash, &80));
+}
+
+#[test]
+fn test_verify_attestation_extends_its_ttl() {
+    let (env, bills) = env_with_bills();
+    let client = setup(&env, &bills);
+    let user = Address::generate(&env);
+    let hash = client.attest_bill_compliance(&user, &PERIOD_START, &PERIOD_END);
+    let key = (soroban_sdk::symbol_short!("ATTEST"), hash.clone());
+    let ttl = || env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+
+    // Let the record age until it is below the extension threshold.
+    env.ledger().with_mut(|l| {
+        l.sequence_number += PERSISTENT_BUMP_AMOUNT - PERSISTENT_LIFETIME_THRESHOLD + 1
+    });
+    assert!(ttl() < PERSISTENT_LIFETIME_THRESHOLD);
+
+    client.verify_attestation(&user, &hash);
+    assert_eq!(ttl(), PERSISTENT_BUMP_AMOUNT);
+}
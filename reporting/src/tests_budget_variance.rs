@@ -0,0 +1,375 @@
+extern crate std;
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    BudgetVarianceReport, Category, DataAvailability, ReportingContract, ReportingContractClient,
+    ReportingError,
+};
+
+// 2023-12-19, 2024-01-15 and 2024-02-10 (UTC).
+const DEC_19_2023: u64 = 1_702_944_000;
+const MID_JAN_2024: u64 = 1_705_276_800;
+const FEB_10_2024: u64 = 1_707_523_200;
+
+mod split {
+    use crate::RemittanceSplitTrait;
+    use soroban_sdk::{contract, contractimpl, vec, Env, Vec};
+
+    #[contract]
+    pub struct FixedSplit;
+
+    #[contractimpl]
+    impl RemittanceSplitTrait for FixedSplit {
+        fn get_split(env: &Env) -> Vec<u32> {
+            vec![env, 5_000, 3_000, 1_500, 500]
+        }
+
+        fn calculate_split(env: Env, _total_amount: i128) -> Vec<i128> {
+            Vec::new(&env)
+        }
+    }
+}
+
+mod history {
+    use crate::{RemittanceHistoryTrait, RemittancePeriod};
+    use soroban_sdk::{contract, contractimpl, vec, Address, Env, Vec};
+
+    /// 10_000 received in both December 2023 and January 2024.
+    #[contract]
+    pub struct FixedHistory;
+
+    #[contractimpl]
+    impl RemittanceHistoryTrait for FixedHistory {
+        fn get_remittance_history(
+            env: Env,
+            _owner: Address,
+            from_period: u64,
+            to_period: u64,
+        ) -> Vec<RemittancePeriod> {
+            let mut selected = Vec::new(&env);
+            for period_key in vec![&env, 202312u64, 202401u64].iter() {
+                if period_key >= from_period && period_key <= to_period {
+                    selected.push_back(RemittancePeriod {
+                        period_key,
+                        received: 10_000,
+                        spending: 5_000,
+                        savings: 3_000,
+                        bills: 1_500,
+                        insurance: 500,
+                        flow_count: 1,
+                    });
+                }
+            }
+            selected
+        }
+    }
+}
+
+mod family {
+    use crate::{FamilyWalletTrait, MemberAddressPage, SpendingPeriod, SpendingTracker};
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    /// 6_000 spent in a tracker period starting mid-January.
+    #[contract]
+    pub struct FixedFamily;
+
+    #[contractimpl]
+    impl FamilyWalletTrait for FixedFamily {
+        fn get_owner(env: &Env) -> Address {
+            env.current_contract_address()
+        }
+
+        fn get_member_addresses_page(env: Env, _cursor: u32, _limit: u32) -> MemberAddressPage {
+            MemberAddressPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn get_spending_tracker(_env: Env, _member: Address) -> Option<SpendingTracker> {
+            Some(SpendingTracker {
+                current_spent: 6_000,
+                last_tx_timestamp: super::MID_JAN_2024,
+                tx_count: 3,
+                period: SpendingPeriod {
+                    period_type: 2,
+                    period_start: super::MID_JAN_2024,
+                    period_duration: 30 * 86_400,
+                },
+            })
+        }
+    }
+}
+
+mod savings {
+    use crate::{SavingsSchedule, SavingsScheduleTrait};
+    use soroban_sdk::{contract, contractimpl, vec, Address, Env, Vec};
+
+    fn schedule(env: &Env, amount: i128, last_executed: Option<u64>) -> SavingsSchedule {
+        SavingsSchedule {
+            id: 1,
+            owner: env.current_contract_address(),
+            goal_id: 1,
+            amount,
+            next_due: 0,
+            interval: 30 * 86_400,
+            recurring: true,
+            active: true,
+            created_at: 0,
+            last_executed,
+            missed_count: 0,
+        }
+    }
+
+    #[contract]
+    pub struct FixedSavings;
+
+    #[contractimpl]
+    impl SavingsScheduleTrait for FixedSavings {
+        fn get_savings_schedules(env: Env, _owner: Address) -> Vec<SavingsSchedule> {
+            vec![
+                &env,
+                schedule(&env, 1_000, Some(super::MID_JAN_2024)),
+                schedule(&env, 500, Some(super::DEC_19_2023)),
+                schedule(&env, 700, None),
+            ]
+        }
+    }
+}
+
+mod bills {
+    use crate::{Bill, BillPage, BillPaymentsTrait};
+    use soroban_sdk::{contract, contractimpl, vec, Address, Env, String, Vec};
+
+    fn bill(env: &Env, amount: i128, paid_at: Option<u64>) -> Bill {
+        Bill {
+            id: 1,
+            owner: env.current_contract_address(),
+            name: String::from_str(env, "bill"),
+            external_ref: None,
+            amount,
+            due_date: 0,
+            recurring: false,
+            frequency_days: 0,
+            paid: paid_at.is_some(),
+            created_at: 0,
+            paid_at,
+            schedule_id: None,
+            tags: Vec::new(env),
+            currency: String::from_str(env, "XLM"),
+        }
+    }
+
+    #[contract]
+    pub struct FixedBills;
+
+    #[contractimpl]
+    impl BillPaymentsTrait for FixedBills {
+        fn get_unpaid_bills(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> BillPage {
+            BillPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn get_total_unpaid(_env: Env, _owner: Address) -> i128 {
+            999
+        }
+
+        fn get_all_bills_for_owner(
+            env: Env,
+            _owner: Address,
+            _cursor: u32,
+            _limit: u32,
+        ) -> BillPage {
+            let items = vec![
+                &env,
+                bill(&env, 1_500, Some(super::MID_JAN_2024)),
+                bill(&env, 300, Some(super::FEB_10_2024)),
+                bill(&env, 999, None),
+            ];
+            BillPage {
+                count: items.len(),
+                items,
+                next_cursor: 0,
+            }
+        }
+    }
+}
+
+mod insurance {
+    use crate::{CoverageType, InsurancePolicy, InsuranceTrait, PolicyPage};
+    use soroban_sdk::{contract, contractimpl, vec, Address, Env, String};
+
+    #[contract]
+    pub struct FixedInsurance;
+
+    #[contractimpl]
+    impl InsuranceTrait for FixedInsurance {
+        fn get_active_policies(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> PolicyPage {
+            PolicyPage {
+                items: vec![&env, 1, 2],
+                next_cursor: 0,
+                count: 2,
+            }
+        }
+
+        fn get_policy(env: Env, policy_id: u32) -> Option<InsurancePolicy> {
+            let (monthly_premium, last_payment_at) = if policy_id == 1 {
+                (400, super::MID_JAN_2024)
+            } else {
+                (100, super::DEC_19_2023)
+            };
+            Some(InsurancePolicy {
+                id: policy_id,
+                owner: env.current_contract_address(),
+                name: String::from_str(&env, "policy"),
+                coverage_type: CoverageType::Health,
+                monthly_premium,
+                coverage_amount: 10_000,
+                external_ref: None,
+                active: true,
+                created_at: 0,
+                last_payment_at,
+                next_payment_date: 0,
+            })
+        }
+
+        fn get_total_monthly_premium(_env: Env, _owner: Address) -> i128 {
+            500
+        }
+    }
+}
+
+fn setup(env: &Env, with_history: bool) -> (ReportingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init(&admin);
+
+    client.configure_addresses(
+        &admin,
+        &env.register_contract(None, split::FixedSplit),
+        &env.register_contract(None, savings::FixedSavings),
+        &env.register_contract(None, bills::FixedBills),
+        &env.register_contract(None, insurance::FixedInsurance),
+        &env.register_contract(None, family::FixedFamily),
+    );
+    if with_history {
+        client.configure_remittance_history(
+            &admin,
+            &env.register_contract(None, history::FixedHistory),
+        );
+    }
+    (client, Address::generate(env))
+}
+
+/// `(category, budgeted, actual, variance, over_budget, under_budget)` rows.
+fn rows(report: &BudgetVarianceReport) -> std::vec::Vec<(Category, i128, i128, i128, bool, bool)> {
+    report
+        .categories
+        .iter()
+        .map(|c| {
+            (
+                c.category,
+                c.budgeted,
+                c.actual,
+                c.variance,
+                c.over_budget,
+                c.under_budget,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_budget_variance_compares_split_with_actuals() {
+    let env = Env::default();
+    let (client, user) = setup(&env, true);
+
+    let report = client.get_budget_variance_report(&user, &202401);
+    assert_eq!(report.data_availability, DataAvailability::Complete);
+    assert_eq!(report.period_start, 1_704_067_200);
+    assert_eq!(report.period_end, 1_706_745_599);
+    assert_eq!(report.total_received, 10_000);
+    assert_eq!(
+        rows(&report),
+        std::vec![
+            (Category::Spending, 5_000, 6_000, -1_000, true, false),
+            (Category::Savings, 3_000, 1_000, 2_000, false, true),
+            (Category::Bills, 1_500, 1_500, 0, false, false),
+            (Category::Insurance, 500, 400, 100, false, true),
+        ]
+    );
+}
+
+#[test]
+fn test_budget_variance_december_ends_at_new_year() {
+    let env = Env::default();
+    let (client, user) = setup(&env, true);
+
+    let report = client.get_budget_variance_report(&user, &202312);
+    assert_eq!(report.period_end, 1_704_067_199);
+    // Only December activity counts: the mid-January tracker and bill do not.
+    assert_eq!(
+        rows(&report),
+        std::vec![
+            (Category::Spending, 5_000, 0, 5_000, false, true),
+            (Category::Savings, 3_000, 500, 2_500, false, true),
+            (Category::Bills, 1_500, 0, 1_500, false, true),
+            (Category::Insurance, 500, 100, 400, false, true),
+        ]
+    );
+}
+
+#[test]
+fn test_budget_variance_rejects_invalid_period_keys() {
+    let env = Env::default();
+    let (client, user) = setup(&env, true);
+
+    for period_key in [202400u64, 202413, 196912, 0] {
+        assert!(matches!(
+            client.try_get_budget_variance_report(&user, &period_key),
+            Err(Ok(ReportingError::InvalidPeriod))
+        ));
+    }
+}
+
+#[test]
+fn test_budget_variance_without_history_is_missing() {
+    let env = Env::default();
+    let (client, user) = setup(&env, false);
+
+    let report = client.get_budget_variance_report(&user, &202401);
+    assert_eq!(report.data_availability, DataAvailability::Missing);
+    assert_eq!(report.total_received, 0);
+    let spending = report.categories.get(0).unwrap();
+    assert_eq!(spending.budgeted, 0);
+    assert_eq!(spending.actual, 6_000);
+    assert!(spending.over_budget);
+}
+
+#[test]
+fn test_budget_variance_unreadable_tracker_is_partial() {
+    let env = Env::default();
+    let (client, user) = setup(&env, true);
+    let admin = client.get_admin().unwrap();
+    let addresses = client.get_addresses().unwrap();
+    client.configure_addresses(
+        &admin,
+        &addresses.remittance_split,
+        &addresses.savings_goals,
+        &addresses.bill_payments,
+        &addresses.insurance,
+        &Address::generate(&env),
+    );
+
+    let report = client.get_budget_variance_report(&user, &202401);
+    assert_eq!(report.data_availability, DataAvailability::Partial);
+    assert_eq!(report.categories.get(0).unwrap().actual, 0);
+}
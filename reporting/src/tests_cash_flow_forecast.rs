@@ -0,0 +1,505 @@
+extern crate std;
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String, Vec};
+use testutils::set_ledger_time;
+
+use crate::{
+    Bill, BillSchedule, CashFlowForecast, Category, DataAvailability, NextPaymentSchedule,
+    RemittanceSchedule, ReportingContract, ReportingContractClient, ReportingError,
+    SavingsSchedule,
+};
+
+const NOW: u64 = 1_704_067_200;
+const DAY: u64 = 86_400;
+
+mod split {
+    use crate::{RemittanceSchedule, RemittanceScheduleTrait, RemittanceSplitTrait};
+    use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Env, Vec};
+
+    /// 50/30/15/5 split with seeded remittance schedules.
+    #[contract]
+    pub struct ScheduledSplit;
+
+    #[contractimpl]
+    impl ScheduledSplit {
+        pub fn seed(env: Env, schedules: Vec<RemittanceSchedule>) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("SCHED"), &schedules);
+        }
+    }
+
+    #[contractimpl]
+    impl RemittanceSplitTrait for ScheduledSplit {
+        fn get_split(env: &Env) -> Vec<u32> {
+            vec![env, 5_000, 3_000, 1_500, 500]
+        }
+
+        fn calculate_split(env: Env, _total_amount: i128) -> Vec<i128> {
+            Vec::new(&env)
+        }
+    }
+
+    #[contractimpl]
+    impl RemittanceScheduleTrait for ScheduledSplit {
+        fn get_remittance_schedules(env: Env, _owner: Address) -> Vec<RemittanceSchedule> {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("SCHED"))
+                .unwrap_or_else(|| Vec::new(&env))
+        }
+    }
+}
+
+mod bills {
+    use crate::{Bill, BillPage, BillPaymentsTrait, BillSchedule, BillScheduleTrait};
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Vec};
+
+    #[contract]
+    pub struct ScheduledBills;
+
+    #[contractimpl]
+    impl ScheduledBills {
+        pub fn seed(env: Env, unpaid: Vec<Bill>, schedules: Vec<BillSchedule>) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("UNPAID"), &unpaid);
+            env.storage()
+                .instance()
+                .set(&symbol_short!("SCHED"), &schedules);
+        }
+    }
+
+    #[contractimpl]
+    impl BillPaymentsTrait for ScheduledBills {
+        fn get_unpaid_bills(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> BillPage {
+            let items: Vec<Bill> = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("UNPAID"))
+                .unwrap_or_else(|| Vec::new(&env));
+            BillPage {
+                count: items.len(),
+                items,
+                next_cursor: 0,
+            }
+        }
+
+        fn get_total_unpaid(_env: Env, _owner: Address) -> i128 {
+            0
+        }
+
+        fn get_all_bills_for_owner(env: Env, owner: Address, cursor: u32, limit: u32) -> BillPage {
+            Self::get_unpaid_bills(env, owner, cursor, limit)
+        }
+    }
+
+    #[contractimpl]
+    impl BillScheduleTrait for ScheduledBills {
+        fn get_bill_schedules(env: Env, _owner: Address) -> Vec<BillSchedule> {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("SCHED"))
+                .unwrap_or_else(|| Vec::new(&env))
+        }
+    }
+}
+
+mod premiums {
+    use crate::{NextPaymentSchedule, PremiumScheduleTrait};
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Vec};
+
+    #[contract]
+    pub struct ScheduledPremiums;
+
+    #[contractimpl]
+    impl ScheduledPremiums {
+        pub fn seed(env: Env, schedules: Vec<NextPaymentSchedule>) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("SCHED"), &schedules);
+        }
+    }
+
+    #[contractimpl]
+    impl PremiumScheduleTrait for ScheduledPremiums {
+        fn get_premium_schedules(env: Env, _owner: Address) -> Vec<NextPaymentSchedule> {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("SCHED"))
+                .unwrap_or_else(|| Vec::new(&env))
+        }
+    }
+}
+
+mod savings {
+    use crate::{SavingsSchedule, SavingsScheduleTrait};
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Vec};
+
+    #[contract]
+    pub struct ScheduledSavings;
+
+    #[contractimpl]
+    impl ScheduledSavings {
+        pub fn seed(env: Env, schedules: Vec<SavingsSchedule>) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("SCHED"), &schedules);
+        }
+    }
+
+    #[contractimpl]
+    impl SavingsScheduleTrait for ScheduledSavings {
+        fn get_savings_schedules(env: Env, _owner: Address) -> Vec<SavingsSchedule> {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("SCHED"))
+                .unwrap_or_else(|| Vec::new(&env))
+        }
+    }
+}
+
+struct Deps<'a> {
+    split: split::ScheduledSplitClient<'a>,
+    bills: bills::ScheduledBillsClient<'a>,
+    premiums: premiums::ScheduledPremiumsClient<'a>,
+    savings: savings::ScheduledSavingsClient<'a>,
+}
+
+fn setup(env: &Env) -> (ReportingContractClient<'_>, Address, Deps<'_>) {
+    env.mock_all_auths();
+    set_ledger_time(env, 1, NOW);
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init(&admin);
+
+    let split_id = env.register_contract(None, split::ScheduledSplit);
+    let bills_id = env.register_contract(None, bills::ScheduledBills);
+    let premiums_id = env.register_contract(None, premiums::ScheduledPremiums);
+    let savings_id = env.register_contract(None, savings::ScheduledSavings);
+    client.configure_addresses(
+        &admin,
+        &split_id,
+        &savings_id,
+        &bills_id,
+        &premiums_id,
+        &Address::generate(env),
+    );
+    let deps = Deps {
+        split: split::ScheduledSplitClient::new(env, &split_id),
+        bills: bills::ScheduledBillsClient::new(env, &bills_id),
+        premiums: premiums::ScheduledPremiumsClient::new(env, &premiums_id),
+        savings: savings::ScheduledSavingsClient::new(env, &savings_id),
+    };
+    (client, Address::generate(env), deps)
+}
+
+fn remittance_schedule(
+    env: &Env,
+    amount: i128,
+    next_due: u64,
+    interval: u64,
+) -> RemittanceSchedule {
+    RemittanceSchedule {
+        id: 1,
+        owner: Address::generate(env),
+        amount,
+        next_due,
+        interval,
+        recurring: interval > 0,
+        active: true,
+        created_at: 0,
+        last_executed: None,
+        missed_count: 0,
+    }
+}
+
+fn bill(env: &Env, amount: i128, due_date: u64, frequency_days: u32, currency: &str) -> Bill {
+    Bill {
+        id: 1,
+        owner: Address::generate(env),
+        name: String::from_str(env, "bill"),
+        external_ref: None,
+        amount,
+        due_date,
+        recurring: frequency_days > 0,
+        frequency_days,
+        paid: false,
+        created_at: 0,
+        paid_at: None,
+        schedule_id: None,
+        tags: Vec::new(env),
+        currency: String::from_str(env, currency),
+    }
+}
+
+fn bill_schedule(env: &Env, amount: i128, next_due: u64, currency: &str) -> BillSchedule {
+    BillSchedule {
+        id: 1,
+        owner: Address::generate(env),
+        name: String::from_str(env, "rent"),
+        amount,
+        currency: String::from_str(env, currency),
+        next_due,
+        interval: 0,
+        recurring: false,
+        active: true,
+        created_at: 0,
+        last_executed: None,
+        missed_count: 0,
+    }
+}
+
+fn premium_schedule(env: &Env, amount: i128, next_due: u64, interval: u64) -> NextPaymentSchedule {
+    NextPaymentSchedule {
+        id: 1,
+        owner: Address::generate(env),
+        policy_id: 1,
+        amount,
+        next_due,
+        interval,
+        recurring: interval > 0,
+        active: true,
+        created_at: 0,
+        last_executed: None,
+        missed_count: 0,
+    }
+}
+
+fn savings_schedule(env: &Env, amount: i128, next_due: u64, interval: u64) -> SavingsSchedule {
+    SavingsSchedule {
+        id: 1,
+        owner: Address::generate(env),
+        goal_id: 1,
+        amount,
+        next_due,
+        interval,
+        recurring: interval > 0,
+        active: true,
+        created_at: 0,
+        last_executed: None,
+        missed_count: 0,
+    }
+}
+
+/// `(category, currency, amount)` per flow in one period.
+fn flows(
+    forecast: &CashFlowForecast,
+    period: u32,
+    outflows: bool,
+) -> std::vec::Vec<(Category, std::string::String, i128)> {
+    let period = forecast.periods.get(period).unwrap();
+    let flows = if outflows {
+        period.outflows
+    } else {
+        period.inflows
+    };
+    flows
+        .iter()
+        .map(|f| {
+            let mut buf = [0u8; 8];
+            let len = f.currency.len() as usize;
+            f.currency.copy_into_slice(&mut buf[..len]);
+            let currency = std::string::String::from_utf8(buf[..len].to_vec()).unwrap();
+            (f.category, currency, f.amount)
+        })
+        .collect()
+}
+
+fn xlm(category: Category, amount: i128) -> (Category, std::string::String, i128) {
+    (category, "XLM".into(), amount)
+}
+
+#[test]
+fn test_forecast_rejects_invalid_windows() {
+    let env = Env::default();
+    let (client, user, _) = setup(&env);
+
+    for (horizon, period) in [(0, 0), (91, 30), (30, 0), (30, 31)] {
+        assert!(matches!(
+            client.try_get_cash_flow_forecast(&user, &horizon, &period),
+            Err(Ok(ReportingError::InvalidForecastWindow))
+        ));
+    }
+}
+
+#[test]
+fn test_forecast_requires_configured_addresses() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(&env, &contract_id);
+    client.init(&Address::generate(&env));
+
+    assert!(matches!(
+        client.try_get_cash_flow_forecast(&Address::generate(&env), &30, &30),
+        Err(Ok(ReportingError::AddressesNotConfigured))
+    ));
+}
+
+#[test]
+fn test_forecast_projects_schedules_and_flags_first_shortfall() {
+    let env = Env::default();
+    let (client, user, deps) = setup(&env);
+
+    deps.split.seed(&vec![
+        &env,
+        remittance_schedule(&env, 1_000, NOW + 5 * DAY, 30 * DAY),
+    ]);
+    deps.bills.seed(
+        &vec![
+            &env,
+            bill(&env, 50, NOW - 3 * DAY, 0, "XLM"),
+            bill(&env, 700, NOW + 40 * DAY, 30, "XLM"),
+        ],
+        &Vec::new(&env),
+    );
+    deps.premiums.seed(&vec![
+        &env,
+        premium_schedule(&env, 100, NOW + 10 * DAY, 30 * DAY),
+    ]);
+    deps.savings.seed(&vec![
+        &env,
+        savings_schedule(&env, 200, NOW + DAY, 15 * DAY),
+    ]);
+
+    let forecast = client.get_cash_flow_forecast(&user, &90, &30);
+    assert_eq!(forecast.data_availability, DataAvailability::Complete);
+    assert_eq!(forecast.generated_at, NOW);
+    assert_eq!(forecast.periods.len(), 3);
+    let first = forecast.periods.get(0).unwrap();
+    assert_eq!(first.period_start, NOW);
+    assert_eq!(first.period_end, NOW + 30 * DAY);
+
+    let inflows = std::vec![
+        xlm(Category::Spending, 500),
+        xlm(Category::Savings, 300),
+        xlm(Category::Bills, 150),
+        xlm(Category::Insurance, 50),
+    ];
+    for period in 0..3 {
+        assert_eq!(flows(&forecast, period, false), inflows);
+    }
+
+    // Period 0: overdue bill, one premium, two savings contributions.
+    assert_eq!(
+        flows(&forecast, 0, true),
+        std::vec![
+            xlm(Category::Savings, 400),
+            xlm(Category::Bills, 50),
+            xlm(Category::Insurance, 100),
+        ]
+    );
+    assert!(!first.shortfall);
+    // Period 1: the recurring bill starts and outflows reach 1_200.
+    assert_eq!(
+        flows(&forecast, 1, true),
+        std::vec![
+            xlm(Category::Savings, 400),
+            xlm(Category::Bills, 700),
+            xlm(Category::Insurance, 100),
+        ]
+    );
+    assert!(forecast.periods.get(1).unwrap().shortfall);
+    assert_eq!(forecast.first_shortfall_period, Some(1));
+}
+
+#[test]
+fn test_forecast_compares_inflows_and_outflows_per_currency() {
+    let env = Env::default();
+    let (client, user, deps) = setup(&env);
+
+    deps.split
+        .seed(&vec![&env, remittance_schedule(&env, 1_000, NOW + DAY, 0)]);
+    deps.bills.seed(
+        &Vec::new(&env),
+        &vec![&env, bill_schedule(&env, 10, NOW + 2 * DAY, "USDC")],
+    );
+
+    let forecast = client.get_cash_flow_forecast(&user, &30, &30);
+    assert_eq!(
+        flows(&forecast, 0, true),
+        std::vec![(Category::Bills, "USDC".into(), 10)]
+    );
+    // A surplus in XLM does not cover a USDC bill.
+    assert_eq!(forecast.first_shortfall_period, Some(0));
+}
+
+#[test]
+fn test_forecast_counts_schedule_generated_bills_once() {
+    let env = Env::default();
+    let (client, user, deps) = setup(&env);
+
+    // The schedule generated the bill due in 5 days and moved on to the next
+    // month; both recur every 30 days.
+    let mut generated = bill(&env, 300, NOW + 5 * DAY, 30, "XLM");
+    generated.schedule_id = Some(1);
+    let mut schedule = bill_schedule(&env, 300, NOW + 35 * DAY, "XLM");
+    schedule.interval = 30 * DAY;
+    schedule.recurring = true;
+    deps.bills
+        .seed(&vec![&env, generated], &vec![&env, schedule]);
+
+    let forecast = client.get_cash_flow_forecast(&user, &90, &30);
+    for period in 0..3 {
+        assert_eq!(
+            flows(&forecast, period, true),
+            std::vec![xlm(Category::Bills, 300)]
+        );
+    }
+}
+
+#[test]
+fn test_forecast_catches_up_overdue_schedules_once() {
+    let env = Env::default();
+    let (client, user, deps) = setup(&env);
+
+    // Overdue by 20 days on a 15-day interval: one catch-up payment now, then
+    // NOW + 10d and NOW + 25d.
+    deps.savings.seed(&vec![
+        &env,
+        savings_schedule(&env, 100, NOW - 20 * DAY, 15 * DAY),
+    ]);
+
+    let forecast = client.get_cash_flow_forecast(&user, &45, &30);
+    assert_eq!(forecast.periods.len(), 2);
+    assert_eq!(forecast.periods.get(1).unwrap().period_end, NOW + 45 * DAY);
+    assert_eq!(
+        flows(&forecast, 0, true),
+        std::vec![xlm(Category::Savings, 300)]
+    );
+    assert_eq!(
+        flows(&forecast, 1, true),
+        std::vec![xlm(Category::Savings, 100)]
+    );
+}
+
+#[test]
+fn test_forecast_marks_unreadable_sources_partial() {
+    let env = Env::default();
+    let (client, user, deps) = setup(&env);
+    let admin = client.get_admin().unwrap();
+    let addresses = client.get_addresses().unwrap();
+
+    // Point insurance at an address with no premium schedules to read.
+    client.configure_addresses(
+        &admin,
+        &addresses.remittance_split,
+        &addresses.savings_goals,
+        &addresses.bill_payments,
+        &Address::generate(&env),
+        &addresses.family_wallet,
+    );
+    deps.savings
+        .seed(&vec![&env, savings_schedule(&env, 100, NOW + DAY, 0)]);
+
+    let forecast = client.get_cash_flow_forecast(&user, &30, &30);
+    assert_eq!(forecast.data_availability, DataAvailability::Partial);
+    assert_eq!(
+        flows(&forecast, 0, true),
+        std::vec![xlm(Category::Savings, 100)]
+    );
+    assert_eq!(forecast.first_shortfall_period, Some(0));
+}
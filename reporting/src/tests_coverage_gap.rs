@@ -0,0 +1,448 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, vec, Address, Env, Vec};
+
+use crate::{
+    CoverageBenchmark, CoverageGapConfig, CoverageGapReport, CoverageType, DataAvailability,
+    ReportType, ReportingContract, ReportingContractClient, ReportingError,
+    MAX_COVERAGE_GAP_MEMBERS,
+};
+
+// 2024-01-15 (UTC).
+const NOW: u64 = 1_705_276_800;
+const DAY: u64 = 86_400;
+
+mod insurance {
+    use crate::{CoverageType, InsurancePolicy, InsuranceTrait, PolicyPage};
+    use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Env, String, Vec};
+
+    fn policy(
+        env: &Env,
+        id: u32,
+        coverage_type: CoverageType,
+        coverage_amount: i128,
+        next_payment_date: u64,
+    ) -> InsurancePolicy {
+        InsurancePolicy {
+            id,
+            owner: env.current_contract_address(),
+            name: String::from_str(env, "policy"),
+            coverage_type,
+            monthly_premium: 10,
+            coverage_amount,
+            external_ref: None,
+            active: true,
+            created_at: 0,
+            last_payment_at: 0,
+            next_payment_date,
+        }
+    }
+
+    /// Health 1_000 due in 30 days, Life 20_000 overdue by a day, a second
+    /// Life 5_000 due in 3 days, and an unknown policy id 9. Owners listed
+    /// under `AUTO` instead hold only Auto 3_000 due in 60 days.
+    #[contract]
+    pub struct Policies;
+
+    #[contractimpl]
+    impl InsuranceTrait for Policies {
+        fn get_active_policies(env: Env, owner: Address, _cursor: u32, _limit: u32) -> PolicyPage {
+            if env.storage().instance().has(&symbol_short!("DOWN")) {
+                panic!("insurance unavailable");
+            }
+            let auto_owners: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("AUTO"))
+                .unwrap_or_else(|| Vec::new(&env));
+            let items = if auto_owners.contains(&owner) {
+                vec![&env, 4]
+            } else {
+                vec![&env, 1, 2, 3, 9]
+            };
+            PolicyPage {
+                count: items.len(),
+                items,
+                next_cursor: 0,
+            }
+        }
+
+        fn get_policy(env: Env, policy_id: u32) -> Option<InsurancePolicy> {
+            let policies: Vec<InsurancePolicy> = vec![
+                &env,
+                policy(
+                    &env,
+                    1,
+                    CoverageType::Health,
+                    1_000,
+                    super::NOW + 30 * super::DAY,
+                ),
+                policy(&env, 2, CoverageType::Life, 20_000, super::NOW - super::DAY),
+                policy(
+                    &env,
+                    3,
+                    CoverageType::Life,
+                    5_000,
+                    super::NOW + 3 * super::DAY,
+                ),
+                policy(
+                    &env,
+                    4,
+                    CoverageType::Auto,
+                    3_000,
+                    super::NOW + 60 * super::DAY,
+                ),
+            ];
+            policies.iter().find(|p| p.id == policy_id)
+        }
+
+        fn get_total_monthly_premium(_env: Env, _owner: Address) -> i128 {
+            30
+        }
+    }
+}
+
+mod family {
+    use crate::{FamilyWalletTrait, MemberAddressPage, SpendingTracker};
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Vec};
+
+    /// Owner and members are seeded into instance storage by the test.
+    #[contract]
+    pub struct Household;
+
+    #[contractimpl]
+    impl FamilyWalletTrait for Household {
+        fn get_owner(env: &Env) -> Address {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("OWNER"))
+                .unwrap()
+        }
+
+        fn get_member_addresses_page(env: Env, cursor: u32, limit: u32) -> MemberAddressPage {
+            let members: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("MEMBERS"))
+                .unwrap_or_else(|| Vec::new(&env));
+            let end = cursor.saturating_add(limit).min(members.len());
+            let items = if cursor < end {
+                members.slice(cursor..end)
+            } else {
+                Vec::new(&env)
+            };
+            MemberAddressPage {
+                count: items.len(),
+                items,
+                next_cursor: if end < members.len() { end } else { 0 },
+            }
+        }
+
+        fn get_spending_tracker(_env: Env, _member: Address) -> Option<SpendingTracker> {
+            None
+        }
+    }
+}
+
+mod history {
+    use crate::{RemittanceHistoryTrait, RemittancePeriod};
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    /// 1_000 received in each month of the requested range.
+    #[contract]
+    pub struct Monthly;
+
+    #[contractimpl]
+    impl RemittanceHistoryTrait for Monthly {
+        fn get_remittance_history(
+            env: Env,
+            _owner: Address,
+            from_period: u64,
+            to_period: u64,
+        ) -> Vec<RemittancePeriod> {
+            let mut periods = Vec::new(&env);
+            let mut period_key = from_period;
+            while period_key <= to_period {
+                periods.push_back(RemittancePeriod {
+                    period_key,
+                    received: 1_000,
+                    spending: 0,
+                    savings: 0,
+                    bills: 0,
+                    insurance: 0,
+                    flow_count: 1,
+                });
+                period_key = if period_key % 100 == 12 {
+                    period_key + 89
+                } else {
+                    period_key + 1
+                };
+            }
+            periods
+        }
+    }
+}
+
+/// Returns the client, admin, insurance contract and the family wallet owner.
+fn setup(
+    env: &Env,
+    with_history: bool,
+) -> (ReportingContractClient<'_>, Address, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = NOW);
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init(&admin);
+
+    let insurance = env.register_contract(None, insurance::Policies);
+    let family = env.register_contract(None, family::Household);
+    client.configure_addresses(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &insurance,
+        &family,
+    );
+    if with_history {
+        client.configure_remittance_history(&admin, &env.register_contract(None, history::Monthly));
+    }
+    let owner = Address::generate(env);
+    env.as_contract(&family, || {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("OWNER"), &owner)
+    });
+    (client, admin, insurance, owner)
+}
+
+/// Seed `members` into the configured family wallet.
+fn seed_members(env: &Env, client: &ReportingContractClient, members: &Vec<Address>) {
+    let family = client.get_addresses().unwrap().family_wallet;
+    env.as_contract(&family, || {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("MEMBERS"), members)
+    });
+}
+
+fn benchmarks(env: &Env) -> CoverageGapConfig {
+    CoverageGapConfig {
+        benchmarks: vec![
+            env,
+            CoverageBenchmark {
+                coverage_type: CoverageType::Life,
+                min_coverage: 10_000,
+                annual_remittance_multiple: 3,
+            },
+            CoverageBenchmark {
+                coverage_type: CoverageType::Property,
+                min_coverage: 2_000,
+                annual_remittance_multiple: 0,
+            },
+        ],
+        lapse_warning_window: 7 * DAY,
+    }
+}
+
+/// `(coverage_type, policies, coverage, required_coverage, shortfall)` row.
+fn row(report: &CoverageGapReport, index: u32) -> (CoverageType, u32, i128, i128, i128) {
+    let gap = report.coverage.get(index).unwrap();
+    (
+        gap.coverage_type,
+        gap.policies,
+        gap.coverage,
+        gap.required_coverage,
+        gap.shortfall,
+    )
+}
+
+#[test]
+fn test_coverage_gaps_against_benchmarks() {
+    let env = Env::default();
+    let (client, admin, _, user) = setup(&env, true);
+    client.set_coverage_gap_config(&admin, &benchmarks(&env));
+
+    let report = client.get_coverage_gap_report(&user, &user);
+    // Unknown policy 9 is skipped without degrading the report.
+    assert_eq!(report.data_availability, DataAvailability::Complete);
+    assert_eq!(report.members, vec![&env, user.clone()]);
+    // February 2023 through January 2024.
+    assert_eq!(report.annual_remittance, 12_000);
+    assert_eq!(report.coverage.len(), 5);
+    assert_eq!(row(&report, 0), (CoverageType::Health, 1, 1_000, 0, 0));
+    assert_eq!(
+        row(&report, 1),
+        (CoverageType::Life, 2, 25_000, 36_000, 11_000)
+    );
+    assert_eq!(
+        row(&report, 2),
+        (CoverageType::Property, 0, 0, 2_000, 2_000)
+    );
+    assert_eq!(
+        report.missing_types,
+        vec![
+            &env,
+            CoverageType::Property,
+            CoverageType::Auto,
+            CoverageType::Liability
+        ]
+    );
+}
+
+#[test]
+fn test_coverage_gaps_cover_the_household() {
+    let env = Env::default();
+    let (client, admin, insurance, user) = setup(&env, true);
+    client.set_coverage_gap_config(&admin, &benchmarks(&env));
+    let driver = Address::generate(&env);
+    env.as_contract(&insurance, || {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("AUTO"), &vec![&env, driver.clone()])
+    });
+    // The owner listed as a member is only counted once.
+    seed_members(&env, &client, &vec![&env, user.clone(), driver.clone()]);
+
+    let report = client.get_coverage_gap_report(&user, &user);
+    assert_eq!(report.data_availability, DataAvailability::Complete);
+    assert_eq!(report.members, vec![&env, user.clone(), driver]);
+    assert_eq!(report.annual_remittance, 24_000);
+    assert_eq!(
+        row(&report, 1),
+        (CoverageType::Life, 2, 25_000, 72_000, 47_000)
+    );
+    assert_eq!(row(&report, 3), (CoverageType::Auto, 1, 3_000, 0, 0));
+    assert_eq!(
+        report.missing_types,
+        vec![&env, CoverageType::Property, CoverageType::Liability]
+    );
+}
+
+#[test]
+fn test_coverage_gaps_beyond_member_cap_are_partial() {
+    let env = Env::default();
+    let (client, _, _, user) = setup(&env, true);
+    let mut members = Vec::new(&env);
+    for _ in 0..=MAX_COVERAGE_GAP_MEMBERS {
+        members.push_back(Address::generate(&env));
+    }
+    seed_members(&env, &client, &members);
+
+    let report = client.get_coverage_gap_report(&user, &user);
+    assert_eq!(report.data_availability, DataAvailability::Partial);
+    assert_eq!(report.members.len(), MAX_COVERAGE_GAP_MEMBERS + 1);
+}
+
+#[test]
+fn test_overdue_and_soon_due_policies_are_lapse_risks() {
+    let env = Env::default();
+    let (client, _, _, user) = setup(&env, true);
+
+    let report = client.get_coverage_gap_report(&user, &user);
+    assert_eq!(report.lapse_risks.len(), 2);
+    let overdue = report.lapse_risks.get(0).unwrap();
+    assert_eq!(overdue.policy_id, 2);
+    assert_eq!(overdue.next_payment_date, NOW - DAY);
+    assert!(overdue.past_due);
+    let soon = report.lapse_risks.get(1).unwrap();
+    assert_eq!(soon.policy_id, 3);
+    assert_eq!(soon.coverage_type, CoverageType::Life);
+    assert!(!soon.past_due);
+}
+
+#[test]
+fn test_income_benchmark_without_history_is_partial() {
+    let env = Env::default();
+    let (client, admin, _, user) = setup(&env, false);
+    client.set_coverage_gap_config(&admin, &benchmarks(&env));
+
+    let report = client.get_coverage_gap_report(&user, &user);
+    assert_eq!(report.annual_remittance, 0);
+    assert_eq!(report.data_availability, DataAvailability::Partial);
+    // Only the fixed floor applies.
+    assert_eq!(row(&report, 1), (CoverageType::Life, 2, 25_000, 10_000, 0));
+}
+
+#[test]
+fn test_unreachable_insurance_flags_nothing() {
+    let env = Env::default();
+    let (client, _, insurance, user) = setup(&env, true);
+    env.as_contract(&insurance, || {
+        env.storage().instance().set(&symbol_short!("DOWN"), &true)
+    });
+
+    let report = client.get_coverage_gap_report(&user, &user);
+    assert_eq!(report.data_availability, DataAvailability::Missing);
+    assert!(report.missing_types.is_empty());
+    assert!(report.lapse_risks.is_empty());
+}
+
+#[test]
+fn test_coverage_gap_config_is_admin_only_and_validated() {
+    let env = Env::default();
+    let (client, admin, _, _) = setup(&env, true);
+    let config = benchmarks(&env);
+
+    assert_eq!(client.get_coverage_gap_config().benchmarks.len(), 0);
+    assert!(matches!(
+        client.try_set_coverage_gap_config(&Address::generate(&env), &config),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+
+    let benchmark = |coverage_type, min_coverage, annual_remittance_multiple| CoverageBenchmark {
+        coverage_type,
+        min_coverage,
+        annual_remittance_multiple,
+    };
+    let invalid: [Vec<CoverageBenchmark>; 3] = [
+        vec![&env, benchmark(CoverageType::Auto, -1, 1)],
+        vec![&env, benchmark(CoverageType::Auto, 0, 0)],
+        vec![
+            &env,
+            benchmark(CoverageType::Auto, 1, 0),
+            benchmark(CoverageType::Auto, 0, 1),
+        ],
+    ];
+    for benchmarks in invalid {
+        let bad = CoverageGapConfig {
+            benchmarks,
+            ..config.clone()
+        };
+        assert!(matches!(
+            client.try_set_coverage_gap_config(&admin, &bad),
+            Err(Ok(ReportingError::InvalidCoverageGapConfig))
+        ));
+    }
+
+    client.set_coverage_gap_config(&admin, &config);
+    assert_eq!(client.get_coverage_gap_config(), config);
+}
+
+#[test]
+fn test_coverage_gap_report_requires_owner_or_grant() {
+    let env = Env::default();
+    let (client, _, _, user) = setup(&env, true);
+    let adviser = Address::generate(&env);
+    let member = Address::generate(&env);
+    seed_members(&env, &client, &vec![&env, member.clone()]);
+
+    // Only the family wallet owner can be reported on.
+    assert!(matches!(
+        client.try_get_coverage_gap_report(&member, &member),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+
+    assert!(matches!(
+        client.try_get_coverage_gap_report(&adviser, &user),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+    client.grant_report_access(&user, &adviser, &ReportType::CoverageGap, &(NOW + 1));
+    assert_eq!(
+        client
+            .get_coverage_gap_report(&adviser, &user)
+            .coverage
+            .len(),
+        5
+    );
+}
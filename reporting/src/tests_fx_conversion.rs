@@ -0,0 +1,347 @@
+use remitwise_common::oracle::PriceOracle;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, Env, String as SorobanString, Vec,
+};
+use testutils::set_ledger_time;
+
+use crate::{
+    Bill, BillPage, BillPaymentsTrait, DataAvailability, OracleConfig, PriceData,
+    RemittanceSplitTrait, ReportingContract, ReportingContractClient, ReportingError,
+};
+
+const PERIOD_START: u64 = 1_704_067_200;
+const PERIOD_END: u64 = 1_706_745_600;
+const NOW: u64 = PERIOD_END;
+const MAX_AGE: u64 = 3_600;
+
+fn create_env() -> Env {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_ledger_time(&env, 1, NOW);
+    env
+}
+
+fn currency(env: &Env, code: &str) -> SorobanString {
+    SorobanString::from_str(env, code)
+}
+
+fn bill(env: &Env, owner: &Address, id: u32, amount: i128, code: &str, paid: bool) -> Bill {
+    Bill {
+        id,
+        owner: owner.clone(),
+        name: SorobanString::from_str(env, "Bill"),
+        external_ref: None,
+        amount,
+        due_date: PERIOD_END + 1,
+        recurring: false,
+        frequency_days: 0,
+        paid,
+        created_at: PERIOD_START,
+        paid_at: if paid { Some(PERIOD_START) } else { None },
+        schedule_id: None,
+        tags: Vec::new(env),
+        currency: currency(env, code),
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    client: ReportingContractClient<'a>,
+    admin: Address,
+    oracle: Address,
+    user: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = create_env();
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    let split_id = env.register_contract(None, fx_split::FxSplit);
+    let bills_id = env.register_contract(None, fx_bills::FxBills);
+    let oracle = env.register_contract(None, fx_oracle::FxOracle);
+    client.configure_addresses(
+        &admin,
+        &split_id,
+        &Address::generate(&env),
+        &bills_id,
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    // 1 NGN = 0.0650 KES, quoted 10 minutes ago.
+    fx_oracle::FxOracleClient::new(&env, &oracle).set_price(
+        &currency(&env, "NGN"),
+        &currency(&env, "KES"),
+        &PriceData {
+            price: 650,
+            decimals: 4,
+            timestamp: NOW - 600,
+        },
+    );
+    // 1 USD = 129.50 KES, quoted 20 minutes ago.
+    fx_oracle::FxOracleClient::new(&env, &oracle).set_price(
+        &currency(&env, "USD"),
+        &currency(&env, "KES"),
+        &PriceData {
+            price: 12_950,
+            decimals: 2,
+            timestamp: NOW - 1_200,
+        },
+    );
+
+    let user = Address::generate(&env);
+    let mut bills = Vec::new(&env);
+    bills.push_back(bill(&env, &user, 1, 100_000, "NGN", true));
+    bills.push_back(bill(&env, &user, 2, 40, "USD", false));
+    bills.push_back(bill(&env, &user, 3, 500, "KES", true));
+    fx_bills::FxBillsClient::new(&env, &bills_id).seed(&bills);
+
+    Setup {
+        env,
+        client,
+        admin,
+        oracle,
+        user,
+    }
+}
+
+mod fx_oracle {
+    use super::*;
+
+    #[contract]
+    pub struct FxOracle;
+
+    #[contractimpl]
+    impl FxOracle {
+        pub fn set_price(env: Env, base: SorobanString, quote: SorobanString, price: PriceData) {
+            env.storage().instance().set(&(base, quote), &price);
+        }
+    }
+
+    #[contractimpl]
+    impl PriceOracle for FxOracle {
+        fn get_price(env: Env, base: SorobanString, quote: SorobanString) -> Option<PriceData> {
+            env.storage().instance().get(&(base, quote))
+        }
+    }
+}
+
+mod fx_split {
+    use super::*;
+
+    #[contract]
+    pub struct FxSplit;
+
+    #[contractimpl]
+    impl RemittanceSplitTrait for FxSplit {
+        fn get_split(env: &Env) -> Vec<u32> {
+            let mut split = Vec::new(env);
+            split.push_back(5000);
+            split.push_back(3000);
+            split.push_back(1500);
+            split.push_back(500);
+            split
+        }
+
+        fn calculate_split(env: Env, total_amount: i128) -> Vec<i128> {
+            let mut amounts = Vec::new(&env);
+            amounts.push_back(total_amount * 5000 / 10_000);
+            amounts.push_back(total_amount * 3000 / 10_000);
+            amounts.push_back(total_amount * 1500 / 10_000);
+            amounts.push_back(total_amount * 500 / 10_000);
+            amounts
+        }
+    }
+}
+
+mod fx_bills {
+    use super::*;
+
+    #[contract]
+    pub struct FxBills;
+
+    #[contractimpl]
+    impl FxBills {
+        pub fn seed(env: Env, bills: Vec<Bill>) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("BILLS"), &bills);
+        }
+    }
+
+    #[contractimpl]
+    impl BillPaymentsTrait for FxBills {
+        fn get_unpaid_bills(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> BillPage {
+            BillPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn get_total_unpaid(_env: Env, _owner: Address) -> i128 {
+            0
+        }
+
+        fn get_all_bills_for_owner(
+            env: Env,
+            _owner: Address,
+            _cursor: u32,
+            _limit: u32,
+        ) -> BillPage {
+            let items: Vec<Bill> = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("BILLS"))
+                .unwrap_or(Vec::new(&env));
+            BillPage {
+                count: items.len(),
+                items,
+                next_cursor: 0,
+            }
+        }
+    }
+}
+
+#[test]
+fn test_configure_oracle_is_admin_only_and_validated() {
+    let s = setup();
+    assert_eq!(s.client.get_oracle_config(), None);
+
+    let outsider = Address::generate(&s.env);
+    assert_eq!(
+        s.client
+            .try_configure_oracle(&outsider, &s.oracle, &MAX_AGE),
+        Err(Ok(ReportingError::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_configure_oracle(&s.admin, &s.oracle, &0),
+        Err(Ok(ReportingError::InvalidOracleConfig))
+    );
+    assert_eq!(
+        s.client
+            .try_configure_oracle(&s.admin, &s.client.address, &MAX_AGE),
+        Err(Ok(ReportingError::InvalidOracleConfig))
+    );
+
+    s.client.configure_oracle(&s.admin, &s.oracle, &MAX_AGE);
+    assert_eq!(
+        s.client.get_oracle_config(),
+        Some(OracleConfig {
+            oracle: s.oracle.clone(),
+            max_age_secs: MAX_AGE,
+        })
+    );
+}
+
+#[test]
+fn test_converted_reports_require_oracle() {
+    let s = setup();
+    let kes = currency(&s.env, "KES");
+    assert!(matches!(
+        s.client.try_get_bill_compliance_report_in(
+            &s.user,
+            &s.user,
+            &PERIOD_START,
+            &PERIOD_END,
+            &kes
+        ),
+        Err(Ok(ReportingError::OracleNotConfigured))
+    ));
+    assert!(matches!(
+        s.client.try_get_remittance_summary_in(
+            &s.user,
+            &1_000,
+            &currency(&s.env, "USD"),
+            &PERIOD_START,
+            &PERIOD_END,
+            &kes
+        ),
+        Err(Ok(ReportingError::OracleNotConfigured))
+    ));
+}
+
+#[test]
+fn test_bill_compliance_report_converts_each_bill_currency() {
+    let s = setup();
+    s.client.configure_oracle(&s.admin, &s.oracle, &MAX_AGE);
+
+    let kes = currency(&s.env, "KES");
+    let converted =
+        s.client
+            .get_bill_compliance_report_in(&s.user, &s.user, &PERIOD_START, &PERIOD_END, &kes);
+
+    // 100_000 NGN -> 6_500 KES, 40 USD -> 5_180 KES, 500 KES unchanged.
+    let report = converted.report;
+    assert_eq!(report.total_bills, 3);
+    assert_eq!(report.paid_amount, 6_500 + 500);
+    assert_eq!(report.unpaid_amount, 5_180);
+    assert_eq!(report.total_amount, 6_500 + 5_180 + 500);
+    assert_eq!(report.data_availability, DataAvailability::Complete);
+    assert_eq!(converted.currency, kes);
+    assert_eq!(converted.unconverted_bills, 0);
+    // The oldest quote used (USD/KES) dates the conversion.
+    assert_eq!(converted.converted_at, NOW - 1_200);
+}
+
+#[test]
+fn test_bill_compliance_report_marks_unpriced_bills_partial() {
+    let s = setup();
+    // USD/KES (20 minutes old) is stale under a 15 minute bound.
+    s.client.configure_oracle(&s.admin, &s.oracle, &900);
+
+    let converted = s.client.get_bill_compliance_report_in(
+        &s.user,
+        &s.user,
+        &PERIOD_START,
+        &PERIOD_END,
+        &currency(&s.env, "KES"),
+    );
+
+    let report = converted.report;
+    assert_eq!(report.total_bills, 3);
+    assert_eq!(report.unpaid_bills, 1);
+    assert_eq!(report.unpaid_amount, 0);
+    assert_eq!(report.total_amount, 6_500 + 500);
+    assert_eq!(report.data_availability, DataAvailability::Partial);
+    assert_eq!(converted.unconverted_bills, 1);
+    assert_eq!(converted.converted_at, NOW - 600);
+}
+
+#[test]
+fn test_remittance_summary_in_converts_before_splitting() {
+    let s = setup();
+    s.client.configure_oracle(&s.admin, &s.oracle, &MAX_AGE);
+
+    let usd = currency(&s.env, "USD");
+    let kes = currency(&s.env, "KES");
+    let converted =
+        s.client
+            .get_remittance_summary_in(&s.user, &1_000, &usd, &PERIOD_START, &PERIOD_END, &kes);
+
+    assert_eq!(converted.summary.total_received, 129_500);
+    assert_eq!(
+        converted.summary.category_breakdown.get(0).unwrap().amount,
+        64_750
+    );
+    assert_eq!(converted.source_currency, usd);
+    assert_eq!(converted.currency, kes);
+    assert_eq!(converted.converted_at, NOW - 1_200);
+
+    // A pair the oracle does not quote is an error rather than a silent 1:1.
+    assert!(matches!(
+        s.client.try_get_remittance_summary_in(
+            &s.user,
+            &1_000,
+            &currency(&s.env, "GHS"),
+            &PERIOD_START,
+            &PERIOD_END,
+            &kes
+        ),
+        Err(Ok(ReportingError::PriceUnavailable))
+    ));
+}
@@ -0,0 +1,454 @@
+extern crate std;
+
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, Env, Vec};
+
+use crate::{
+    DataAvailability, HealthScoreModel, ReportType, ReportingContract, ReportingContractClient,
+    ReportingError, MAX_HOUSEHOLD_PAGE,
+};
+
+const NOW: u64 = 1_704_067_200;
+
+/// Make `contract` panic whenever it is asked about `member`.
+fn break_for(env: &Env, contract: &Address, member: &Address) {
+    env.as_contract(contract, || {
+        let mut broken = broken_members(env);
+        broken.push_back(member.clone());
+        env.storage()
+            .instance()
+            .set(&symbol_short!("BROKEN"), &broken);
+    });
+}
+
+fn broken_members(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("BROKEN"))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn check_member(env: &Env, member: &Address) {
+    if broken_members(env).contains(member) {
+        panic!("dependency unavailable");
+    }
+}
+
+mod family {
+    use crate::{FamilyWalletTrait, MemberAddressPage, SpendingTracker};
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Vec};
+
+    /// Members and owner are seeded into instance storage by the test.
+    #[contract]
+    pub struct Household;
+
+    #[contractimpl]
+    impl FamilyWalletTrait for Household {
+        fn get_owner(env: &Env) -> Address {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("OWNER"))
+                .unwrap()
+        }
+
+        fn get_member_addresses_page(env: Env, cursor: u32, limit: u32) -> MemberAddressPage {
+            let members: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("MEMBERS"))
+                .unwrap_or_else(|| Vec::new(&env));
+            let end = cursor.saturating_add(limit).min(members.len());
+            let items = if cursor < end {
+                members.slice(cursor..end)
+            } else {
+                Vec::new(&env)
+            };
+            MemberAddressPage {
+                count: items.len(),
+                items,
+                next_cursor: if end < members.len() { end } else { 0 },
+            }
+        }
+
+        fn get_spending_tracker(_env: Env, _member: Address) -> Option<SpendingTracker> {
+            None
+        }
+    }
+}
+
+mod savings {
+    use crate::{GoalPage, SavingsGoal, SavingsGoalsTrait};
+    use soroban_sdk::{contract, contractimpl, vec, Address, Env, String, Vec};
+
+    /// One goal per member: 500 saved of 1_000.
+    #[contract]
+    pub struct Goals;
+
+    #[contractimpl]
+    impl SavingsGoalsTrait for Goals {
+        fn get_all_goals(env: Env, owner: Address) -> Vec<SavingsGoal> {
+            super::check_member(&env, &owner);
+            vec![
+                &env,
+                SavingsGoal {
+                    id: 1,
+                    owner,
+                    name: String::from_str(&env, "goal"),
+                    target_amount: 1_000,
+                    current_amount: 500,
+                    target_date: 0,
+                    locked: false,
+                    unlock_date: None,
+                    tags: Vec::new(&env),
+                },
+            ]
+        }
+
+        fn get_goals(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> GoalPage {
+            GoalPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn is_goal_completed(_env: Env, _goal_id: u32) -> bool {
+            false
+        }
+    }
+}
+
+mod bills {
+    use crate::{Bill, BillPage, BillPaymentsTrait};
+    use soroban_sdk::{contract, contractimpl, vec, Address, Env, String, Vec};
+
+    /// One overdue unpaid bill of 200 per member.
+    #[contract]
+    pub struct Bills;
+
+    #[contractimpl]
+    impl BillPaymentsTrait for Bills {
+        fn get_unpaid_bills(env: Env, owner: Address, _cursor: u32, _limit: u32) -> BillPage {
+            super::check_member(&env, &owner);
+            let items = vec![
+                &env,
+                Bill {
+                    id: 1,
+                    owner,
+                    name: String::from_str(&env, "bill"),
+                    external_ref: None,
+                    amount: 200,
+                    due_date: super::NOW - 86_400,
+                    recurring: false,
+                    frequency_days: 0,
+                    paid: false,
+                    created_at: 0,
+                    paid_at: None,
+                    schedule_id: None,
+                    tags: Vec::new(&env),
+                    currency: String::from_str(&env, "XLM"),
+                },
+            ];
+            BillPage {
+                count: items.len(),
+                items,
+                next_cursor: 0,
+            }
+        }
+
+        fn get_total_unpaid(_env: Env, _owner: Address) -> i128 {
+            200
+        }
+
+        fn get_all_bills_for_owner(
+            env: Env,
+            _owner: Address,
+            _cursor: u32,
+            _limit: u32,
+        ) -> BillPage {
+            BillPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+    }
+}
+
+mod insurance {
+    use crate::{InsurancePolicy, InsuranceTrait, PolicyPage};
+    use soroban_sdk::{contract, contractimpl, vec, Address, Env};
+
+    /// One active policy with a monthly premium of 50 per member.
+    #[contract]
+    pub struct Policies;
+
+    #[contractimpl]
+    impl InsuranceTrait for Policies {
+        fn get_active_policies(env: Env, owner: Address, _cursor: u32, _limit: u32) -> PolicyPage {
+            super::check_member(&env, &owner);
+            PolicyPage {
+                items: vec![&env, 1],
+                next_cursor: 0,
+                count: 1,
+            }
+        }
+
+        fn get_policy(_env: Env, _policy_id: u32) -> Option<InsurancePolicy> {
+            None
+        }
+
+        fn get_total_monthly_premium(env: Env, owner: Address) -> i128 {
+            super::check_member(&env, &owner);
+            50
+        }
+    }
+}
+
+mod history {
+    use crate::{RemittanceHistoryTrait, RemittancePeriod};
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    /// 1_000 received in the first requested month.
+    #[contract]
+    pub struct Received;
+
+    #[contractimpl]
+    impl RemittanceHistoryTrait for Received {
+        fn get_remittance_history(
+            env: Env,
+            owner: Address,
+            from_period: u64,
+            _to_period: u64,
+        ) -> Vec<RemittancePeriod> {
+            super::check_member(&env, &owner);
+            let mut periods = Vec::new(&env);
+            periods.push_back(RemittancePeriod {
+                period_key: from_period,
+                received: 1_000,
+                spending: 0,
+                savings: 0,
+                bills: 0,
+                insurance: 0,
+                flow_count: 1,
+            });
+            periods
+        }
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    client: ReportingContractClient<'a>,
+    admin: Address,
+    owner: Address,
+    members: std::vec::Vec<Address>,
+    savings: Address,
+    bills: Address,
+    insurance: Address,
+}
+
+fn setup<'a>(member_count: u32) -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = NOW);
+
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    let family = env.register_contract(None, family::Household);
+    let savings = env.register_contract(None, savings::Goals);
+    let bills = env.register_contract(None, bills::Bills);
+    let insurance = env.register_contract(None, insurance::Policies);
+    client.configure_addresses(
+        &admin,
+        &Address::generate(&env),
+        &savings,
+        &bills,
+        &insurance,
+        &family,
+    );
+
+    let owner = Address::generate(&env);
+    let members: std::vec::Vec<Address> =
+        (0..member_count).map(|_| Address::generate(&env)).collect();
+    env.as_contract(&family, || {
+        let mut stored = Vec::new(&env);
+        for member in members.iter() {
+            stored.push_back(member.clone());
+        }
+        env.storage()
+            .instance()
+            .set(&symbol_short!("OWNER"), &owner);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("MEMBERS"), &stored);
+    });
+
+    Setup {
+        env,
+        client,
+        admin,
+        owner,
+        members,
+        savings,
+        bills,
+        insurance,
+    }
+}
+
+#[test]
+fn test_household_report_aggregates_members() {
+    let s = setup(3);
+
+    let report = s.client.get_household_report(&s.owner, &s.owner, &0, &0);
+    assert_eq!(report.data_availability, DataAvailability::Complete);
+    assert_eq!(report.members.len(), 3);
+    assert_eq!(report.next_cursor, 0);
+    assert_eq!(report.total_target, 3_000);
+    assert_eq!(report.total_saved, 1_500);
+    assert_eq!(report.unpaid_bills, 3);
+    assert_eq!(report.unpaid_amount, 600);
+    assert_eq!(report.overdue_bills, 3);
+    assert_eq!(report.active_policies, 3);
+    assert_eq!(report.monthly_premium, 150);
+
+    let row = report.members.get(1).unwrap();
+    assert_eq!(row.member, s.members[1]);
+    assert_eq!(row.goals, 1);
+    assert_eq!(row.data_availability, DataAvailability::Complete);
+    // Default model: savings 20 (50% of 40), overdue bills 20, insurance 20.
+    assert_eq!(row.health_score.score, 60);
+    // Version 1 gives income no weight, so no history source is needed.
+    assert_eq!(row.monthly_income, 0);
+    assert_eq!(report.average_health_score, 60);
+}
+
+#[test]
+fn test_household_report_paginates_members() {
+    let s = setup(MAX_HOUSEHOLD_PAGE + 2);
+
+    let first = s.client.get_household_report(&s.owner, &s.owner, &0, &50);
+    assert_eq!(first.members.len(), MAX_HOUSEHOLD_PAGE);
+    assert_eq!(first.next_cursor, MAX_HOUSEHOLD_PAGE);
+
+    let second = s
+        .client
+        .get_household_report(&s.owner, &s.owner, &first.next_cursor, &5);
+    assert_eq!(second.members.len(), 2);
+    assert_eq!(second.next_cursor, 0);
+    assert_eq!(
+        second.members.get(1).unwrap().member,
+        s.members[MAX_HOUSEHOLD_PAGE as usize + 1]
+    );
+    assert_eq!(second.total_target, 2_000);
+}
+
+#[test]
+fn test_household_report_marks_failing_members() {
+    let s = setup(3);
+    break_for(&s.env, &s.savings, &s.members[0]);
+    for contract in [&s.savings, &s.bills, &s.insurance] {
+        break_for(&s.env, contract, &s.members[2]);
+    }
+
+    let report = s.client.get_household_report(&s.owner, &s.owner, &0, &0);
+    assert_eq!(report.data_availability, DataAvailability::Partial);
+    assert_eq!(report.members.len(), 3);
+
+    let partial = report.members.get(0).unwrap();
+    assert_eq!(partial.data_availability, DataAvailability::Partial);
+    assert_eq!(partial.goals, 0);
+    assert_eq!(partial.unpaid_bills, 1);
+
+    assert_eq!(
+        report.members.get(1).unwrap().data_availability,
+        DataAvailability::Complete
+    );
+
+    let missing = report.members.get(2).unwrap();
+    assert_eq!(missing.data_availability, DataAvailability::Missing);
+    assert_eq!(missing.monthly_premium, 0);
+    assert_eq!(report.total_target, 1_000);
+    assert_eq!(report.monthly_premium, 100);
+}
+
+#[test]
+fn test_household_report_scores_members_against_recorded_income() {
+    let s = setup(2);
+    s.client.set_health_score_model(
+        &s.admin,
+        &HealthScoreModel {
+            version: 2,
+            savings_weight: 30,
+            savings_no_goals_score: 15,
+            bills_weight: 30,
+            bills_unpaid_score: 25,
+            bills_overdue_score: 10,
+            insurance_weight: 10,
+            premium_income_weight: 15,
+            max_premium_income_bps: 500,
+            emergency_fund_weight: 15,
+            emergency_fund_target_months: 3,
+        },
+    );
+    let history = s.env.register_contract(None, history::Received);
+    s.client.configure_remittance_history(&s.admin, &history);
+    break_for(&s.env, &history, &s.members[1]);
+
+    let report = s.client.get_household_report(&s.owner, &s.owner, &0, &0);
+    assert_eq!(report.data_availability, DataAvailability::Partial);
+
+    // Premiums are 5% of income; savings cover half a month of 3.
+    let scored = report.members.get(0).unwrap();
+    assert_eq!(scored.data_availability, DataAvailability::Complete);
+    assert_eq!(scored.monthly_income, 1_000);
+    assert_eq!(scored.health_score.premium_income_score, 15);
+    assert_eq!(scored.health_score.emergency_fund_score, 2);
+    assert_eq!(scored.health_score.score, 52);
+
+    let unscored = report.members.get(1).unwrap();
+    assert_eq!(unscored.data_availability, DataAvailability::Partial);
+    assert_eq!(unscored.monthly_income, 0);
+    assert_eq!(unscored.health_score.premium_income_score, 0);
+    assert_eq!(unscored.health_score.emergency_fund_score, 0);
+    assert_eq!(unscored.health_score.score, 35);
+}
+
+#[test]
+fn test_household_report_requires_wallet_owner_or_grant() {
+    let s = setup(2);
+    let member = s.members[0].clone();
+    assert!(matches!(
+        s.client.try_get_household_report(&member, &member, &0, &0),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+
+    let adviser = Address::generate(&s.env);
+    assert!(matches!(
+        s.client
+            .try_get_household_report(&adviser, &s.owner, &0, &0),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+    s.client
+        .grant_report_access(&s.owner, &adviser, &ReportType::Household, &(NOW + 1));
+    assert_eq!(
+        s.client
+            .get_household_report(&adviser, &s.owner, &0, &0)
+            .members
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_household_report_without_members_is_missing() {
+    let s = setup(0);
+
+    let report = s.client.get_household_report(&s.owner, &s.owner, &0, &0);
+    assert_eq!(report.data_availability, DataAvailability::Missing);
+    assert!(report.members.is_empty());
+    assert_eq!(report.average_health_score, 0);
+}
@@ -0,0 +1,144 @@
+extern crate std;
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Vec};
+
+use crate::{
+    Category, DataAvailability, RemittanceHistoryTrait, RemittancePeriod, ReportingContract,
+    ReportingContractClient, ReportingError,
+};
+
+// 2024-01-15 and 2024-02-10 (UTC).
+const MID_JAN_2024: u64 = 1_705_276_800;
+const FEB_10_2024: u64 = 1_707_523_200;
+
+fn period(period_key: u64, spending: i128, savings: i128, bills: i128) -> RemittancePeriod {
+    RemittancePeriod {
+        period_key,
+        received: spending + savings + bills,
+        spending,
+        savings,
+        bills,
+        insurance: 0,
+        flow_count: 1,
+    }
+}
+
+mod history_source {
+    use super::*;
+
+    #[contract]
+    pub struct HistorySource;
+
+    #[contractimpl]
+    impl HistorySource {
+        pub fn seed(env: Env, periods: Vec<RemittancePeriod>) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("HIST"), &periods);
+        }
+    }
+
+    #[contractimpl]
+    impl RemittanceHistoryTrait for HistorySource {
+        fn get_remittance_history(
+            env: Env,
+            _owner: Address,
+            from_period: u64,
+            to_period: u64,
+        ) -> Vec<RemittancePeriod> {
+            let periods: Vec<RemittancePeriod> = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("HIST"))
+                .unwrap_or_else(|| Vec::new(&env));
+            let mut selected = Vec::new(&env);
+            for p in periods.iter() {
+                if p.period_key >= from_period && p.period_key <= to_period {
+                    selected.push_back(p);
+                }
+            }
+            selected
+        }
+    }
+}
+
+fn setup(env: &Env) -> (ReportingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init(&admin);
+
+    let source = env.register_contract(None, history_source::HistorySource);
+    let mut periods = Vec::new(env);
+    periods.push_back(period(202312, 500, 500, 0));
+    periods.push_back(period(202401, 2_000, 1_000, 1_000));
+    periods.push_back(period(202402, 1_000, 3_000, 2_000));
+    history_source::HistorySourceClient::new(env, &source).seed(&periods);
+    (client, admin, source)
+}
+
+#[test]
+fn test_configure_remittance_history_is_admin_only() {
+    let env = Env::default();
+    let (client, admin, source) = setup(&env);
+    assert_eq!(client.get_remittance_history_source(), None);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_configure_remittance_history(&outsider, &source),
+        Err(Ok(ReportingError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_configure_remittance_history(&admin, &client.address),
+        Err(Ok(ReportingError::InvalidDependencyAddressConfiguration))
+    );
+
+    client.configure_remittance_history(&admin, &source);
+    assert_eq!(client.get_remittance_history_source(), Some(source));
+}
+
+#[test]
+fn test_recorded_summary_sums_whole_months_in_period() {
+    let env = Env::default();
+    let (client, admin, source) = setup(&env);
+    client.configure_remittance_history(&admin, &source);
+    let user = Address::generate(&env);
+
+    let summary = client.get_recorded_remittance_summary(&user, &MID_JAN_2024, &FEB_10_2024);
+
+    assert_eq!(summary.data_availability, DataAvailability::Complete);
+    assert_eq!(summary.total_received, 10_000);
+    assert_eq!(summary.total_allocated, 10_000);
+    let breakdown: std::vec::Vec<(Category, i128, u32)> = summary
+        .category_breakdown
+        .iter()
+        .map(|c| (c.category, c.amount, c.percentage))
+        .collect();
+    assert_eq!(
+        breakdown,
+        std::vec![
+            (Category::Spending, 3_000, 3_000),
+            (Category::Savings, 4_000, 4_000),
+            (Category::Bills, 3_000, 3_000),
+            (Category::Insurance, 0, 0),
+        ]
+    );
+}
+
+#[test]
+fn test_recorded_summary_without_source_is_missing() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    let user = Address::generate(&env);
+
+    let summary = client.get_recorded_remittance_summary(&user, &MID_JAN_2024, &FEB_10_2024);
+    assert_eq!(summary.data_availability, DataAvailability::Missing);
+    assert_eq!(summary.total_received, 0);
+
+    assert!(matches!(
+        client.try_get_recorded_remittance_summary(&user, &FEB_10_2024, &MID_JAN_2024),
+        Err(Ok(ReportingError::InvalidPeriod))
+    ));
+}
@@ -0,0 +1,292 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    ReportType, ReportingContract, ReportingContractClient, ReportingError,
+    MAX_ACCESS_AUDIT_ENTRIES, MAX_ACCESS_GRANTS,
+};
+
+const NOW: u64 = 1_704_067_200;
+
+fn setup(env: &Env) -> (ReportingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = NOW);
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(env, &contract_id);
+    client.init(&Address::generate(env));
+    (client, Address::generate(env))
+}
+
+#[test]
+fn test_grantee_reads_until_expiry() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+    let lender = Address::generate(&env);
+
+    assert!(matches!(
+        client.try_get_stored_report(&lender, &user, &202_401),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+
+    client.grant_report_access(&user, &lender, &ReportType::StoredReport, &(NOW + 100));
+    assert!(client.get_stored_report(&lender, &user, &202_401).is_none());
+
+    env.ledger().with_mut(|l| l.timestamp = NOW + 100);
+    assert!(matches!(
+        client.try_get_stored_report(&lender, &user, &202_401),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+}
+
+#[test]
+fn test_grant_is_scoped_to_report_type_and_grantee() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+    let insurer = Address::generate(&env);
+
+    client.grant_report_access(&user, &insurer, &ReportType::StoredReport, &(NOW + 100));
+    assert!(client
+        .get_stored_report(&insurer, &user, &202_401)
+        .is_none());
+    assert!(matches!(
+        client.try_get_savings_report(&insurer, &user, &0, &NOW),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+    assert!(matches!(
+        client.try_get_stored_report(&Address::generate(&env), &user, &202_401),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+}
+
+#[test]
+fn test_revoke_removes_access() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+    let lender = Address::generate(&env);
+
+    client.grant_report_access(&user, &lender, &ReportType::StoredReport, &(NOW + 100));
+    client.grant_report_access(&user, &lender, &ReportType::StoredReport, &(NOW + 200));
+    let grants = client.get_report_access_grants(&user);
+    assert_eq!(grants.len(), 1);
+    assert_eq!(grants.get(0).unwrap().expires_at, NOW + 200);
+
+    assert!(client.revoke_report_access(&user, &lender, &ReportType::StoredReport));
+    assert!(!client.revoke_report_access(&user, &lender, &ReportType::StoredReport));
+    assert!(matches!(
+        client.try_get_stored_report(&lender, &user, &202_401),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+}
+
+#[test]
+fn test_grant_list_requires_user_auth() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+    client.grant_report_access(
+        &user,
+        &Address::generate(&env),
+        &ReportType::Savings,
+        &(NOW + 100),
+    );
+
+    client.get_report_access_grants(&user);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, user);
+
+    env.set_auths(&[]);
+    assert!(client.try_get_report_access_grants(&user).is_err());
+}
+
+#[test]
+fn test_invalid_grants_and_limit() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+    let grantee = Address::generate(&env);
+
+    for (grantee, expires_at) in [(&grantee, NOW), (&user, NOW + 100)] {
+        assert!(matches!(
+            client.try_grant_report_access(&user, grantee, &ReportType::Savings, &expires_at),
+            Err(Ok(ReportingError::InvalidAccessGrant))
+        ));
+    }
+
+    for _ in 0..MAX_ACCESS_GRANTS - 1 {
+        client.grant_report_access(
+            &user,
+            &Address::generate(&env),
+            &ReportType::Savings,
+            &(NOW + 100),
+        );
+    }
+    client.grant_report_access(&user, &grantee, &ReportType::Savings, &(NOW + 50));
+    assert!(matches!(
+        client.try_grant_report_access(
+            &user,
+            &Address::generate(&env),
+            &ReportType::Savings,
+            &(NOW + 100)
+        ),
+        Err(Ok(ReportingError::AccessGrantLimit))
+    ));
+
+    // Expired grants are pruned and free their slot.
+    env.ledger().with_mut(|l| l.timestamp = NOW + 50);
+    client.grant_report_access(
+        &user,
+        &Address::generate(&env),
+        &ReportType::Savings,
+        &(NOW + 100),
+    );
+    assert_eq!(
+        client.get_report_access_grants(&user).len(),
+        MAX_ACCESS_GRANTS
+    );
+}
+
+#[test]
+fn test_reads_are_recorded_in_access_audit() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+    let lender = Address::generate(&env);
+    client.grant_report_access(&user, &lender, &ReportType::StoredReport, &(NOW + 100));
+
+    client.get_stored_report(&user, &user, &202_401);
+    client.get_stored_report(&lender, &user, &202_401);
+    client.get_stored_report(&user, &user, &202_402);
+    // Trend analysis is a pure computation and leaves no audit entry.
+    client.get_trend_analysis(&lender, &user, &1, &1);
+
+    let audit = client.get_access_audit(&user, &2);
+    assert_eq!(audit.len(), 2);
+    let entry = audit.get(0).unwrap();
+    assert_eq!(entry.caller, lender);
+    assert_eq!(entry.report_type, ReportType::StoredReport);
+    assert_eq!(entry.timestamp, NOW);
+    assert_eq!(audit.get(1).unwrap().caller, user);
+
+    let page = client.get_access_audit_page(&user, &0, &2);
+    assert_eq!(page.count, 3);
+    assert_eq!(page.next_cursor, 2);
+    assert_eq!(page.items.get(0).unwrap().caller, user);
+    let page = client.get_access_audit_page(&user, &2, &2);
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.next_cursor, 0);
+}
+
+#[test]
+fn test_access_audit_is_bounded() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    for i in 0..MAX_ACCESS_AUDIT_ENTRIES + 5 {
+        env.ledger().with_mut(|l| l.timestamp = NOW + i as u64);
+        client.get_stored_report(&user, &user, &202_401);
+    }
+
+    let page = client.get_access_audit_page(&user, &0, &1);
+    assert_eq!(page.count, MAX_ACCESS_AUDIT_ENTRIES);
+    assert_eq!(page.items.get(0).unwrap().timestamp, NOW + 5);
+}
+
+/// A primary instance and a secondary instance that reads its grants, both
+/// run by `admin`.
+fn setup_linked(
+    env: &Env,
+) -> (
+    ReportingContractClient<'_>,
+    ReportingContractClient<'_>,
+    Address,
+    Address,
+) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = NOW);
+    let admin = Address::generate(env);
+    let primary_id = env.register_contract(None, ReportingContract);
+    let primary = ReportingContractClient::new(env, &primary_id);
+    primary.init(&admin);
+    let secondary_id = env.register_contract(None, ReportingContract);
+    let secondary = ReportingContractClient::new(env, &secondary_id);
+    secondary.init(&admin);
+    secondary.set_grant_authority(&admin, &Some(primary_id));
+    (primary, secondary, Address::generate(env), admin)
+}
+
+#[test]
+fn test_secondary_instance_reads_grants_from_authority() {
+    let env = Env::default();
+    let (primary, secondary, user, admin) = setup_linked(&env);
+    let lender = Address::generate(&env);
+    primary.grant_report_access(&user, &lender, &ReportType::StoredReport, &(NOW + 100));
+
+    // Not linked yet: the authority refuses to vouch for the secondary.
+    assert!(matches!(
+        secondary.try_get_stored_report(&lender, &user, &202_401),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+    assert!(matches!(
+        primary.try_link_report_instance(&lender, &secondary.address, &true),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+
+    primary.link_report_instance(&admin, &secondary.address, &true);
+    assert!(primary.is_report_instance_linked(&secondary.address));
+    assert!(secondary
+        .get_stored_report(&lender, &user, &202_401)
+        .is_none());
+    assert!(secondary
+        .get_stored_report(&user, &user, &202_401)
+        .is_none());
+
+    // Reads on the secondary land in the authority's audit log.
+    let audit = primary.get_access_audit(&user, &10);
+    assert_eq!(audit.len(), 2);
+    assert_eq!(audit.get(0).unwrap().caller, lender);
+    assert_eq!(secondary.get_access_audit(&user, &10).len(), 0);
+
+    // Revoking on the authority applies to the secondary too.
+    primary.revoke_report_access(&user, &lender, &ReportType::StoredReport);
+    assert!(matches!(
+        secondary.try_get_stored_report(&lender, &user, &202_401),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+
+    primary.link_report_instance(&admin, &secondary.address, &false);
+    assert!(matches!(
+        secondary.try_get_stored_report(&user, &user, &202_401),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+}
+
+#[test]
+fn test_secondary_instance_rejects_local_grants() {
+    let env = Env::default();
+    let (primary, secondary, user, admin) = setup_linked(&env);
+    assert_eq!(
+        secondary.get_grant_authority(),
+        Some(primary.address.clone())
+    );
+
+    assert!(matches!(
+        secondary.try_grant_report_access(
+            &user,
+            &Address::generate(&env),
+            &ReportType::Savings,
+            &(NOW + 100)
+        ),
+        Err(Ok(ReportingError::GrantsManagedByAuthority))
+    ));
+    assert!(matches!(
+        secondary.try_set_grant_authority(&admin, &Some(secondary.address.clone())),
+        Err(Ok(ReportingError::InvalidDependencyAddressConfiguration))
+    ));
+
+    secondary.set_grant_authority(&admin, &None);
+    assert_eq!(secondary.get_grant_authority(), None);
+    secondary.grant_report_access(
+        &user,
+        &Address::generate(&env),
+        &ReportType::Savings,
+        &(NOW + 100),
+    );
+}
@@ -0,0 +1,414 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, Env};
+
+use crate::{
+    ReportCadence, ReportingContract, ReportingContractClient, ReportingError, RetentionPolicy,
+    StoredStorageStats,
+};
+
+// 2024-01-15, 2024-02-03 and 2024-04-02 (UTC).
+const JAN_15_2024: u64 = 1_705_276_800;
+const FEB_3_2024: u64 = 1_706_918_400;
+const APR_2_2024: u64 = 1_712_016_000;
+const DAY: u64 = 86_400;
+
+mod split {
+    use crate::RemittanceSplitTrait;
+    use soroban_sdk::{contract, contractimpl, vec, Env, Vec};
+
+    #[contract]
+    pub struct Split;
+
+    #[contractimpl]
+    impl RemittanceSplitTrait for Split {
+        fn get_split(env: &Env) -> Vec<u32> {
+            vec![env, 5_000, 3_000, 1_500, 500]
+        }
+
+        fn calculate_split(env: Env, total_amount: i128) -> Vec<i128> {
+            let mut amounts = Vec::new(&env);
+            for share in [5_000, 3_000, 1_500, 500] {
+                amounts.push_back(total_amount * share / 10_000);
+            }
+            amounts
+        }
+    }
+}
+
+mod savings {
+    use crate::{GoalPage, SavingsGoal, SavingsGoalsTrait};
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    #[contract]
+    pub struct Savings;
+
+    #[contractimpl]
+    impl SavingsGoalsTrait for Savings {
+        fn get_all_goals(env: Env, _owner: Address) -> Vec<SavingsGoal> {
+            Vec::new(&env)
+        }
+
+        fn get_goals(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> GoalPage {
+            GoalPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn is_goal_completed(_env: Env, _goal_id: u32) -> bool {
+            false
+        }
+    }
+}
+
+mod bills {
+    use crate::{BillPage, BillPaymentsTrait};
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    #[contract]
+    pub struct Bills;
+
+    #[contractimpl]
+    impl BillPaymentsTrait for Bills {
+        fn get_unpaid_bills(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> BillPage {
+            BillPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn get_total_unpaid(_env: Env, _owner: Address) -> i128 {
+            0
+        }
+
+        fn get_all_bills_for_owner(
+            env: Env,
+            _owner: Address,
+            _cursor: u32,
+            _limit: u32,
+        ) -> BillPage {
+            BillPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+    }
+}
+
+mod insurance {
+    use crate::{InsurancePolicy, InsuranceTrait, PolicyPage};
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    #[contract]
+    pub struct Insurance;
+
+    #[contractimpl]
+    impl InsuranceTrait for Insurance {
+        fn get_active_policies(env: Env, _owner: Address, _cursor: u32, _limit: u32) -> PolicyPage {
+            PolicyPage {
+                items: Vec::new(&env),
+                next_cursor: 0,
+                count: 0,
+            }
+        }
+
+        fn get_policy(_env: Env, _policy_id: u32) -> Option<InsurancePolicy> {
+            None
+        }
+
+        fn get_total_monthly_premium(_env: Env, _owner: Address) -> i128 {
+            0
+        }
+    }
+}
+
+mod history {
+    use crate::{RemittanceHistoryTrait, RemittancePeriod};
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    /// 1_000 received in each month of the requested range.
+    #[contract]
+    pub struct Monthly;
+
+    #[contractimpl]
+    impl RemittanceHistoryTrait for Monthly {
+        fn get_remittance_history(
+            env: Env,
+            _owner: Address,
+            from_period: u64,
+            to_period: u64,
+        ) -> Vec<RemittancePeriod> {
+            let mut periods = Vec::new(&env);
+            let mut period_key = from_period;
+            while period_key <= to_period {
+                periods.push_back(RemittancePeriod {
+                    period_key,
+                    received: 1_000,
+                    spending: 0,
+                    savings: 0,
+                    bills: 0,
+                    insurance: 0,
+                    flow_count: 1,
+                });
+                period_key = if period_key % 100 == 12 {
+                    period_key + 89
+                } else {
+                    period_key + 1
+                };
+            }
+            periods
+        }
+    }
+}
+
+fn setup(env: &Env) -> (ReportingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = JAN_15_2024);
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init(&admin);
+    (client, admin)
+}
+
+fn configure(env: &Env, client: &ReportingContractClient, admin: &Address) {
+    client.configure_addresses(
+        admin,
+        &env.register_contract(None, split::Split),
+        &env.register_contract(None, savings::Savings),
+        &env.register_contract(None, bills::Bills),
+        &env.register_contract(None, insurance::Insurance),
+        &Address::generate(env),
+    );
+    client.configure_remittance_history(admin, &env.register_contract(None, history::Monthly));
+}
+
+#[test]
+fn test_monthly_subscription_generates_each_completed_month_once() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    configure(&env, &client, &admin);
+    let user = Address::generate(&env);
+
+    client.subscribe_reports(&user, &ReportCadence::Monthly);
+    assert_eq!(
+        client
+            .get_report_subscription(&user)
+            .unwrap()
+            .last_period_key,
+        202312
+    );
+    // December completed before subscribing, so nothing is due yet.
+    assert_eq!(client.run_scheduled_reports(&0, &0).generated, 0);
+
+    env.ledger().with_mut(|l| l.timestamp = FEB_3_2024);
+    let run = client.run_scheduled_reports(&0, &0);
+    assert_eq!((run.visited, run.generated, run.next_cursor), (1, 1, 0));
+    assert_eq!(client.run_scheduled_reports(&0, &0).generated, 0);
+
+    let report = client.get_stored_report(&user, &user, &202401).unwrap();
+    assert_eq!(report.generated_at, FEB_3_2024);
+    assert_eq!(report.remittance_summary.total_received, 1_000);
+    assert_eq!(report.savings_report.period_start, 1_704_067_200);
+    assert_eq!(report.savings_report.period_end, 1_706_745_599);
+
+    let stats = client.get_storage_stats();
+    assert_eq!(stats.subscriptions, 1);
+    assert_eq!(stats.scheduled_reports, 1);
+    // `active_reports` counts only reports saved with `store_report`.
+    assert_eq!(stats.active_reports, 0);
+}
+
+#[test]
+fn test_quarterly_subscription_covers_calendar_quarter() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    configure(&env, &client, &admin);
+    let user = Address::generate(&env);
+
+    client.subscribe_reports(&user, &ReportCadence::Quarterly);
+    assert_eq!(
+        client
+            .get_report_subscription(&user)
+            .unwrap()
+            .last_period_key,
+        202310
+    );
+    env.ledger().with_mut(|l| l.timestamp = FEB_3_2024);
+    assert_eq!(client.run_scheduled_reports(&0, &0).generated, 0);
+
+    env.ledger().with_mut(|l| l.timestamp = APR_2_2024);
+    assert_eq!(client.run_scheduled_reports(&0, &0).generated, 1);
+    let report = client.get_stored_report(&user, &user, &202401).unwrap();
+    assert_eq!(report.remittance_summary.total_received, 3_000);
+    assert_eq!(report.savings_report.period_end, APR_2_2024 - DAY - 1);
+}
+
+#[test]
+fn test_scheduled_run_pages_and_retries_failures() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let users = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for user in users.iter() {
+        client.subscribe_reports(user, &ReportCadence::Monthly);
+    }
+
+    // Without dependency addresses every report fails and stays due.
+    env.ledger().with_mut(|l| l.timestamp = FEB_3_2024);
+    let run = client.run_scheduled_reports(&0, &2);
+    assert_eq!((run.visited, run.generated, run.next_cursor), (2, 0, 2));
+
+    configure(&env, &client, &admin);
+    let first = client.run_scheduled_reports(&0, &2);
+    assert_eq!(
+        (first.visited, first.generated, first.next_cursor),
+        (2, 2, 2)
+    );
+    let last = client.run_scheduled_reports(&first.next_cursor, &2);
+    assert_eq!((last.visited, last.generated, last.next_cursor), (1, 1, 0));
+    assert_eq!(client.run_scheduled_reports(&3, &2).visited, 0);
+    assert_eq!(client.get_storage_stats().scheduled_reports, 3);
+}
+
+#[test]
+fn test_unsubscribe_stops_generation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    configure(&env, &client, &admin);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.subscribe_reports(&user, &ReportCadence::Monthly);
+    client.subscribe_reports(&other, &ReportCadence::Monthly);
+    // Re-subscribing changes the cadence without adding a subscriber.
+    client.subscribe_reports(&user, &ReportCadence::Quarterly);
+    assert_eq!(client.get_storage_stats().subscriptions, 2);
+
+    assert!(client.unsubscribe_reports(&user));
+    assert!(!client.unsubscribe_reports(&user));
+    assert!(client.get_report_subscription(&user).is_none());
+    assert_eq!(client.get_storage_stats().subscriptions, 1);
+
+    env.ledger().with_mut(|l| l.timestamp = FEB_3_2024);
+    let run = client.run_scheduled_reports(&0, &0);
+    assert_eq!((run.visited, run.generated), (1, 1));
+    assert!(client.get_stored_report(&user, &user, &202401).is_none());
+}
+
+#[test]
+fn test_retention_moves_scheduled_reports_through_tiers() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    configure(&env, &client, &admin);
+    let user = Address::generate(&env);
+
+    assert!(matches!(
+        client.try_apply_retention(&0),
+        Err(Ok(ReportingError::RetentionNotConfigured))
+    ));
+    let policy = RetentionPolicy {
+        archive_after: 30 * DAY,
+        delete_after: 60 * DAY,
+    };
+    assert!(matches!(
+        client.try_set_retention_policy(&user, &policy),
+        Err(Ok(ReportingError::Unauthorized))
+    ));
+    assert!(matches!(
+        client.try_set_retention_policy(
+            &admin,
+            &RetentionPolicy {
+                archive_after: 0,
+                ..policy.clone()
+            }
+        ),
+        Err(Ok(ReportingError::InvalidRetentionPolicy))
+    ));
+    client.set_retention_policy(&admin, &policy);
+    assert_eq!(client.get_retention_policy(), Some(policy));
+
+    // A report saved by the user is left to the admin archive calls.
+    let report = client.get_financial_health_report(&user, &user, &1_000, &0, &JAN_15_2024);
+    client.store_report(&user, &report, &202312);
+
+    client.subscribe_reports(&user, &ReportCadence::Monthly);
+    env.ledger().with_mut(|l| l.timestamp = FEB_3_2024);
+    client.run_scheduled_reports(&0, &0);
+    env.ledger()
+        .with_mut(|l| l.timestamp = FEB_3_2024 + 29 * DAY);
+    client.run_scheduled_reports(&0, &0);
+
+    // Only the January report is older than 30 days.
+    env.ledger()
+        .with_mut(|l| l.timestamp = FEB_3_2024 + 31 * DAY);
+    let run = client.apply_retention(&0);
+    assert_eq!((run.archived, run.deleted, run.more), (1, 0, false));
+    assert!(client.get_stored_report(&user, &user, &202401).is_none());
+    let archive = client
+        .get_scheduled_report_archive(&user, &user, &202401)
+        .unwrap();
+    assert_eq!(archive.generated_at, FEB_3_2024);
+    assert_eq!(archive.archived_at, FEB_3_2024 + 31 * DAY);
+    assert!(client.get_stored_report(&user, &user, &202402).is_some());
+
+    // The January archive is deleted 60 days after it was archived and the
+    // February report is archived meanwhile; the limit leaves one step over.
+    env.ledger()
+        .with_mut(|l| l.timestamp = FEB_3_2024 + 92 * DAY);
+    let run = client.apply_retention(&1);
+    assert_eq!((run.archived, run.deleted, run.more), (1, 1, true));
+    assert!(client
+        .get_scheduled_report_archive(&user, &user, &202401)
+        .is_none());
+    let run = client.apply_retention(&1);
+    assert_eq!((run.archived, run.deleted, run.more), (0, 0, false));
+    assert!(client
+        .get_scheduled_report_archive(&user, &user, &202402)
+        .is_some());
+    assert!(client.get_stored_report(&user, &user, &202312).is_some());
+
+    let stats = client.get_storage_stats();
+    assert_eq!(stats.active_reports, 1);
+    assert_eq!(stats.archived_reports, 0);
+    assert_eq!(stats.scheduled_reports, 2);
+    assert_eq!(stats.retention_archived, 2);
+    assert_eq!(stats.retention_deleted, 1);
+    assert_eq!(stats.last_retention_run, FEB_3_2024 + 92 * DAY);
+}
+
+#[test]
+fn test_storage_stats_decode_existing_entry() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    // `STOR_STAT` as written before the scheduling counters existed.
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(
+            &symbol_short!("STOR_STAT"),
+            &StoredStorageStats {
+                active_reports: 3,
+                archived_reports: 1,
+                last_updated: 5,
+            },
+        )
+    });
+    client.subscribe_reports(&Address::generate(&env), &ReportCadence::Monthly);
+
+    let stats = client.get_storage_stats();
+    assert_eq!(
+        (
+            stats.active_reports,
+            stats.archived_reports,
+            stats.last_updated
+        ),
+        (3, 1, 5)
+    );
+    assert_eq!(stats.subscriptions, 1);
+    assert_eq!(stats.scheduled_reports, 0);
+}
@@ -0,0 +1,125 @@
+extern crate std;
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Env, Vec};
+
+use crate::{
+    Category, DataAvailability, ReportingContract, ReportingContractClient, ReportingError,
+    SplitInfo, SplitInfoTrait, SplitUnit, SPLIT_INFO_VERSION,
+};
+
+mod unit_split {
+    use super::*;
+
+    /// Split contract that reports whatever `SplitInfo` it was seeded with.
+    #[contract]
+    pub struct UnitSplit;
+
+    #[contractimpl]
+    impl UnitSplit {
+        pub fn seed(env: Env, info: SplitInfo) {
+            env.storage().instance().set(&symbol_short!("INFO"), &info);
+        }
+    }
+
+    #[contractimpl]
+    impl SplitInfoTrait for UnitSplit {
+        fn get_split_info(env: Env) -> SplitInfo {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("INFO"))
+                .unwrap()
+        }
+    }
+}
+
+fn setup(env: &Env, info: SplitInfo) -> (ReportingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ReportingContract);
+    let client = ReportingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init(&admin);
+
+    let split_id = env.register_contract(None, unit_split::UnitSplit);
+    unit_split::UnitSplitClient::new(env, &split_id).seed(&info);
+    client.configure_addresses(
+        &admin,
+        &split_id,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+    (client, Address::generate(env))
+}
+
+fn breakdown(summary: &crate::RemittanceSummary) -> std::vec::Vec<(Category, i128, u32)> {
+    summary
+        .category_breakdown
+        .iter()
+        .map(|c| (c.category, c.amount, c.percentage))
+        .collect()
+}
+
+#[test]
+fn test_percent_split_is_normalised_to_basis_points() {
+    let env = Env::default();
+    let info = SplitInfo {
+        version: SPLIT_INFO_VERSION,
+        unit: SplitUnit::Percent,
+        values: vec![&env, 50, 30, 15, 5],
+    };
+    let (client, user) = setup(&env, info);
+
+    let summary = client.get_remittance_summary(&user, &10_000, &0, &0);
+    assert_eq!(summary.data_availability, DataAvailability::Complete);
+    assert_eq!(
+        breakdown(&summary),
+        std::vec![
+            (Category::Spending, 5_000, 5_000),
+            (Category::Savings, 3_000, 3_000),
+            (Category::Bills, 1_500, 1_500),
+            (Category::Insurance, 500, 500),
+        ]
+    );
+}
+
+#[test]
+fn test_split_must_sum_to_its_units_scale() {
+    let env = Env::default();
+    // Basis-point values labelled as percents must not be accepted.
+    let info = SplitInfo {
+        version: SPLIT_INFO_VERSION,
+        unit: SplitUnit::Percent,
+        values: vec![&env, 5_000, 3_000, 1_500, 500],
+    };
+    let (client, user) = setup(&env, info);
+
+    assert!(matches!(
+        client.try_get_remittance_summary(&user, &10_000, &0, &0),
+        Err(Ok(ReportingError::InvalidPercentageSplit))
+    ));
+}
+
+#[test]
+fn test_unknown_split_info_version_is_partial() {
+    let env = Env::default();
+    let info = SplitInfo {
+        version: SPLIT_INFO_VERSION + 1,
+        unit: SplitUnit::BasisPoints,
+        values: Vec::new(&env),
+    };
+    let (client, user) = setup(&env, info);
+
+    let summary = client.get_remittance_summary(&user, &10_000, &0, &0);
+    assert_eq!(summary.data_availability, DataAvailability::Partial);
+    assert_eq!(
+        breakdown(&summary),
+        std::vec![
+            (Category::Spending, 0, 0),
+            (Category::Savings, 0, 0),
+            (Category::Bills, 0, 0),
+            (Category::Insurance, 0, 0),
+        ]
+    );
+}
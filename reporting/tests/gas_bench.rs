@@ -17,9 +17,8 @@
 //! - **medium** – 25 items per data source
 //! - **large**  – 50 items per data source
 //!
-//! `get_trend_analysis_multi` is an in-contract computation that receives its
-//! history as a `Vec<(u64, i128)>` argument, so size is controlled directly
-//! without mock contracts.
+//! `get_trend_analysis_multi` reads stored health snapshots, so size is the
+//! number of daily snapshots captured before the measured call.
 //!
 //! # Security notes
 //!
@@ -30,9 +29,11 @@
 //! - All numeric inputs are within i128 range; no overflow paths are exercised.
 
 use reporting::{DataAvailability, ReportingContract, ReportingContractClient};
+#[cfg(feature = "snapshots")]
+use reporting::{HealthMetric, PeriodKind};
 use soroban_sdk::{
     testutils::{Address as AddressTrait, EnvTestConfig, Ledger, LedgerInfo},
-    Address, Env, Vec,
+    Address, Env,
 };
 
 // ── Benchmark infrastructure ─────────────────────────────────────────────────
@@ -434,45 +435,46 @@ fn bench_remittance_summary_with_split() {
 }
 
 // ═════════════════════════════════════════════════════════════════════════════
-//  2. get_trend_analysis_multi – pure in-contract history computation
+//  2. get_trend_analysis_multi – trends over stored health snapshots
 // ═════════════════════════════════════════════════════════════════════════════
+//
+// Snapshots are an opt-in report family; run these with
+// `cargo test -p reporting --features snapshots`.
 
-/// Build a synthetic history Vec of length `n` with linearly increasing amounts.
-fn make_history(env: &Env, user: &Address, n: u32) -> Vec<(u64, i128)> {
-    let _ = user; // user is reserved for future auth scoping
-    let mut history = Vec::new(env);
+/// Capture `n` daily health snapshots for `user`, one ledger day apart.
+#[cfg(feature = "snapshots")]
+fn capture_daily_snapshots(env: &Env, client: &ReportingContractClient, user: &Address, n: u32) {
     for i in 0u32..n {
-        let period_key = PERIOD_START + (i as u64) * 86_400;
-        let amount = 100_000i128 * (i as i128 + 1);
-        history.push_back((period_key, amount));
+        env.ledger()
+            .with_mut(|l| l.timestamp = PERIOD_START + (i as u64) * 86_400);
+        client.capture_health_snapshot(user, user, &PeriodKind::Day);
     }
-    history
 }
 
-/// Benchmark: trend analysis over 5 history periods.
+/// Benchmark: trend analysis over 5 stored snapshots.
 ///
-/// Security: no cross-contract calls; result is fully deterministic for
-/// identical inputs regardless of ledger state.
+/// Security: reads only the caller's own snapshot series; no cross-contract
+/// calls happen inside the measured window.
+#[cfg(feature = "snapshots")]
 #[test]
 fn bench_trend_analysis_multi_5_periods() {
     let env = bench_env();
-    let contract_id = env.register_contract(None, ReportingContract);
-    let client = ReportingContractClient::new(&env, &contract_id);
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    client.init(&admin);
-
-    let history = make_history(&env, &user, 5);
+    let (client, _admin, user) = setup_reporting!(
+        env,
+        mock_remittance_split::MockRemittanceSplit,
+        mock_savings_5::MockSavings5,
+        mock_bills_5::MockBills5,
+        mock_insurance_5::MockInsurance5
+    );
+    capture_daily_snapshots(&env, &client, &user, 5);
 
-    let (cpu, mem, trends) = measure(&env, || client.get_trend_analysis_multi(&user, &history));
+    let (cpu, mem, trends) = measure(&env, || {
+        client.get_trend_analysis_multi(&user, &PeriodKind::Day, &HealthMetric::TotalSaved, &0, &5)
+    });
 
-    // 5 data points → 5 trend entries (first entry uses prev=0 baseline)
+    // 5 snapshots → 5 trend entries (first entry uses prev=0 baseline)
     assert_eq!(trends.len(), 5);
-    // Each window should show a 100 % increase (amount doubles each step)
-    for trend in trends.iter() {
-        assert!(trend.change_percentage > 0);
-    }
+    assert!(trends.get(0).unwrap().change_percentage > 0);
 
     println!(
         r#"{{"contract":"reporting","method":"get_trend_analysis_multi","scenario":"5_periods","cpu":{},"mem":{}}}"#,
@@ -480,23 +482,25 @@ fn bench_trend_analysis_multi_5_periods() {
     );
 }
 
-/// Benchmark: trend analysis over 25 history periods.
+/// Benchmark: trend analysis over 25 stored snapshots.
 ///
-/// Security: same as 5-period variant; determinism assertion below confirms
-/// that ledger timestamp does not influence output.
+/// Security: same as 5-period variant.
+#[cfg(feature = "snapshots")]
 #[test]
 fn bench_trend_analysis_multi_25_periods() {
     let env = bench_env();
-    let contract_id = env.register_contract(None, ReportingContract);
-    let client = ReportingContractClient::new(&env, &contract_id);
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    client.init(&admin);
-
-    let history = make_history(&env, &user, 25);
+    let (client, _admin, user) = setup_reporting!(
+        env,
+        mock_remittance_split::MockRemittanceSplit,
+        mock_savings_5::MockSavings5,
+        mock_bills_5::MockBills5,
+        mock_insurance_5::MockInsurance5
+    );
+    capture_daily_snapshots(&env, &client, &user, 25);
 
-    let (cpu, mem, trends) = measure(&env, || client.get_trend_analysis_multi(&user, &history));
+    let (cpu, mem, trends) = measure(&env, || {
+        client.get_trend_analysis_multi(&user, &PeriodKind::Day, &HealthMetric::TotalSaved, &0, &25)
+    });
 
     assert_eq!(trends.len(), 25);
 
@@ -506,23 +510,27 @@ fn bench_trend_analysis_multi_25_periods() {
     );
 }
 
-/// Benchmark: trend analysis over 50 history periods (worst-case realistic load).
+/// Benchmark: trend analysis over 50 stored snapshots, the largest page
+/// `clamp_limit` allows.
 ///
-/// Security: 50-element input is within safe processing range; no integer
-/// overflow occurs since amounts are spaced at 100_000 steps per period.
+/// Security: page size is bounded by `MAX_PAGE_LIMIT`, so cost cannot grow
+/// past this scenario in a single call.
+#[cfg(feature = "snapshots")]
 #[test]
 fn bench_trend_analysis_multi_50_periods() {
     let env = bench_env();
-    let contract_id = env.register_contract(None, ReportingContract);
-    let client = ReportingContractClient::new(&env, &contract_id);
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    client.init(&admin);
-
-    let history = make_history(&env, &user, 50);
+    let (client, _admin, user) = setup_reporting!(
+        env,
+        mock_remittance_split::MockRemittanceSplit,
+        mock_savings_5::MockSavings5,
+        mock_bills_5::MockBills5,
+        mock_insurance_5::MockInsurance5
+    );
+    capture_daily_snapshots(&env, &client, &user, 50);
 
-    let (cpu, mem, trends) = measure(&env, || client.get_trend_analysis_multi(&user, &history));
+    let (cpu, mem, trends) = measure(&env, || {
+        client.get_trend_analysis_multi(&user, &PeriodKind::Day, &HealthMetric::TotalSaved, &0, &50)
+    });
 
     assert_eq!(trends.len(), 50);
 
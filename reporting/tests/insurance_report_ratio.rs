@@ -161,9 +161,8 @@ fn ratio_and_annual_premium_are_computed_with_checked_math() {
 
     let (reporting_id, user) = setup(&env, policies);
     let rc = ReportingContractClient::new(&env, &reporting_id);
-    let caller = Address::generate(&env);
 
-    let report = rc.get_insurance_report(&caller, &user, &0u64, &100u64);
+    let report = rc.get_insurance_report(&user, &user, &0u64, &100u64);
 
     assert_eq!(report.active_policies, 2);
     assert_eq!(report.total_coverage, 600_000_000);
@@ -184,10 +183,9 @@ fn zero_active_policies_yields_zero_ratio_without_dividing_by_zero() {
 
     let (reporting_id, user) = setup(&env, Vec::new(&env));
     let rc = ReportingContractClient::new(&env, &reporting_id);
-    let caller = Address::generate(&env);
 
     // Must not panic (no divide-by-zero) and must report zeroes.
-    let report = rc.get_insurance_report(&caller, &user, &0u64, &100u64);
+    let report = rc.get_insurance_report(&user, &user, &0u64, &100u64);
 
     assert_eq!(report.active_policies, 0);
     assert_eq!(report.total_coverage, 0);
@@ -209,9 +207,8 @@ fn zero_premium_with_coverage_does_not_divide_by_zero() {
 
     let (reporting_id, user) = setup(&env, policies);
     let rc = ReportingContractClient::new(&env, &reporting_id);
-    let caller = Address::generate(&env);
 
-    let report = rc.get_insurance_report(&caller, &user, &0u64, &100u64);
+    let report = rc.get_insurance_report(&user, &user, &0u64, &100u64);
 
     assert_eq!(report.active_policies, 1);
     assert_eq!(report.total_coverage, 500_000_000);
@@ -235,9 +232,8 @@ fn aggregation_is_active_only() {
 
     let (reporting_id, user) = setup(&env, policies);
     let rc = ReportingContractClient::new(&env, &reporting_id);
-    let caller = Address::generate(&env);
 
-    let report = rc.get_insurance_report(&caller, &user, &0u64, &100u64);
+    let report = rc.get_insurance_report(&user, &user, &0u64, &100u64);
 
     assert_eq!(
         report.active_policies, 1,
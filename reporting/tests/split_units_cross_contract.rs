@@ -0,0 +1,79 @@
+//! Cross-contract check that reporting reads remittance_split's split in the
+//! unit the split contract declares, using the real contracts on both sides.
+
+use remittance_split::{RemittanceSplit, RemittanceSplitClient};
+use remitwise_common::{Category, SplitUnit, SPLIT_INFO_VERSION};
+use reporting::{DataAvailability, ReportingContract, ReportingContractClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup(env: &Env) -> (RemittanceSplitClient<'_>, ReportingContractClient<'_>) {
+    env.mock_all_auths();
+
+    let split_id = env.register_contract(None, RemittanceSplit);
+    let split_client = RemittanceSplitClient::new(env, &split_id);
+
+    let reporting_id = env.register_contract(None, ReportingContract);
+    let reporting_client = ReportingContractClient::new(env, &reporting_id);
+    let admin = Address::generate(env);
+    reporting_client.init(&admin);
+    reporting_client.configure_addresses(
+        &admin,
+        &split_id,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+
+    (split_client, reporting_client)
+}
+
+#[test]
+fn test_reporting_summary_matches_configured_split() {
+    let env = Env::default();
+    let (split_client, reporting_client) = setup(&env);
+    let owner = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    split_client.initialize_split(&owner, &0, &token.address(), &4_000, &3_000, &2_000, &1_000);
+
+    let info = split_client.get_split_info();
+    assert_eq!(info.version, SPLIT_INFO_VERSION);
+    assert_eq!(info.unit, SplitUnit::BasisPoints);
+
+    let total = 10_000i128;
+    let amounts = split_client.calculate_split(&total);
+    assert_eq!(amounts, soroban_sdk::vec![&env, 4_000, 3_000, 2_000, 1_000]);
+    let summary = reporting_client.get_remittance_summary(&owner, &total, &0, &0);
+    assert_eq!(summary.data_availability, DataAvailability::Complete);
+
+    let categories = [
+        Category::Spending,
+        Category::Savings,
+        Category::Bills,
+        Category::Insurance,
+    ];
+    for (i, category) in categories.iter().enumerate() {
+        let entry = summary.category_breakdown.get(i as u32).unwrap();
+        assert_eq!(entry.category, *category);
+        assert_eq!(entry.amount, amounts.get(i as u32).unwrap());
+        assert_eq!(entry.percentage, info.values.get(i as u32).unwrap());
+    }
+}
+
+#[test]
+fn test_reporting_summary_uses_default_split_before_initialization() {
+    let env = Env::default();
+    let (_, reporting_client) = setup(&env);
+    let user = Address::generate(&env);
+
+    let summary = reporting_client.get_remittance_summary(&user, &1_000, &0, &0);
+    assert_eq!(summary.data_availability, DataAvailability::Complete);
+
+    let amounts: Vec<i128> = summary
+        .category_breakdown
+        .iter()
+        .map(|c| c.amount)
+        .collect();
+    assert_eq!(amounts, vec![500, 300, 150, 50]);
+}
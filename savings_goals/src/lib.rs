@@ -2,11 +2,11 @@
 #![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 use remitwise_common::{
     reversible_op::{ReversibleOpError, SavingsGoalsReversible},
-    EventCategory, EventPriority, RemitwiseEvents, SNAPSHOT_KEY, SNAPSHOT_VERSION,
+    EventCategory, EventPriority, RemitwiseError, RemitwiseEvents, SNAPSHOT_KEY, SNAPSHOT_VERSION,
 };
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Map, String,
-    Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, Address,
+    Env, Map, String, Symbol, Vec,
 };
 
 /// Mirrors `bill_payments::Error`'s naming convention (`*NotFound`,
@@ -248,6 +248,46 @@ pub enum SavingsGoalError {
     TimeLockShortening = 15,
     SnapshotNotFound = 16,
     SnapshotTooOld = 17,
+    ScheduleNotFound = 18,
+    /// Unlock date or schedule due date is not strictly in the future.
+    DateNotInFuture = 19,
+    /// `extend_goal_deadline` target is not later than the current one or
+    /// exceeds `MAX_EXTENSION_SECONDS` past the ledger time.
+    InvalidTargetDate = 20,
+    GoalNotCompleted = 21,
+    GoalAlreadyArchived = 22,
+    ArchivedGoalNotFound = 23,
+    ActiveGoalExists = 24,
+    /// The contract is globally paused.
+    ContractPaused = 25,
+    /// The called function is individually paused.
+    FunctionPaused = 26,
+    /// A pause operation was attempted before `set_pause_admin`.
+    PauseAdminNotSet = 27,
+    /// An upgrade operation was attempted before `set_upgrade_admin`.
+    UpgradeAdminNotSet = 28,
+    /// `unpause` was called before the scheduled `UnpauseAt` time.
+    UnpauseTimelockActive = 29,
+    /// A tag list is empty or a tag is not 1–32 characters long.
+    InvalidTag = 30,
+    /// The pagination cursor is not a goal ID in the index.
+    InvalidCursor = 31,
+    /// The supplied nonce does not match the caller's current nonce.
+    InvalidNonce = 32,
+}
+
+impl SavingsGoalError {
+    /// Shared `RemitwiseError` for variants with a cross-contract meaning,
+    /// so callers composing several contracts can handle them uniformly.
+    pub fn to_remitwise_error(self) -> Option<RemitwiseError> {
+        match self {
+            SavingsGoalError::Unauthorized => Some(RemitwiseError::Unauthorized),
+            SavingsGoalError::InvalidAmount | SavingsGoalError::TargetAmountMustBePositive => {
+                Some(RemitwiseError::InvalidAmount)
+            }
+            _ => None,
+        }
+    }
 }
 #[contract]
 pub struct SavingsGoalContract;
@@ -350,10 +390,10 @@ impl SavingsGoalContract {
     }
     fn require_not_paused(env: &Env, func: Symbol) {
         if Self::get_global_paused(env) {
-            panic!("Contract is paused");
+            panic_with_error!(env, SavingsGoalError::ContractPaused);
         }
         if Self::is_function_paused(env, func) {
-            panic!("Function is paused");
+            panic_with_error!(env, SavingsGoalError::FunctionPaused);
         }
     }
 
@@ -387,10 +427,12 @@ impl SavingsGoalContract {
         match current {
             None => {
                 if caller != new_admin {
-                    panic!("Unauthorized");
+                    panic_with_error!(&env, SavingsGoalError::Unauthorized);
                 }
             }
-            Some(ref admin) if admin != &caller => panic!("Unauthorized"),
+            Some(ref admin) if admin != &caller => {
+                panic_with_error!(&env, SavingsGoalError::Unauthorized)
+            }
             _ => {}
         }
         env.storage()
@@ -402,9 +444,10 @@ impl SavingsGoalContract {
         remitwise_common::require_no_active_kill_switch(&env)
             .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
         caller.require_auth();
-        let admin = Self::get_pause_admin(&env).unwrap_or_else(|| panic!("No pause admin set"));
+        let admin = Self::get_pause_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, SavingsGoalError::PauseAdminNotSet));
         if admin != caller {
-            panic!("Unauthorized");
+            panic_with_error!(&env, SavingsGoalError::Unauthorized);
         }
         env.storage().instance().set(&DataKey::Paused, &true);
         env.storage()
@@ -426,14 +469,15 @@ impl SavingsGoalContract {
         remitwise_common::require_no_active_kill_switch(&env)
             .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
         caller.require_auth();
-        let admin = Self::get_pause_admin(&env).unwrap_or_else(|| panic!("No pause admin set"));
+        let admin = Self::get_pause_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, SavingsGoalError::PauseAdminNotSet));
         if admin != caller {
-            panic!("Unauthorized");
+            panic_with_error!(&env, SavingsGoalError::Unauthorized);
         }
         let unpause_at: Option<u64> = env.storage().instance().get(&DataKey::UnpauseAt);
         if let Some(at) = unpause_at {
             if env.ledger().timestamp() < at {
-                panic!("Time-locked unpause not yet reached");
+                panic_with_error!(&env, SavingsGoalError::UnpauseTimelockActive);
             }
             env.storage().instance().remove(&DataKey::UnpauseAt);
         }
@@ -455,9 +499,10 @@ impl SavingsGoalContract {
         remitwise_common::require_no_active_kill_switch(&env)
             .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
         caller.require_auth();
-        let admin = Self::get_pause_admin(&env).unwrap_or_else(|| panic!("No pause admin set"));
+        let admin = Self::get_pause_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, SavingsGoalError::PauseAdminNotSet));
         if admin != caller {
-            panic!("Unauthorized");
+            panic_with_error!(&env, SavingsGoalError::Unauthorized);
         }
         let mut m: Map<Symbol, bool> = env
             .storage()
@@ -472,9 +517,10 @@ impl SavingsGoalContract {
         remitwise_common::require_no_active_kill_switch(&env)
             .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
         caller.require_auth();
-        let admin = Self::get_pause_admin(&env).unwrap_or_else(|| panic!("No pause admin set"));
+        let admin = Self::get_pause_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, SavingsGoalError::PauseAdminNotSet));
         if admin != caller {
-            panic!("Unauthorized");
+            panic_with_error!(&env, SavingsGoalError::Unauthorized);
         }
         let mut m: Map<Symbol, bool> = env
             .storage()
@@ -528,7 +574,7 @@ impl SavingsGoalContract {
     /// - `new_admin`: The address to become the new upgrade admin
     ///
     /// # Panics
-    /// - If caller is unauthorized for the operation
+    /// - `SavingsGoalError::Unauthorized` if caller is unauthorized for the operation
     pub fn set_upgrade_admin(env: Env, caller: Address, new_admin: Address) {
         caller.require_auth();
 
@@ -541,13 +587,13 @@ impl SavingsGoalContract {
             None => {
                 // Bootstrap pattern - caller must be setting themselves as admin
                 if caller != new_admin {
-                    panic!("Unauthorized: bootstrap requires caller == new_admin");
+                    panic_with_error!(&env, SavingsGoalError::Unauthorized);
                 }
             }
             Some(ref current_admin) => {
                 // Admin transfer - only current admin can transfer
                 if *current_admin != caller {
-                    panic!("Unauthorized: only current upgrade admin can transfer");
+                    panic_with_error!(&env, SavingsGoalError::Unauthorized);
                 }
             }
         }
@@ -574,12 +620,10 @@ impl SavingsGoalContract {
 
     pub fn set_version(env: Env, caller: Address, new_version: u32) {
         caller.require_auth();
-        let admin = match Self::get_upgrade_admin(&env) {
-            Some(a) => a,
-            None => panic!("No upgrade admin set"),
-        };
+        let admin = Self::get_upgrade_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, SavingsGoalError::UpgradeAdminNotSet));
         if admin != caller {
-            panic!("Unauthorized");
+            panic_with_error!(&env, SavingsGoalError::Unauthorized);
         }
         let prev = Self::get_version(env.clone());
         env.storage()
@@ -604,20 +648,20 @@ impl SavingsGoalContract {
     /// Only the upgrade admin may take a snapshot.
     ///
     /// # Panics
-    /// - If `caller` is not the upgrade admin
-    /// - If no upgrade admin is set
+    /// - `SavingsGoalError::Unauthorized` if `caller` is not the upgrade admin
+    /// - `SavingsGoalError::UpgradeAdminNotSet` if no upgrade admin is set
     ///
     /// # Events
     /// Emits `(symbol_short!("savings"), symbol_short!("snap_pre"))`.
     pub fn pre_upgrade(env: Env, caller: Address) {
-        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            panic!("cannot write: kill switch is active");
-        }
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
         caller.require_auth();
         Self::extend_instance_ttl(&env);
-        let admin = Self::get_upgrade_admin(&env).unwrap_or_else(|| panic!("No upgrade admin set"));
+        let admin = Self::get_upgrade_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, SavingsGoalError::UpgradeAdminNotSet));
         if admin != caller {
-            panic!("Unauthorized");
+            panic_with_error!(&env, SavingsGoalError::Unauthorized);
         }
         let snapshot = PreUpgradeSnapshot {
             schema_version: SNAPSHOT_VERSION,
@@ -652,20 +696,21 @@ impl SavingsGoalContract {
     /// Only the upgrade admin may restore from a snapshot.
     ///
     /// # Panics
-    /// - If `caller` is not the upgrade admin
-    /// - If no snapshot exists or version is unsupported
-    /// - If no upgrade admin is set
+    /// - `SavingsGoalError::Unauthorized` if `caller` is not the upgrade admin
+    /// - `SnapshotNotFound` / `UnsupportedVersion` / `SnapshotTooOld` if the
+    ///   snapshot is missing, from another schema or stale
+    /// - `SavingsGoalError::UpgradeAdminNotSet` if no upgrade admin is set
     ///
     /// # Events
     /// Emits `(symbol_short!("savings"), symbol_short!("snap_rst"))`.
     pub fn restore_from_snapshot(env: Env, caller: Address) {
-        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            panic!("cannot write: kill switch is active");
-        }
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
         caller.require_auth();
-        let admin = Self::get_upgrade_admin(&env).unwrap_or_else(|| panic!("No upgrade admin set"));
+        let admin = Self::get_upgrade_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, SavingsGoalError::UpgradeAdminNotSet));
         if admin != caller {
-            panic!("Unauthorized");
+            panic_with_error!(&env, SavingsGoalError::Unauthorized);
         }
         let snapshot: PreUpgradeSnapshot = env
             .storage()
@@ -719,16 +764,16 @@ impl SavingsGoalContract {
     /// Only the upgrade admin may discard a snapshot.
     ///
     /// # Panics
-    /// - If `caller` is not the upgrade admin
-    /// - If no upgrade admin is set
+    /// - `SavingsGoalError::Unauthorized` if `caller` is not the upgrade admin
+    /// - `SavingsGoalError::UpgradeAdminNotSet` if no upgrade admin is set
     pub fn discard_snapshot(env: Env, caller: Address) {
-        if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            panic!("cannot write: kill switch is active");
-        }
+        remitwise_common::require_no_active_kill_switch(&env)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
         caller.require_auth();
-        let admin = Self::get_upgrade_admin(&env).unwrap_or_else(|| panic!("No upgrade admin set"));
+        let admin = Self::get_upgrade_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, SavingsGoalError::UpgradeAdminNotSet));
         if admin != caller {
-            panic!("Unauthorized");
+            panic_with_error!(&env, SavingsGoalError::Unauthorized);
         }
         env.storage().persistent().remove(&SNAPSHOT_KEY);
         env.events()
@@ -746,14 +791,8 @@ impl SavingsGoalContract {
     fn validate_and_normalize_tags(env: &Env, tags: &Vec<String>) -> Vec<String> {
         match remitwise_common::canonicalize_tags_checked(env, tags) {
             Ok(normalized) => normalized,
-            Err(remitwise_common::TagError::Empty) => {
-                if tags.is_empty() {
-                    panic!("Tags cannot be empty");
-                }
-                panic!("Tag must be between 1 and 32 characters");
-            }
-            Err(remitwise_common::TagError::TooLong) => {
-                panic!("Tag must be between 1 and 32 characters");
+            Err(remitwise_common::TagError::Empty) | Err(remitwise_common::TagError::TooLong) => {
+                panic_with_error!(env, SavingsGoalError::InvalidTag)
             }
             Err(remitwise_common::TagError::InvalidChar { .. }) => {
                 soroban_sdk::panic_with_error!(env, SavingsGoalError::InvalidTagContent)
@@ -828,7 +867,12 @@ impl SavingsGoalContract {
     /// - Duplicate tags are preserved as provided.
     /// - Maintains canonicalized tag index; each (owner, tag) maps to goal IDs.
     /// - Emits `(savings, tags_add)` with `(goal_id, caller, tags)`.
-    pub fn add_tags_to_goal(env: Env, caller: Address, goal_id: u32, tags: Vec<String>) {
+    pub fn add_tags_to_goal(
+        env: Env,
+        caller: Address,
+        goal_id: u32,
+        tags: Vec<String>,
+    ) -> Result<(), SavingsGoalError> {
         remitwise_common::require_no_active_kill_switch(&env)
             .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
         caller.require_auth();
@@ -843,13 +887,13 @@ impl SavingsGoalContract {
             Some(g) => g,
             None => {
                 Self::append_audit(&env, symbol_short!("add_tags"), &caller, false);
-                panic!("Goal not found");
+                return Err(SavingsGoalError::GoalNotFound);
             }
         };
 
         if goal.owner != caller {
             Self::append_audit(&env, symbol_short!("add_tags"), &caller, false);
-            panic!("Only the goal owner can add tags");
+            return Err(SavingsGoalError::Unauthorized);
         }
 
         for tag in normalized_tags.iter() {
@@ -880,6 +924,7 @@ impl SavingsGoalContract {
         );
 
         Self::append_audit(&env, symbol_short!("add_tags"), &caller, true);
+        Ok(())
     }
 
     /// Removes tags from a goal's metadata and updates the tag index.
@@ -892,7 +937,12 @@ impl SavingsGoalContract {
     /// - Removing a tag that is not present is a no-op.
     /// - Removes goal ID from tag index for each removed tag.
     /// - Emits `(savings, tags_rem)` with `(goal_id, caller, tags)`.
-    pub fn remove_tags_from_goal(env: Env, caller: Address, goal_id: u32, tags: Vec<String>) {
+    pub fn remove_tags_from_goal(
+        env: Env,
+        caller: Address,
+        goal_id: u32,
+        tags: Vec<String>,
+    ) -> Result<(), SavingsGoalError> {
         remitwise_common::require_no_active_kill_switch(&env)
             .unwrap_or_else(|e| soroban_sdk::panic_with_error!(&env, e));
         caller.require_auth();
@@ -907,13 +957,13 @@ impl SavingsGoalContract {
             Some(g) => g,
             None => {
                 Self::append_audit(&env, symbol_short!("rem_tags"), &caller, false);
-                panic!("Goal not found");
+                return Err(SavingsGoalError::GoalNotFound);
             }
         };
 
         if goal.owner != caller {
             Self::append_audit(&env, symbol_short!("rem_tags"), &caller, false);
-            panic!("Only the goal owner can remove tags");
+            return Err(SavingsGoalError::Unauthorized);
         }
 
         let mut new_tags = Vec::new(&env);
@@ -955,6 +1005,7 @@ impl SavingsGoalContract {
         );
 
         Self::append_audit(&env, symbol_short!("rem_tags"), &caller, true);
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
@@ -1089,6 +1140,63 @@ impl SavingsGoalContract {
         amount: i128,
     ) -> Result<i128, SavingsGoalError> {
         caller.require_auth();
+        Self::contribute(env, caller, goal_id, amount)
+    }
+
+    /// Adds funds to `owner`'s goal on their behalf.
+    ///
+    /// `executor` must be registered by `owner` through
+    /// [`Self::set_authorized_executor`]; the orchestrator uses this to run
+    /// scheduled flows without the owner signing each run. Otherwise behaves
+    /// exactly like [`Self::add_to_goal`] with `caller == owner`.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If `owner` has not registered `executor`, or the
+    ///   goal is not `owner`'s
+    /// * Any error of [`Self::add_to_goal`]
+    ///
+    /// # Panics
+    /// * If `executor` does not authorize the transaction
+    pub fn add_to_goal_for(
+        env: Env,
+        executor: Address,
+        owner: Address,
+        goal_id: u32,
+        amount: i128,
+    ) -> Result<i128, SavingsGoalError> {
+        executor.require_auth();
+        if remitwise_common::require_authorized_executor(&env, &owner, &executor).is_err() {
+            Self::append_audit(&env, symbol_short!("add"), &executor, false);
+            return Err(SavingsGoalError::Unauthorized);
+        }
+        Self::contribute(env, owner, goal_id, amount)
+    }
+
+    /// Lets `executor` (typically the orchestrator) add funds to `owner`'s
+    /// goals through [`Self::add_to_goal_for`], or revokes it.
+    ///
+    /// # Panics
+    /// * If `owner` does not authorize the transaction
+    pub fn set_authorized_executor(env: Env, owner: Address, executor: Address, authorized: bool) {
+        owner.require_auth();
+        Self::extend_instance_ttl(&env);
+        remitwise_common::set_authorized_executor(&env, &owner, &executor, authorized);
+        Self::append_audit(&env, symbol_short!("executor"), &owner, true);
+    }
+
+    /// Whether `owner` has registered `executor` for [`Self::add_to_goal_for`].
+    pub fn is_authorized_executor(env: Env, owner: Address, executor: Address) -> bool {
+        remitwise_common::is_authorized_executor(&env, &owner, &executor)
+    }
+
+    /// Shared body of [`Self::add_to_goal`] and [`Self::add_to_goal_for`];
+    /// `caller` is the owner whose goal is funded.
+    fn contribute(
+        env: Env,
+        caller: Address,
+        goal_id: u32,
+        amount: i128,
+    ) -> Result<i128, SavingsGoalError> {
         Self::require_not_paused(&env, pause_functions::ADD_TO_GOAL);
 
         if amount < 0 {
@@ -1443,9 +1551,9 @@ impl SavingsGoalContract {
     ///
     /// # Events
     /// - Emits `SavingsEvent::GoalLocked`.
-    pub fn lock_goal(env: Env, caller: Address, goal_id: u32) -> bool {
+    pub fn lock_goal(env: Env, caller: Address, goal_id: u32) -> Result<bool, SavingsGoalError> {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
+            return Ok(false);
         }
         caller.require_auth();
         Self::require_not_paused(&env, pause_functions::LOCK);
@@ -1459,17 +1567,17 @@ impl SavingsGoalContract {
             Some(g) => g,
             None => {
                 Self::append_audit(&env, symbol_short!("lock"), &caller, false);
-                panic!("Goal not found");
+                return Err(SavingsGoalError::GoalNotFound);
             }
         };
 
         if goal.owner != caller {
             Self::append_audit(&env, symbol_short!("lock"), &caller, false);
-            panic!("Only the goal owner can lock this goal");
+            return Err(SavingsGoalError::Unauthorized);
         }
 
         if goal.locked {
-            return true;
+            return Ok(true);
         }
 
         goal.locked = true;
@@ -1493,7 +1601,7 @@ impl SavingsGoalContract {
             },
         );
 
-        true
+        Ok(true)
     }
 
     /// Unlocks a goal for manual withdrawals.
@@ -1504,9 +1612,9 @@ impl SavingsGoalContract {
     ///
     /// # Events
     /// - Emits `SavingsEvent::GoalUnlocked`.
-    pub fn unlock_goal(env: Env, caller: Address, goal_id: u32) -> bool {
+    pub fn unlock_goal(env: Env, caller: Address, goal_id: u32) -> Result<bool, SavingsGoalError> {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
+            return Ok(false);
         }
         caller.require_auth();
         Self::require_not_paused(&env, pause_functions::UNLOCK);
@@ -1520,17 +1628,17 @@ impl SavingsGoalContract {
             Some(g) => g,
             None => {
                 Self::append_audit(&env, symbol_short!("unlock"), &caller, false);
-                panic!("Goal not found");
+                return Err(SavingsGoalError::GoalNotFound);
             }
         };
 
         if goal.owner != caller {
             Self::append_audit(&env, symbol_short!("unlock"), &caller, false);
-            panic!("Only the goal owner can unlock this goal");
+            return Err(SavingsGoalError::Unauthorized);
         }
 
         if !goal.locked {
-            return true;
+            return Ok(true);
         }
 
         goal.locked = false;
@@ -1554,7 +1662,7 @@ impl SavingsGoalContract {
             },
         );
 
-        true
+        Ok(true)
     }
 
     /// Push a savings goal's target date further into the future.
@@ -1567,18 +1675,18 @@ impl SavingsGoalContract {
     /// # Returns
     /// The goal's updated target date
     ///
-    /// # Panics
-    /// - If caller is not the goal owner
-    /// - If goal is not found
-    /// - If `new_target_date` is not later than the current target date
-    /// - If `new_target_date` is further than `MAX_EXTENSION_SECONDS` past
-    ///   the current ledger time (the "ledger cap")
+    /// # Errors
+    /// - `Unauthorized` if caller is not the goal owner
+    /// - `GoalNotFound` if the goal does not exist
+    /// - `InvalidTargetDate` if `new_target_date` is not later than the
+    ///   current target date, or is further than `MAX_EXTENSION_SECONDS`
+    ///   past the current ledger time (the "ledger cap")
     pub fn extend_goal_deadline(
         env: Env,
         caller: Address,
         goal_id: u32,
         new_target_date: u64,
-    ) -> u64 {
+    ) -> Result<u64, SavingsGoalError> {
         // Access control: require caller authorization
         caller.require_auth();
 
@@ -1591,20 +1699,20 @@ impl SavingsGoalContract {
             .get(&symbol_short!("GOALS"))
             .unwrap_or_else(|| Map::new(&env));
 
-        let mut goal = goals.get(goal_id).expect("Goal not found");
+        let mut goal = goals.get(goal_id).ok_or(SavingsGoalError::GoalNotFound)?;
 
         // Access control: verify caller is the owner
         if goal.owner != caller {
-            panic!("Only the goal owner can extend this goal's deadline");
+            return Err(SavingsGoalError::Unauthorized);
         }
 
         if new_target_date <= goal.target_date {
-            panic!("New target date must be later than the current target date");
+            return Err(SavingsGoalError::InvalidTargetDate);
         }
 
         let max_target_date = env.ledger().timestamp() + MAX_EXTENSION_SECONDS;
         if new_target_date > max_target_date {
-            panic!("New target date exceeds the maximum extension allowed from the current ledger time");
+            return Err(SavingsGoalError::InvalidTargetDate);
         }
 
         goal.target_date = new_target_date;
@@ -1613,7 +1721,7 @@ impl SavingsGoalContract {
             .instance()
             .set(&symbol_short!("GOALS"), &goals);
 
-        new_target_date
+        Ok(new_target_date)
     }
 
     /// Get a savings goal by ID
@@ -1658,7 +1766,8 @@ impl SavingsGoalContract {
     /// - `count`: Number of items returned
     ///
     /// # Panics
-    /// - If `cursor != 0` and does not match an existing goal ID in the owner's index
+    /// - `SavingsGoalError::InvalidCursor` if `cursor != 0` and does not match an
+    ///   existing goal ID in the owner's index
     ///
     /// # Example
     /// ```ignore
@@ -1690,7 +1799,7 @@ impl SavingsGoalContract {
             if let Some(pos) = ids.iter().position(|id| id == cursor) {
                 start_index = (pos as u32) + 1;
             } else {
-                panic!("Invalid cursor");
+                panic_with_error!(&env, SavingsGoalError::InvalidCursor);
             }
         }
 
@@ -1758,7 +1867,7 @@ impl SavingsGoalContract {
         let canonical_tag = match remitwise_common::canonicalize_tag_checked(&env, &tag) {
             Ok(t) => t,
             Err(remitwise_common::TagError::Empty) | Err(remitwise_common::TagError::TooLong) => {
-                panic!("Tag must be between 1 and 32 characters")
+                panic_with_error!(&env, SavingsGoalError::InvalidTag)
             }
             Err(remitwise_common::TagError::InvalidChar { .. }) => {
                 soroban_sdk::panic_with_error!(&env, SavingsGoalError::InvalidTagContent)
@@ -1784,7 +1893,7 @@ impl SavingsGoalContract {
             if let Some(pos) = ids.iter().position(|id| id == cursor) {
                 start_index = (pos as u32) + 1;
             } else {
-                panic!("Invalid cursor");
+                panic_with_error!(&env, SavingsGoalError::InvalidCursor);
             }
         }
 
@@ -1835,9 +1944,9 @@ impl SavingsGoalContract {
     /// - Removes the goal from the active owner index and inserts it into the archived owner index.
     /// - Archived pagination order is deterministic: ascending goal ID for that owner.
     /// - Removes goal ID from all tag indexes it was associated with.
-    pub fn archive_goal(env: Env, caller: Address, goal_id: u32) -> bool {
+    pub fn archive_goal(env: Env, caller: Address, goal_id: u32) -> Result<bool, SavingsGoalError> {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
+            return Ok(false);
         }
         caller.require_auth();
         Self::require_not_paused(&env, pause_functions::ARCHIVE);
@@ -1851,17 +1960,17 @@ impl SavingsGoalContract {
             Some(g) => g,
             None => {
                 Self::append_audit(&env, symbol_short!("archive"), &caller, false);
-                panic!("Goal not found");
+                return Err(SavingsGoalError::GoalNotFound);
             }
         };
 
         if goal.owner != caller {
             Self::append_audit(&env, symbol_short!("archive"), &caller, false);
-            panic!("Only the goal owner can archive this goal");
+            return Err(SavingsGoalError::Unauthorized);
         }
         if goal.current_amount < goal.target_amount {
             Self::append_audit(&env, symbol_short!("archive"), &caller, false);
-            panic!("Goal not completed");
+            return Err(SavingsGoalError::GoalNotCompleted);
         }
 
         if env
@@ -1870,7 +1979,7 @@ impl SavingsGoalContract {
             .has(&DataKey::ArchivedGoal(goal_id))
         {
             Self::append_audit(&env, symbol_short!("archive"), &caller, false);
-            panic!("Goal already archived");
+            return Err(SavingsGoalError::GoalAlreadyArchived);
         }
 
         // Remove goal from all tag indexes before archiving
@@ -1893,7 +2002,7 @@ impl SavingsGoalContract {
         Self::insert_owner_archived_goal_id_sorted(&env, &caller, goal_id);
 
         Self::append_audit(&env, symbol_short!("archive"), &caller, true);
-        true
+        Ok(true)
     }
 
     /// Restores an archived goal back into active storage.
@@ -1901,9 +2010,9 @@ impl SavingsGoalContract {
     /// Security:
     /// - `caller` must authorize the invocation.
     /// - Only the archived goal owner can restore.
-    pub fn restore_goal(env: Env, caller: Address, goal_id: u32) -> bool {
+    pub fn restore_goal(env: Env, caller: Address, goal_id: u32) -> Result<bool, SavingsGoalError> {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
+            return Ok(false);
         }
         caller.require_auth();
         Self::require_not_paused(&env, pause_functions::RESTORE);
@@ -1917,18 +2026,18 @@ impl SavingsGoalContract {
             Some(g) => g,
             None => {
                 Self::append_audit(&env, symbol_short!("restore"), &caller, false);
-                panic!("Archived goal not found");
+                return Err(SavingsGoalError::ArchivedGoalNotFound);
             }
         };
 
         if archived_goal.owner != caller {
             Self::append_audit(&env, symbol_short!("restore"), &caller, false);
-            panic!("Only the goal owner can restore this goal");
+            return Err(SavingsGoalError::Unauthorized);
         }
 
         if env.storage().persistent().has(&DataKey::Goal(goal_id)) {
             Self::append_audit(&env, symbol_short!("restore"), &caller, false);
-            panic!("Active goal already exists");
+            return Err(SavingsGoalError::ActiveGoalExists);
         }
 
         env.storage()
@@ -1954,7 +2063,7 @@ impl SavingsGoalContract {
         Self::insert_owner_goal_id_sorted(&env, &caller, goal_id);
 
         Self::append_audit(&env, symbol_short!("restore"), &caller, true);
-        true
+        Ok(true)
     }
 
     /// Returns a deterministic page of archived goals for one owner.
@@ -1993,7 +2102,7 @@ impl SavingsGoalContract {
             if let Some(pos) = ids.iter().position(|id| id == cursor) {
                 start_index = (pos as u32) + 1;
             } else {
-                panic!("Invalid cursor");
+                panic_with_error!(&env, SavingsGoalError::InvalidCursor);
             }
         }
 
@@ -2293,16 +2402,15 @@ impl SavingsGoalContract {
     fn require_nonce(env: &Env, address: &Address, expected: u64) {
         let current = Self::get_nonce(env.clone(), address.clone());
         if expected != current {
-            panic!("Invalid nonce: expected {}, got {}", expected, current);
+            panic_with_error!(env, SavingsGoalError::InvalidNonce);
         }
     }
 
     fn increment_nonce(env: &Env, address: &Address) {
         let current = Self::get_nonce(env.clone(), address.clone());
-        let next = match current.checked_add(1) {
-            Some(v) => v,
-            None => panic!("nonce overflow"),
-        };
+        let next = current
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(env, SavingsGoalError::Overflow));
         env.storage()
             .instance()
             .set(&DataKey::Nonces(address.clone()), &next);
@@ -2514,14 +2622,19 @@ impl SavingsGoalContract {
     /// * `unlock_date` - Unix timestamp when the goal becomes withdrawable
     ///
     /// # Errors
-    /// Returns [`SavingsGoalError::TimeLockShortening`] when attempting to
-    /// shorten an already-active time-lock.
-    ///
-    /// # Panics
-    /// - If caller is not the owner or goal not found.
-    pub fn set_time_lock(env: Env, caller: Address, goal_id: u32, unlock_date: u64) -> bool {
+    /// - [`SavingsGoalError::GoalNotFound`] / [`SavingsGoalError::Unauthorized`]
+    ///   if the goal does not exist or caller is not its owner.
+    /// - [`SavingsGoalError::DateNotInFuture`] if `unlock_date` is not in the future.
+    /// - [`SavingsGoalError::TimeLockShortening`] when attempting to
+    ///   shorten an already-active time-lock.
+    pub fn set_time_lock(
+        env: Env,
+        caller: Address,
+        goal_id: u32,
+        unlock_date: u64,
+    ) -> Result<bool, SavingsGoalError> {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
+            return Ok(false);
         }
         caller.require_auth();
         Self::extend_instance_ttl(&env);
@@ -2534,19 +2647,19 @@ impl SavingsGoalContract {
             Some(g) => g,
             None => {
                 Self::append_audit(&env, symbol_short!("timelock"), &caller, false);
-                panic!("Goal not found");
+                return Err(SavingsGoalError::GoalNotFound);
             }
         };
 
         if goal.owner != caller {
             Self::append_audit(&env, symbol_short!("timelock"), &caller, false);
-            panic!("Only the goal owner can set time-lock");
+            return Err(SavingsGoalError::Unauthorized);
         }
 
         let current_time = env.ledger().timestamp();
         if unlock_date <= current_time {
             Self::append_audit(&env, symbol_short!("timelock"), &caller, false);
-            panic!("Unlock date must be in the future");
+            return Err(SavingsGoalError::DateNotInFuture);
         }
 
         // Monotonicity guard: while an existing time-lock is active,
@@ -2555,7 +2668,7 @@ impl SavingsGoalContract {
             // Active iff unlock_date is strictly in the future.
             if prev_unlock > current_time && unlock_date < prev_unlock {
                 Self::append_audit(&env, symbol_short!("timelock"), &caller, false);
-                soroban_sdk::panic_with_error!(env, SavingsGoalError::TimeLockShortening);
+                return Err(SavingsGoalError::TimeLockShortening);
             }
         }
 
@@ -2575,7 +2688,7 @@ impl SavingsGoalContract {
         );
 
         Self::append_audit(&env, symbol_short!("timelock"), &caller, true);
-        true
+        Ok(true)
     }
 
     /// Creates a recurring savings schedule.
@@ -2596,11 +2709,11 @@ impl SavingsGoalContract {
         amount: i128,
         next_due: u64,
         interval: u64,
-    ) -> u32 {
+    ) -> Result<u32, SavingsGoalError> {
         owner.require_auth();
 
         if amount <= 0 {
-            panic!("Amount must be positive");
+            return Err(SavingsGoalError::InvalidAmount);
         }
 
         let goal = match env
@@ -2609,16 +2722,16 @@ impl SavingsGoalContract {
             .get::<_, SavingsGoal>(&DataKey::Goal(goal_id))
         {
             Some(g) => g,
-            None => panic!("Goal not found"),
+            None => return Err(SavingsGoalError::GoalNotFound),
         };
 
         if goal.owner != owner {
-            panic!("Only the goal owner can create schedules");
+            return Err(SavingsGoalError::Unauthorized);
         }
 
         let current_time = env.ledger().timestamp();
         if next_due <= current_time {
-            panic!("Next due date must be in the future");
+            return Err(SavingsGoalError::DateNotInFuture);
         }
 
         Self::extend_instance_ttl(&env);
@@ -2670,7 +2783,7 @@ impl SavingsGoalContract {
             },
         );
 
-        next_schedule_id
+        Ok(next_schedule_id)
     }
 
     pub fn modify_savings_schedule(
@@ -2680,16 +2793,16 @@ impl SavingsGoalContract {
         amount: i128,
         next_due: u64,
         interval: u64,
-    ) -> bool {
+    ) -> Result<bool, SavingsGoalError> {
         caller.require_auth();
 
         if amount <= 0 {
-            panic!("Amount must be positive");
+            return Err(SavingsGoalError::InvalidAmount);
         }
 
         let current_time = env.ledger().timestamp();
         if next_due <= current_time {
-            panic!("Next due date must be in the future");
+            return Err(SavingsGoalError::DateNotInFuture);
         }
 
         Self::extend_instance_ttl(&env);
@@ -2700,11 +2813,11 @@ impl SavingsGoalContract {
             .get::<_, SavingsSchedule>(&DataKey::Schedule(schedule_id))
         {
             Some(s) => s,
-            None => panic!("Schedule not found"),
+            None => return Err(SavingsGoalError::ScheduleNotFound),
         };
 
         if schedule.owner != caller {
-            panic!("Only the schedule owner can modify it");
+            return Err(SavingsGoalError::Unauthorized);
         }
 
         schedule.amount = amount;
@@ -2734,12 +2847,16 @@ impl SavingsGoalContract {
             },
         );
 
-        true
+        Ok(true)
     }
 
-    pub fn cancel_savings_schedule(env: Env, caller: Address, schedule_id: u32) -> bool {
+    pub fn cancel_savings_schedule(
+        env: Env,
+        caller: Address,
+        schedule_id: u32,
+    ) -> Result<bool, SavingsGoalError> {
         if remitwise_common::require_no_active_kill_switch(&env).is_err() {
-            return false;
+            return Ok(false);
         }
         caller.require_auth();
 
@@ -2751,11 +2868,11 @@ impl SavingsGoalContract {
             .get::<_, SavingsSchedule>(&DataKey::Schedule(schedule_id))
         {
             Some(s) => s,
-            None => panic!("Schedule not found"),
+            None => return Err(SavingsGoalError::ScheduleNotFound),
         };
 
         if schedule.owner != caller {
-            panic!("Only the schedule owner can cancel it");
+            return Err(SavingsGoalError::Unauthorized);
         }
 
         schedule.active = false;
@@ -2779,7 +2896,7 @@ impl SavingsGoalContract {
             },
         );
 
-        true
+        Ok(true)
     }
 
     /// Executes all savings schedules whose `next_due` timestamp is at or before
@@ -449,7 +449,6 @@ fn test_extend_goal_deadline_within_cap() {
 }
 
 #[test]
-#[should_panic(expected = "exceeds the maximum extension")]
 fn test_extend_goal_deadline_past_ledger_cap() {
     let env = Env::default();
     env.ledger().set_timestamp(NOW);
@@ -465,11 +464,11 @@ fn test_extend_goal_deadline_past_ledger_cap() {
     // to land just past the cap (and still past the current target_date,
     // so it's the cap -- not the forward-move check -- that rejects it).
     let past_cap = NOW + (5 * 365 * 86400) + 86400;
-    client.extend_goal_deadline(&user, &id, &past_cap);
+    let res = client.try_extend_goal_deadline(&user, &id, &past_cap);
+    assert_eq!(res, Err(Ok(SavingsGoalError::InvalidTargetDate)));
 }
 
 #[test]
-#[should_panic(expected = "must be later than the current target date")]
 fn test_extend_goal_deadline_rejects_non_forward_move() {
     let env = Env::default();
     let contract_id = env.register_contract(None, SavingsGoalContract);
@@ -480,5 +479,71 @@ fn test_extend_goal_deadline_rejects_non_forward_move() {
     env.mock_all_auths();
     let id = client.create_goal(&user, &String::from_str(&env, "Trip"), &1000, &2000000000);
 
-    client.extend_goal_deadline(&user, &id, &2000000000);
+    let res = client.try_extend_goal_deadline(&user, &id, &2000000000);
+    assert_eq!(res, Err(Ok(SavingsGoalError::InvalidTargetDate)));
+}
+
+#[test]
+fn test_pause_requires_pause_admin() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SavingsGoalContract);
+    let client = SavingsGoalContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.init();
+    env.mock_all_auths();
+
+    let res = client.try_pause(&admin);
+    assert_eq!(res, Err(Ok(SavingsGoalError::PauseAdminNotSet.into())));
+
+    client.set_pause_admin(&admin, &admin);
+    let res = client.try_pause(&stranger);
+    assert_eq!(res, Err(Ok(SavingsGoalError::Unauthorized.into())));
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_paused_contract_rejects_create_goal() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SavingsGoalContract);
+    let client = SavingsGoalContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.init();
+    env.mock_all_auths();
+    client.set_pause_admin(&admin, &admin);
+    client.pause(&admin);
+
+    let res = client.try_create_goal(
+        &user,
+        &String::from_str(&env, "Trip"),
+        &1000,
+        &2000000000,
+        &false,
+    );
+    assert_eq!(res, Err(Ok(SavingsGoalError::ContractPaused)));
+}
+
+#[test]
+fn test_upgrade_admin_paths_return_typed_errors() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SavingsGoalContract);
+    let client = SavingsGoalContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.init();
+    env.mock_all_auths();
+
+    let res = client.try_set_version(&admin, &2);
+    assert_eq!(res, Err(Ok(SavingsGoalError::UpgradeAdminNotSet.into())));
+
+    client.set_upgrade_admin(&admin, &admin);
+    let res = client.try_set_upgrade_admin(&stranger, &stranger);
+    assert_eq!(res, Err(Ok(SavingsGoalError::Unauthorized.into())));
+    let res = client.try_set_version(&stranger, &2);
+    assert_eq!(res, Err(Ok(SavingsGoalError::Unauthorized.into())));
+    assert_eq!(client.get_upgrade_admin_public(), Some(admin));
 }
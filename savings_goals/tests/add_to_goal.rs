@@ -58,3 +58,51 @@ fn test_add_to_goal_unauthorized_access() {
 
     println!("Successfully verified that only the goal owner can add funds.");
 }
+
+#[test]
+fn test_add_to_goal_for_requires_registration_by_the_goal_owner() {
+    let env = bench_env();
+    let contract_id = env.register_contract(None, SavingsGoalContract);
+    let client = SavingsGoalContractClient::new(&env, &contract_id);
+
+    let owner_a = Address::generate(&env);
+    let owner_b = Address::generate(&env);
+    let executor = Address::generate(&env);
+    let goal_id = client.create_goal(
+        &owner_a,
+        &String::from_str(&env, "Owner A Goal"),
+        &10_000i128,
+        &1_800_000u64,
+        &false,
+    );
+
+    // Not registered yet.
+    assert!(client
+        .try_add_to_goal_for(&executor, &owner_a, &goal_id, &500)
+        .is_err());
+
+    // Registered by owner B only: cannot reach owner A's goal, neither as A
+    // nor by naming B as the owner.
+    client.set_authorized_executor(&owner_b, &executor, &true);
+    assert!(client
+        .try_add_to_goal_for(&executor, &owner_a, &goal_id, &500)
+        .is_err());
+    assert!(client
+        .try_add_to_goal_for(&executor, &owner_b, &goal_id, &500)
+        .is_err());
+    assert_eq!(client.get_goal(&goal_id).unwrap().current_amount, 0);
+
+    client.set_authorized_executor(&owner_a, &executor, &true);
+    assert!(client.is_authorized_executor(&owner_a, &executor));
+    assert_eq!(
+        client.add_to_goal_for(&executor, &owner_a, &goal_id, &500),
+        500
+    );
+
+    client.set_authorized_executor(&owner_a, &executor, &false);
+    assert!(!client.is_authorized_executor(&owner_a, &executor));
+    assert!(client
+        .try_add_to_goal_for(&executor, &owner_a, &goal_id, &500)
+        .is_err());
+    assert_eq!(client.get_goal(&goal_id).unwrap().current_amount, 500);
+}